@@ -0,0 +1,114 @@
+#!/bin/bash
+#	Compile-time benchmark for the dimension backends.
+#
+#	Generates a crate that performs many chained dimension operations, and times
+#		`cargo check` on it, first with `Dimension` types from `dim!` and then
+#		with the `ConstDim` types of the `const_dim` feature. The latter is
+#		checked with the nightly toolchain, which must be installed.
+#
+#	Usage: benches/compile_dim.sh [FUNCTIONS] [OPS]
+
+
+main() {
+	local n_fns="${1:-200}"
+	local n_ops="${2:-20}"
+	local root
+	root="$(cd "$(dirname "$0")/.." && pwd)"
+
+	local dir
+	dir="$(mktemp -d)"
+	trap 'rm -rf "$dir"' EXIT
+
+	gen_crate "$dir" "$root" "$n_fns" "$n_ops"
+
+	echo "Checking $n_fns functions of $n_ops dimension operations each."
+	time_check "$dir" "typenum" "" stable
+	time_check "$dir" "const_dim" "const_dim" nightly
+}
+
+
+gen_crate() {
+	local dir="$1" root="$2" n_fns="$3" n_ops="$4"
+
+	mkdir -p "$dir/src"
+	cat >"$dir/Cargo.toml" <<-TOML
+		[package]
+		name = "compile_dim"
+		version = "0.0.0"
+		edition = "2021"
+
+		[dependencies]
+		dana = { path = "$root", default-features = false }
+		typenum = "1.17.0"
+
+		[features]
+		const_dim = ["dana/const_dim"]
+	TOML
+
+	{
+		echo '#![cfg_attr(feature = "const_dim", feature(generic_const_exprs))]'
+		echo '#![cfg_attr(feature = "const_dim", allow(incomplete_features))]'
+		echo 'use dana::dimension::*;'
+		echo
+		echo '#[cfg(not(feature = "const_dim"))]'
+		echo 'macro_rules! d { ($($e:tt)*) => { dana::dim!(<$($e)*>) }; }'
+		echo '#[cfg(feature = "const_dim")]'
+		echo 'macro_rules! d { ($($e:tt)*) => { ConstDim<{ pack([$($e)*]) }> }; }'
+		echo
+
+		local f i op axis dims
+		local -a exp
+		local -a ops=(
+			"* <d!(0,1,0,0,0,0,0)>" "/ <d!(0,0,1,0,0,0,0)>"
+			"* <d!(0,0,0,0,1,0,0)>" "/ <d!(1,0,0,0,0,0,0)>"
+		)
+		local -a axes=(1 2 4 0)
+		for ((f = 0; f < n_fns; f++)); do
+			exp=(1 0 0 0 0 0 0)
+			echo "pub fn f$f() -> impl DimType {"
+			echo "    let d = <d!(1,0,0,0,0,0,0)>::dimension();"
+			for ((i = 0; i < n_ops; i++)); do
+				op="${ops[i % 4]}"
+				axis="${axes[i % 4]}"
+				if [[ "${op:0:1}" == "*" ]]; then
+					((exp[axis] += 1))
+				else
+					((exp[axis] -= 1))
+				fi
+				# Name each intermediate type, as a real crate would in its
+				#	signatures. Without this, `ConstDim` nests the unevaluated
+				#	exponent expressions of every step into the next.
+				printf -v dims '%s,' "${exp[@]}"
+				echo "    let d: d!(${dims%,}) = d ${op}::dimension();"
+			done
+			echo "    d"
+			echo "}"
+		done
+	} >"$dir/src/lib.rs"
+}
+
+
+time_check() {
+	local dir="$1" name="$2" features="$3" toolchain="$4"
+
+	# Build the dependencies first, so that only the generated crate is timed.
+	cargo "+$toolchain" check -q --manifest-path "$dir/Cargo.toml" --features "$features" \
+		>/dev/null 2>&1
+	touch "$dir/src/lib.rs"
+
+	# Disable incremental compilation, so that the timed check cannot reuse
+	#	the results of the build above.
+	local -x CARGO_INCREMENTAL=0
+
+	local start end
+	start=$(date +%s.%N)
+	if cargo "+$toolchain" check -q --manifest-path "$dir/Cargo.toml" --features "$features"; then
+		end=$(date +%s.%N)
+		printf "%-10s %8.3fs\n" "$name:" "$(awk "BEGIN { print $end - $start }")"
+	else
+		echo "$name: failed"
+	fi
+}
+
+
+main "$@"
@@ -1,3 +1,6 @@
+//! Benchmarks for basic quantity operations.
+#![allow(missing_docs)]
+
 use criterion::{Criterion, criterion_group, criterion_main};
 use dana::{constants::*, qty, Quantity, symbols::physics::*, units::*};
 
@@ -0,0 +1,71 @@
+//! Benchmarks for quantity normalization.
+#![allow(missing_docs)]
+
+use criterion::{black_box, Criterion, criterion_group, criterion_main};
+use dana::{qty, quantity::NormalizeOptions, symbols::basic::*, units::*};
+
+
+/// Values spread across many orders of magnitude, so that normalization has to
+///     move a different distance for each one.
+const VALUES: [f64; 8] = [4.3e-9, 2.1e-4, 0.37, 8.0, 650.0, 1.2e5, 9.9e8, 3.3e13];
+
+
+fn normalize_concrete(values: &[f64]) -> f64 {
+    values.iter()
+        .map(|&v| Length::Meter.quantity(v).normalize().value)
+        .sum()
+}
+
+
+fn normalize_concrete_stepped(values: &[f64]) -> f64 {
+    let options = NormalizeOptions::new();
+
+    values.iter()
+        .map(|&v| Length::Meter.quantity(v).normalize_with(&options).value)
+        .sum()
+}
+
+
+fn normalize_compound(values: &[f64]) -> f64 {
+    values.iter()
+        .map(|&v| qty![v m/s].normalize().value)
+        .sum()
+}
+
+
+fn normalize_compound_stepped(values: &[f64]) -> f64 {
+    let options = NormalizeOptions::new();
+
+    values.iter()
+        .map(|&v| qty![v m/s].normalize_with(&options).value)
+        .sum()
+}
+
+
+fn normalize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Normalize");
+
+    group.bench_function(
+        "concrete table",
+        |b| b.iter(|| normalize_concrete(black_box(&VALUES))),
+    );
+
+    group.bench_function(
+        "concrete stepped",
+        |b| b.iter(|| normalize_concrete_stepped(black_box(&VALUES))),
+    );
+
+    group.bench_function(
+        "compound table",
+        |b| b.iter(|| normalize_compound(black_box(&VALUES))),
+    );
+
+    group.bench_function(
+        "compound stepped",
+        |b| b.iter(|| normalize_compound_stepped(black_box(&VALUES))),
+    );
+}
+
+
+criterion_group!(benches, normalize);
+criterion_main!(benches);
@@ -81,7 +81,7 @@ impl Parse for DimSeq {
             if array.len() <= i {
                 return Err(syn::Error::new(
                     literal.span(),
-                    &format!("too many dimensions specified, expected at \
+                    format!("too many dimensions specified, expected at \
                     most {}", array.len()),
                     // "too many dimensions specified",
                 ));
@@ -142,7 +142,7 @@ impl Parse for DimBase {
             } else {
                 Err(syn::Error::new(
                     literal.span(),
-                    &format!("expected `1` or dimension, found `{literal}`"),
+                    format!("expected `1` or dimension, found `{literal}`"),
                 ))
             }
         } else {
@@ -283,7 +283,7 @@ impl Parse for MacroDim {
 
             let mul = match &seq {
                 Some(_) if input.parse::<Token![*]>().is_err() => None,
-                __ => Some(input.parse()?),
+                _ => Some(input.parse()?),
             };
 
             Ok(Self { seq, mul })
@@ -10,7 +10,7 @@ use crate::unit_spec::UnitSpecType;
 
 pub enum ScalarType {
     Inferred(Token![_]),
-    Explicit(syn::Type),
+    Explicit(Box<syn::Type>),
 }
 
 impl std::fmt::Debug for ScalarType {
@@ -249,13 +249,11 @@ impl<U: UnitCore> Parse for UnitExp<U> {
         let exp;
 
         if input.parse::<Token![^]>().is_ok() {
-            neg = if input.parse::<Token![-]>().is_ok() {
-                true
-            } else if input.parse::<Token![+]>().is_ok() {
-                false
-            } else {
-                false
-            };
+            neg = input.parse::<Token![-]>().is_ok();
+
+            if !neg {
+                let _ = input.parse::<Token![+]>();
+            }
 
             exp = Some(input.parse()?);
         } else {
@@ -268,7 +266,7 @@ impl<U: UnitCore> Parse for UnitExp<U> {
 }
 
 impl<U: UnitCore> UnitExp<U> {
-    fn to_unit(self) -> Result<UnitSpec<U>> {
+    fn into_unit(self) -> Result<UnitSpec<U>> {
         let unit = match self.exp {
             Some(exp) => {
                 let a = exp.numerator();
@@ -361,19 +359,19 @@ impl<U: UnitCore> Parse for UnitSpec<U> {
     fn parse(input: ParseStream) -> Result<Self> {
         use syn::parse::discouraged::Speculative;
 
-        let mut unit: Self = input.parse::<UnitExp<U>>()?.to_unit()?;
+        let mut unit: Self = input.parse::<UnitExp<U>>()?.into_unit()?;
 
         loop {
             let fork = input.fork();
 
             unit = if fork.parse::<Token![/]>().is_ok() {
                 match fork.parse::<UnitExp<U>>() {
-                    Ok(rhs) => Self::Div(unit.into(), rhs.to_unit()?.into()),
+                    Ok(rhs) => Self::Div(unit.into(), rhs.into_unit()?.into()),
                     Err(..) => break,
                 }
             } else if fork.parse::<Token![*]>().is_ok() {
                 match fork.parse::<UnitExp<U>>() {
-                    Ok(rhs) => Self::Mul(unit.into(), rhs.to_unit()?.into()),
+                    Ok(rhs) => Self::Mul(unit.into(), rhs.into_unit()?.into()),
                     Err(..) => break,
                 }
             } else {
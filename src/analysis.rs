@@ -0,0 +1,236 @@
+//! Module for dimensional analysis of the variables in a problem.
+//!
+//! By the Buckingham π theorem, any physically meaningful relation between `N`
+//!     variables can be rewritten as a relation between `N - r` dimensionless
+//!     products of them, where `r` is the rank of their dimensions. Given the
+//!     [`DynDimension`] of each variable, [`PiGroups`] finds a basis for these
+//!     products, such as the Reynolds or Froude numbers.
+
+use num_traits::real::Real;
+use crate::{
+    dimension::{DynDimension, ExpInt, LEN},
+    error::DimensionError,
+    matrix::DimVector,
+    Value,
+};
+
+
+/// Return the greatest common divisor of two integers, which is never negative.
+const fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a.abs()
+}
+
+
+/// Divide every element of a row by their greatest common divisor.
+fn reduce<const N: usize>(row: &mut [i64; N]) {
+    let div = row.iter().fold(0, |acc, &x| gcd(acc, x));
+
+    if div > 1 {
+        row.iter_mut().for_each(|x| *x /= div);
+    }
+}
+
+
+/// Subtract a multiple of a pivot row from another row, such that the pivot
+///     column of the other row becomes zero.
+fn eliminate<const N: usize>(row: &mut [i64; N], pivot: &[i64; N], col: usize) {
+    let factor = row[col];
+
+    if factor != 0 {
+        for (x, p) in row.iter_mut().zip(pivot) {
+            *x = *x * pivot[col] - p * factor;
+        }
+
+        reduce(row);
+    }
+}
+
+
+/// A dimensionless product of variables, represented as the exponent of each
+///     variable.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct PiGroup<const N: usize> {
+    /// Exponents of the variables, in the order they were given.
+    pub exponents: [ExpInt; N],
+}
+
+impl<const N: usize> PiGroup<N> {
+    /// Return the dimension of this product, for variables with the given
+    ///     dimensions. This is [`DynDimension::ONE`] if the product is
+    ///     dimensionless.
+    pub fn dimension(&self, dims: &[DynDimension; N]) -> DynDimension {
+        dims.iter().zip(self.exponents).fold(
+            DynDimension::ONE,
+            |acc, (dim, exp)| acc * dim.pow(exp),
+        )
+    }
+
+    /// Evaluate this product on the values of a vector of variables, returning
+    ///     a plain value.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if the product is not dimensionless
+    ///     for the dimensions of the variables.
+    pub fn evaluate<V: Value + Real>(&self, vars: &DimVector<V, N>) -> Result<V, DimensionError> {
+        let found = self.dimension(&vars.dims);
+
+        if found != DynDimension::ONE {
+            return Err(DimensionError::Mismatch { expected: DynDimension::ONE, found });
+        }
+
+        Ok(vars.values.iter().zip(self.exponents).fold(
+            V::one(),
+            |acc, (&value, exp)| acc * value.powi(exp),
+        ))
+    }
+}
+
+
+/// A basis of the dimensionless products of a set of variables.
+///
+/// Variables given earlier are preferred as the "repeating" variables. Each
+///     group contains exactly one of the other variables, raised to a positive
+///     power, and the exponents of each group have no common divisor.
+///
+/// # Example
+/// ```
+/// use dana::{analysis::PiGroups, dimension::{Density, Length, Velocity}, matrix::*, prelude::*};
+///
+/// type Viscosity = dim!(<-1, 1,-1, 0, 0, 0, 0>);
+///
+/// //  Density, speed, and length of a flow, and the viscosity of the fluid.
+/// let dims = dims::<(Density, Velocity, Length, Viscosity)>();
+/// let pi = PiGroups::new(dims);
+///
+/// //  There is one group, which is the inverse of the Reynolds number.
+/// assert_eq!(pi.len(), 1);
+/// assert_eq!(pi.groups()[0].exponents, [-1, -1, -1, 1]);
+///
+/// let vars = DimVector::from_base(dims, [1000.0f64, 2.0, 0.05, 1e-3]);
+/// let re = pi.groups()[0].evaluate(&vars).unwrap().recip();
+/// assert!((re - 1e5).abs() < 1e-6);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PiGroups<const N: usize> {
+    dims: [DynDimension; N],
+    groups: [PiGroup<N>; N],
+    len: usize,
+}
+
+impl<const N: usize> PiGroups<N> {
+    /// Find a basis of the dimensionless products of variables with the given
+    ///     dimensions. Any [extension axes](crate::dimension::ExtAxis) are
+    ///     treated as further base axes.
+    pub fn new(dims: [DynDimension; N]) -> Self {
+        //  Scale every exponent by the same factor, so that they are all
+        //      integers. This does not change which products are dimensionless.
+        let den = dims.iter().fold(1, |acc, dim| {
+            let den = i64::from(dim.1);
+            acc / gcd(acc, den) * den
+        });
+
+        let base = (0..LEN).map(|axis| core::array::from_fn(|var| {
+            i64::from(dims[var].0[axis]) * (den / i64::from(dims[var].1))
+        }));
+
+        //  Every extension axis is another row, taken from the first variable
+        //      in which it appears.
+        let ext = dims.iter().enumerate()
+            .flat_map(|(i, dim)| dim.2.iter().map(move |ext| (i, ext.id)))
+            .filter(|&(i, id)| !dims[..i].iter().any(|dim| dim.2.iter().any(|ext| ext.id == id)))
+            .map(|(_, id)| core::array::from_fn(|var| {
+                dims[var].2.iter()
+                    .find(|ext| ext.id == id)
+                    .map_or(0, |ext| i64::from(ext.exp))
+            }));
+
+        //  Fraction-free Gauss-Jordan elimination, one row at a time, so that
+        //      every pivot column is zero outside of its pivot row. There can
+        //      be no more independent rows than there are variables.
+        let mut rows = [[0; N]; N];
+        let mut pivots = [0; N];
+        let mut rank = 0;
+
+        for mut new in base.chain(ext) {
+            if rank == N { break; }
+
+            for (&col, row) in pivots[..rank].iter().zip(&rows) {
+                eliminate(&mut new, row, col);
+            }
+
+            let Some(col) = (0..N).find(|&col| new[col] != 0) else {
+                continue;
+            };
+
+            for row in &mut rows[..rank] {
+                eliminate(row, &new, col);
+            }
+
+            rows[rank] = new;
+            pivots[rank] = col;
+            rank += 1;
+        }
+
+        //  Each column without a pivot yields one group.
+        let mut groups = [PiGroup { exponents: [0; N] }; N];
+        let mut len = 0;
+
+        for free in (0..N).filter(|col| !pivots[..rank].contains(col)) {
+            let scale = pivots[..rank].iter().zip(&rows)
+                .map(|(&col, row)| row[col].abs())
+                .fold(1, |acc, p| acc / gcd(acc, p) * p);
+
+            let mut exps = [0; N];
+            exps[free] = scale;
+
+            for (&col, row) in pivots[..rank].iter().zip(&rows) {
+                exps[col] = -row[free] * scale / row[col];
+            }
+
+            reduce(&mut exps);
+            groups[len].exponents = exps.map(|exp| exp as ExpInt);
+            len += 1;
+        }
+
+        Self { dims, groups, len }
+    }
+
+    /// Return the dimensions of the variables.
+    pub const fn dims(&self) -> &[DynDimension; N] { &self.dims }
+
+    /// Return the groups found.
+    pub fn groups(&self) -> &[PiGroup<N>] { &self.groups[..self.len] }
+
+    /// Return the number of groups found.
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Return `true` if no groups were found, meaning that no product of the
+    ///     variables is dimensionless.
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Evaluate every group on the values of a vector of variables, returning
+    ///     an array of plain values. Entries past [`len`](Self::len) are zero.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if the dimensions of the variables
+    ///     are not the dimensions these groups were found for.
+    pub fn evaluate<V: Value + Real>(&self, vars: &DimVector<V, N>) -> Result<[V; N], DimensionError> {
+        for (&expected, &found) in self.dims.iter().zip(&vars.dims) {
+            if expected != found {
+                return Err(DimensionError::Mismatch { expected, found });
+            }
+        }
+
+        let mut out = [V::zero(); N];
+
+        for (out, group) in out.iter_mut().zip(self.groups()) {
+            *out = group.evaluate(vars)?;
+        }
+
+        Ok(out)
+    }
+}
@@ -1,11 +1,12 @@
 //! Module for base dimensions underlying the unit system.
 
-use core::{marker::PhantomData, ops::{Add, Div, Mul, Neg, Sub}};
+use core::{marker::PhantomData, ops::{Div, Mul, Neg}};
 use num_traits::Inv;
 use typenum::{
-    marker_traits::{Integer, NonZero},
-    PartialDiv,
+    marker_traits::{Integer, NonZero, Unsigned},
+    NInt, P1, PInt, Prod,
 };
+use ratio::{CrossDiff, CrossSum, Reduce, Reduced};
 
 
 dummy!(
@@ -13,6 +14,11 @@ dummy!(
     pub trait Int: Integer
 );
 
+dummy!(
+    /// Trait bound for the denominator parameter of a [`Dimension`].
+    pub trait Den: Int + NonZero
+);
+
 
 /// Integer type used for dimension exponents.
 pub type ExpInt = i32;
@@ -48,23 +54,67 @@ pub type Power        = dim!(< 2, 1,-3, 0, 0, 0, 0>);
 pub type Voltage      = dim!(< 2, 1,-3,-1, 0, 0, 0>);
 pub type Resistance   = dim!(< 2, 1,-3,-2, 0, 0, 0>);
 pub type Capacitance  = dim!(<-2,-1, 4, 2, 0, 0, 0>);
+pub type Inductance   = dim!(< 2, 1,-2,-2, 0, 0, 0>);
 //                             L  M  T  I  Θ  N  J
 
 
+/// Names of the dimensions defined in this module, paired with their runtime
+///     representations. Where several dimensions have the same exponents, such
+///     as [`Energy`] and [`Torque`], the first is used by [`DynDimension::name`].
+pub const NAMES: [(&str, DynDimension); 24] = [
+    ("One",         DynDimension::of::<One>()),
+    ("Length",      DynDimension::of::<Length>()),
+    ("Mass",        DynDimension::of::<Mass>()),
+    ("Time",        DynDimension::of::<Time>()),
+    ("Current",     DynDimension::of::<Current>()),
+    ("Temp",        DynDimension::of::<Temp>()),
+    ("Amount",      DynDimension::of::<Amount>()),
+    ("Intensity",   DynDimension::of::<Intensity>()),
+    ("Frequency",   DynDimension::of::<Frequency>()),
+    ("Velocity",    DynDimension::of::<Velocity>()),
+    ("Accel",       DynDimension::of::<Accel>()),
+    ("Force",       DynDimension::of::<Force>()),
+    ("Pressure",    DynDimension::of::<Pressure>()),
+    ("Area",        DynDimension::of::<Area>()),
+    ("Volume",      DynDimension::of::<Volume>()),
+    ("Density",     DynDimension::of::<Density>()),
+    ("Charge",      DynDimension::of::<Charge>()),
+    ("Energy",      DynDimension::of::<Energy>()),
+    ("Torque",      DynDimension::of::<Torque>()),
+    ("Power",       DynDimension::of::<Power>()),
+    ("Voltage",     DynDimension::of::<Voltage>()),
+    ("Resistance",  DynDimension::of::<Resistance>()),
+    ("Capacitance", DynDimension::of::<Capacitance>()),
+    ("Inductance",  DynDimension::of::<Inductance>()),
+];
+
+
 /// Zero-size type that serves as a type-level array of exponents.
+///
+/// The exponents are fractions over the common denominator `Q`, which is
+///     positive and kept in lowest terms. For a dimension whose exponents are
+///     all integers, such as any of those defined by [`dim!`], `Q` is [`P1`].
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub struct Dimension<L: Int, M: Int, T: Int, I: Int, K: Int, N: Int, J: Int> {
+pub struct Dimension<
+    L: Int, M: Int, T: Int, I: Int, K: Int, N: Int, J: Int,
+    Q: Den = P1,
+    X: ExtList = (),
+> {
     _l: PhantomData<L>, _m: PhantomData<M>, _t: PhantomData<T>,
     _i: PhantomData<I>, _k: PhantomData<K>, _n: PhantomData<N>,
     _j: PhantomData<J>,
+    _q: PhantomData<Q>,
+    _x: PhantomData<X>,
 }
 
-impl<L: Int, M: Int, T: Int, I: Int, K: Int, N: Int, J: Int>
-Dimension<L, M, T, I, K, N, J> {
+impl<L: Int, M: Int, T: Int, I: Int, K: Int, N: Int, J: Int, Q: Den, X: ExtList>
+Dimension<L, M, T, I, K, N, J, Q, X> {
     pub const fn new() -> Self { Self {
         _l: PhantomData, _m: PhantomData, _t: PhantomData,
         _i: PhantomData, _k: PhantomData, _n: PhantomData,
         _j: PhantomData,
+        _q: PhantomData,
+        _x: PhantomData,
     }}
 }
 
@@ -75,12 +125,31 @@ mod private {
 }
 
 
-impl<L: Int, M: Int, T: Int, I: Int, Θ: Int, N: Int, J: Int>
-Sealed for Dimension<L, M, T, I, Θ, N, J> {}
+mod extension;
+mod ratio;
+
+pub use extension::{
+    AxisDim, DimensionExt, DynExt, DynExtExp, EXT_MAX, ExtAxis, ExtCons, ExtExp, ExtList,
+};
+
+#[cfg(feature = "const_dim")]
+mod const_dim;
+#[cfg(feature = "const_dim")]
+pub use const_dim::{
+    Assert, ConstBit, ConstDim, ConstExp, ConstPos, ConstUint, IsTrue, pack, PackedExp,
+    ToBit, ToInt, ToPos, ToUnsigned, unpack,
+};
+
+
+impl<L: Int, M: Int, T: Int, I: Int, Θ: Int, N: Int, J: Int, Q: Den, X: ExtList>
+Sealed for Dimension<L, M, T, I, Θ, N, J, Q, X> {}
 
 
 /// Trait specifying a type to be a [`Dimension`] with arbitrary exponents.
-pub trait DimType: Sealed + Copy + core::fmt::Display {
+///
+/// This is implemented by [`Dimension`], and, with the `const_dim` feature, by
+///     the alternative `ConstDim` type.
+pub trait DimType: Sealed + Copy + core::fmt::Display + 'static {
     //region Definitions.
     /// Exponent typenum for Length.
     type ExpLen: Int;
@@ -97,6 +166,9 @@ pub trait DimType: Sealed + Copy + core::fmt::Display {
     /// Exponent typenum for Luminous Intensity.
     type ExpLum: Int;
 
+    /// Denominator typenum of the exponents.
+    type ExpDen: Den;
+
     /// Exponent constant for Length.
     const EXP_LEN:  ExpInt = <Self::ExpLen as Integer>::I32;
     /// Exponent constant for Mass.
@@ -111,10 +183,18 @@ pub trait DimType: Sealed + Copy + core::fmt::Display {
     const EXP_AMT:  ExpInt = <Self::ExpAmt as Integer>::I32;
     /// Exponent constant for Luminous Intensity.
     const EXP_LUM:  ExpInt = <Self::ExpLum as Integer>::I32;
+
+    /// Common denominator of the exponent constants, which is always positive.
+    ///     Each exponent of the dimension is its constant divided by this.
+    const EXP_DEN:  ExpInt = <Self::ExpDen as Integer>::I32;
+
+    /// Exponents of user-defined [extension axes](ExtAxis), if there are any.
+    const EXT: Option<&'static ExtExp> = None;
     //endregion
 
     //region Arrays.
-    /// Exponents of the seven fundamental quantities.
+    /// Exponents of the seven fundamental quantities, as numerators over
+    ///     [`EXP_DEN`](Self::EXP_DEN).
     const ARRAY: [ExpInt; LEN] = [
         Self::EXP_LEN,
         Self::EXP_MASS,
@@ -141,8 +221,8 @@ pub trait DimType: Sealed + Copy + core::fmt::Display {
     fn dimension() -> Self;
 }
 
-impl<L: Int, M: Int, T: Int, I: Int, K: Int, N: Int, J: Int> DimType
-for Dimension<L, M, T, I, K, N, J> {
+impl<L: Int, M: Int, T: Int, I: Int, K: Int, N: Int, J: Int, Q: Den, X: ExtList> DimType
+for Dimension<L, M, T, I, K, N, J, Q, X> {
     type ExpLen = L;
     type ExpMass = M;
     type ExpTime = T;
@@ -151,60 +231,168 @@ for Dimension<L, M, T, I, K, N, J> {
     type ExpAmt = N;
     type ExpLum = J;
 
+    type ExpDen = Q;
+
+    const EXT: Option<&'static ExtExp> = X::EXT;
+
     fn dimension() -> Self { Self::new() }
 }
 
 
-impl<L: Int, M: Int, T: Int, I: Int, K: Int, N: Int, J: Int> core::fmt::Display
-for Dimension<L, M, T, I, K, N, J> {
+impl<L: Int, M: Int, T: Int, I: Int, K: Int, N: Int, J: Int, Q: Den, X: ExtList> core::fmt::Display
+for Dimension<L, M, T, I, K, N, J, Q, X> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        use core::fmt::Write;
+        let ext = X::EXT.into_iter().flat_map(ExtExp::iter);
+        fmt_exponents(f, Self::ARRAY, Self::EXP_DEN, ext.map(|ext| (ext.symbol, ext.exp)))
+    }
+}
 
-        let mut any = false;
 
-        for (char, exp) in Self::CHARS {
-            if exp != 0 {
-                if any {
-                    f.write_char('*')?;
-                } else {
-                    any = true;
-                }
+/// Return the greatest common divisor of two exponents, which is never
+///     negative.
+const fn gcd(mut a: ExpInt, mut b: ExpInt) -> ExpInt {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a.abs()
+}
 
-                f.write_char(char)?;
 
-                if exp != 1 {
-                    write!(f, "^{}", exp)?;
-                }
+/// Write an array of exponents over a common denominator, followed by any
+///     exponents of extension axes, in the form `L^2*M*T^-2`, or `L^(1/2)*T^-1`
+///     where an exponent is not an integer.
+///
+/// With the alternate flag, the form is instead `L²MT⁻²` or `L¹ᐟ²T⁻¹`, and a
+///     scalar dimension is written as `1`.
+fn fmt_exponents(
+    f: &mut core::fmt::Formatter<'_>,
+    array: [ExpInt; LEN],
+    den: ExpInt,
+    ext: impl Iterator<Item=(&'static str, ExpInt)>,
+) -> core::fmt::Result {
+    use core::fmt::Write;
+
+    const CHARS: [char; LEN] = ['L', 'M', 'T', 'I', 'Θ', 'N', 'J'];
+    let alt = f.alternate();
+    let mut any = false;
+
+    for (char, exp) in CHARS.into_iter().zip(array) {
+        if exp != 0 {
+            if any && !alt {
+                f.write_char('*')?;
             }
+
+            any = true;
+            f.write_char(char)?;
+
+            let div = gcd(exp, den);
+            fmt_exponent(f, exp / div, den / div)?;
+        }
+    }
+
+    for (symbol, exp) in ext {
+        if any {
+            f.write_char(if alt { '·' } else { '*' })?;
+        }
+
+        any = true;
+        f.write_str(symbol)?;
+        fmt_exponent(f, exp, 1)?;
+    }
+
+    if alt && !any {
+        f.write_char('1')?;
+    }
+
+    Ok(())
+}
+
+
+/// Write a single exponent in lowest terms, unless it is one. With the
+///     alternate flag, the exponent is written in superscript.
+fn fmt_exponent(
+    f: &mut core::fmt::Formatter<'_>,
+    num: ExpInt,
+    den: ExpInt,
+) -> core::fmt::Result {
+    use core::fmt::Write;
+
+    fn superscript(f: &mut core::fmt::Formatter<'_>, n: ExpInt) -> core::fmt::Result {
+        const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+        if n < 0 {
+            f.write_char('⁻')?;
+        }
+
+        let n = n.unsigned_abs();
+        let mut place = 1;
+
+        while place <= n / 10 {
+            place *= 10;
+        }
+
+        while place > 0 {
+            f.write_char(DIGITS[(n / place % 10) as usize])?;
+            place /= 10;
         }
 
         Ok(())
     }
+
+    if f.alternate() {
+        if den != 1 {
+            superscript(f, num)?;
+            f.write_char('ᐟ')?;
+            superscript(f, den)?;
+        } else if num != 1 {
+            superscript(f, num)?;
+        }
+    } else if den != 1 {
+        write!(f, "^({}/{})", num, den)?;
+    } else if num != 1 {
+        write!(f, "^{}", num)?;
+    }
+
+    Ok(())
 }
 
 
 /// Division.
 impl<
-    L1: Int, M1: Int, T1: Int, I1: Int, K1: Int, N1: Int, J1: Int,
-    L2: Int, M2: Int, T2: Int, I2: Int, K2: Int, N2: Int, J2: Int,
-> Div<Dimension<L2, M2, T2, I2, K2, N2, J2>>
-for Dimension<L1, M1, T1, I1, K1, N1, J1> where
-    L1: Sub<L2>, L1::Output: Int,
-    M1: Sub<M2>, M1::Output: Int,
-    T1: Sub<T2>, T1::Output: Int,
-    I1: Sub<I2>, I1::Output: Int,
-    K1: Sub<K2>, K1::Output: Int,
-    N1: Sub<N2>, N1::Output: Int,
-    J1: Sub<J2>, J1::Output: Int,
+    L1: Int, M1: Int, T1: Int, I1: Int, K1: Int, N1: Int, J1: Int, Q1: Den, X1: ExtList,
+    L2: Int, M2: Int, T2: Int, I2: Int, K2: Int, N2: Int, J2: Int, Q2: Den, X2: ExtList,
+> Div<Dimension<L2, M2, T2, I2, K2, N2, J2, Q2, X2>>
+for Dimension<L1, M1, T1, I1, K1, N1, J1, Q1, X1> where
+    L1: ratio::CrossSub<Q1, L2, Q2>,
+    M1: ratio::CrossSub<Q1, M2, Q2>,
+    T1: ratio::CrossSub<Q1, T2, Q2>,
+    I1: ratio::CrossSub<Q1, I2, Q2>,
+    K1: ratio::CrossSub<Q1, K2, Q2>,
+    N1: ratio::CrossSub<Q1, N2, Q2>,
+    J1: ratio::CrossSub<Q1, J2, Q2>,
+    Q1: Mul<Q2>, Prod<Q1, Q2>: Den,
+    X1: extension::ExtDiv<X2>,
+    Dimension<
+        CrossDiff<L1, Q1, L2, Q2>, CrossDiff<M1, Q1, M2, Q2>,
+        CrossDiff<T1, Q1, T2, Q2>, CrossDiff<I1, Q1, I2, Q2>,
+        CrossDiff<K1, Q1, K2, Q2>, CrossDiff<N1, Q1, N2, Q2>,
+        CrossDiff<J1, Q1, J2, Q2>,
+        Prod<Q1, Q2>,
+        <X1 as extension::ExtDiv<X2>>::Output,
+    >: Reduce,
 {
-    type Output = Dimension<
-        L1::Output, M1::Output, T1::Output,
-        I1::Output, K1::Output, N1::Output,
-        J1::Output,
-    >;
-
-    fn div(self, _: Dimension<L2, M2, T2, I2, K2, N2, J2>) -> Self::Output {
-        Default::default()
+    type Output = Reduced<Dimension<
+        CrossDiff<L1, Q1, L2, Q2>, CrossDiff<M1, Q1, M2, Q2>,
+        CrossDiff<T1, Q1, T2, Q2>, CrossDiff<I1, Q1, I2, Q2>,
+        CrossDiff<K1, Q1, K2, Q2>, CrossDiff<N1, Q1, N2, Q2>,
+        CrossDiff<J1, Q1, J2, Q2>,
+        Prod<Q1, Q2>,
+        <X1 as extension::ExtDiv<X2>>::Output,
+    >>;
+
+    fn div(self, _: Dimension<L2, M2, T2, I2, K2, N2, J2, Q2, X2>) -> Self::Output {
+        DimType::dimension()
     }
 }
 
@@ -223,26 +411,39 @@ impl<A: DimType, B: DimType> CanDimDiv<B> for A where
 
 /// Multiplication.
 impl<
-    L1: Int, M1: Int, T1: Int, I1: Int, K1: Int, N1: Int, J1: Int,
-    L2: Int, M2: Int, T2: Int, I2: Int, K2: Int, N2: Int, J2: Int,
-> Mul<Dimension<L2, M2, T2, I2, K2, N2, J2>>
-for Dimension<L1, M1, T1, I1, K1, N1, J1> where
-    L1: Add<L2>, L1::Output: Int,
-    M1: Add<M2>, M1::Output: Int,
-    T1: Add<T2>, T1::Output: Int,
-    I1: Add<I2>, I1::Output: Int,
-    K1: Add<K2>, K1::Output: Int,
-    N1: Add<N2>, N1::Output: Int,
-    J1: Add<J2>, J1::Output: Int,
+    L1: Int, M1: Int, T1: Int, I1: Int, K1: Int, N1: Int, J1: Int, Q1: Den, X1: ExtList,
+    L2: Int, M2: Int, T2: Int, I2: Int, K2: Int, N2: Int, J2: Int, Q2: Den, X2: ExtList,
+> Mul<Dimension<L2, M2, T2, I2, K2, N2, J2, Q2, X2>>
+for Dimension<L1, M1, T1, I1, K1, N1, J1, Q1, X1> where
+    L1: ratio::CrossAdd<Q1, L2, Q2>,
+    M1: ratio::CrossAdd<Q1, M2, Q2>,
+    T1: ratio::CrossAdd<Q1, T2, Q2>,
+    I1: ratio::CrossAdd<Q1, I2, Q2>,
+    K1: ratio::CrossAdd<Q1, K2, Q2>,
+    N1: ratio::CrossAdd<Q1, N2, Q2>,
+    J1: ratio::CrossAdd<Q1, J2, Q2>,
+    Q1: Mul<Q2>, Prod<Q1, Q2>: Den,
+    X1: extension::ExtMul<X2>,
+    Dimension<
+        CrossSum<L1, Q1, L2, Q2>, CrossSum<M1, Q1, M2, Q2>,
+        CrossSum<T1, Q1, T2, Q2>, CrossSum<I1, Q1, I2, Q2>,
+        CrossSum<K1, Q1, K2, Q2>, CrossSum<N1, Q1, N2, Q2>,
+        CrossSum<J1, Q1, J2, Q2>,
+        Prod<Q1, Q2>,
+        <X1 as extension::ExtMul<X2>>::Output,
+    >: Reduce,
 {
-    type Output = Dimension<
-        L1::Output, M1::Output, T1::Output,
-        I1::Output, K1::Output, N1::Output,
-        J1::Output,
-    >;
-
-    fn mul(self, _: Dimension<L2, M2, T2, I2, K2, N2, J2>) -> Self::Output {
-        Default::default()
+    type Output = Reduced<Dimension<
+        CrossSum<L1, Q1, L2, Q2>, CrossSum<M1, Q1, M2, Q2>,
+        CrossSum<T1, Q1, T2, Q2>, CrossSum<I1, Q1, I2, Q2>,
+        CrossSum<K1, Q1, K2, Q2>, CrossSum<N1, Q1, N2, Q2>,
+        CrossSum<J1, Q1, J2, Q2>,
+        Prod<Q1, Q2>,
+        <X1 as extension::ExtMul<X2>>::Output,
+    >>;
+
+    fn mul(self, _: Dimension<L2, M2, T2, I2, K2, N2, J2, Q2, X2>) -> Self::Output {
+        DimType::dimension()
     }
 }
 
@@ -264,7 +465,9 @@ impl<
     L: Int + Neg, M: Int + Neg, T: Int + Neg,
     I: Int + Neg, K: Int + Neg, N: Int + Neg,
     J: Int + Neg,
-> Inv for Dimension<L, M, T, I, K, N, J> where
+    Q: Den,
+    X: ExtList + extension::ExtInv,
+> Inv for Dimension<L, M, T, I, K, N, J, Q, X> where
     L::Output: Int, M::Output: Int, T::Output: Int,
     I::Output: Int, K::Output: Int, N::Output: Int,
     J::Output: Int,
@@ -273,6 +476,8 @@ impl<
         L::Output, M::Output, T::Output,
         I::Output, K::Output, N::Output,
         J::Output,
+        Q,
+        X::Output,
     >;
 
     fn inv(self) -> Self::Output { Default::default() }
@@ -301,41 +506,96 @@ impl<
     L: Int + Mul<E>, M: Int + Mul<E>, T: Int + Mul<E>,
     I: Int + Mul<E>, K: Int + Mul<E>, N: Int + Mul<E>,
     J: Int + Mul<E>,
+    Q: Den,
+    X: ExtList + extension::ExtPow<E>,
     E: Int,
-> CanDimPowType<E> for Dimension<L, M, T, I, K, N, J> where
+> CanDimPowType<E> for Dimension<L, M, T, I, K, N, J, Q, X> where
     L::Output: Int, M::Output: Int, T::Output: Int,
     I::Output: Int, K::Output: Int, N::Output: Int,
     J::Output: Int,
+    Dimension<
+        L::Output, M::Output, T::Output,
+        I::Output, K::Output, N::Output,
+        J::Output,
+        Q,
+        X::Output,
+    >: Reduce,
 {
-    type Output = Dimension<
+    type Output = Reduced<Dimension<
         L::Output, M::Output, T::Output,
         I::Output, K::Output, N::Output,
         J::Output,
-    >;
+        Q,
+        X::Output,
+    >>;
 }
 
 
 /// Indicates that a [`Dimension`] may be taken to a [`NonZero`] [`Integer`] root.
+///
+/// For a [`Dimension`], this is always possible, because the exponents of the
+///     output may be fractions.
 pub trait CanDimRootType<D: Int + NonZero>: DimType {
     /// The output of the operation.
     type Output: DimType;
 }
 
+/// Positive roots, which multiply the denominator.
 impl<
-    L: Int + PartialDiv<D>, M: Int + PartialDiv<D>, T: Int + PartialDiv<D>,
-    I: Int + PartialDiv<D>, K: Int + PartialDiv<D>, N: Int + PartialDiv<D>,
-    J: Int + PartialDiv<D>,
-    D: Int + NonZero,
-> CanDimRootType<D> for Dimension<L, M, T, I, K, N, J> where
+    L: Int, M: Int, T: Int, I: Int, K: Int, N: Int, J: Int,
+    Q: Den + Mul<PInt<U>>,
+    X: ExtList + extension::ExtRoot<PInt<U>>,
+    U: Unsigned + NonZero,
+> CanDimRootType<PInt<U>> for Dimension<L, M, T, I, K, N, J, Q, X> where
+    Prod<Q, PInt<U>>: Den,
+    Dimension<L, M, T, I, K, N, J, Prod<Q, PInt<U>>, X::Output>: Reduce,
+{
+    type Output = Reduced<Dimension<L, M, T, I, K, N, J, Prod<Q, PInt<U>>, X::Output>>;
+}
+
+/// Negative roots, which also negate the exponents, so that the denominator
+///     stays positive.
+impl<
+    L: Int + Neg, M: Int + Neg, T: Int + Neg,
+    I: Int + Neg, K: Int + Neg, N: Int + Neg,
+    J: Int + Neg,
+    Q: Den + Mul<PInt<U>>,
+    X: ExtList + extension::ExtRoot<NInt<U>>,
+    U: Unsigned + NonZero,
+> CanDimRootType<NInt<U>> for Dimension<L, M, T, I, K, N, J, Q, X> where
     L::Output: Int, M::Output: Int, T::Output: Int,
     I::Output: Int, K::Output: Int, N::Output: Int,
     J::Output: Int,
+    Prod<Q, PInt<U>>: Den,
+    Dimension<
+        L::Output, M::Output, T::Output,
+        I::Output, K::Output, N::Output,
+        J::Output,
+        Prod<Q, PInt<U>>,
+        X::Output,
+    >: Reduce,
 {
-    type Output = Dimension<
+    type Output = Reduced<Dimension<
         L::Output, M::Output, T::Output,
         I::Output, K::Output, N::Output,
         J::Output,
-    >;
+        Prod<Q, PInt<U>>,
+        X::Output,
+    >>;
+}
+
+
+/// Indicates that a [`Dimension`] may be raised to a fractional power, given as
+///     an [`Integer`] numerator and a positive [`Integer`] denominator.
+pub trait CanDimPowRatio<E: Int, Q: Den>: DimType {
+    /// The output of the operation.
+    type Output: DimType;
+}
+
+impl<D: CanDimPowType<E>, E: Int, Q: Den> CanDimPowRatio<E, Q> for D where
+    <D as CanDimPowType<E>>::Output: CanDimRootType<Q>,
+{
+    type Output = <<D as CanDimPowType<E>>::Output as CanDimRootType<Q>>::Output;
 }
 
 
@@ -343,6 +603,11 @@ impl<
 pub struct Exponent<const E: ExpInt>;
 
 /// Trait for associating a type with a specific [`typenum`] [`Integer`].
+#[diagnostic::on_unimplemented(
+    message = "exponent `{Self}` is out of range",
+    label = "exponent out of range",
+    note = "exponents of `typenum` dimensions must be in the range `-64..=64`",
+)]
 pub trait HasTypenum {
     /// The [`Integer`] equivalent of this type.
     type Typenum: Integer;
@@ -352,16 +617,23 @@ dana_macros::impl_typenums!();
 
 
 /// Indicates that a [`Dimension`] may be raised to an arbitrary `i32` power.
+#[diagnostic::on_unimplemented(
+    message = "cannot raise dimension `{Self}` to the power of {E}",
+    label = "exponent out of range",
+)]
 pub trait CanDimPow<const E: ExpInt>: DimType {
     /// The output of the operation.
     type Output: DimType;
 }
 
-impl<Dim: DimType, const E: ExpInt> CanDimPow<E> for Dim where
+impl<
+    L: Int, M: Int, T: Int, I: Int, K: Int, N: Int, J: Int, Q: Den, X: ExtList,
+    const E: ExpInt,
+> CanDimPow<E> for Dimension<L, M, T, I, K, N, J, Q, X> where
     Exponent<E>: HasTypenum,
-    Dim: CanDimPowType<<Exponent<E> as HasTypenum>::Typenum>,
+    Self: CanDimPowType<<Exponent<E> as HasTypenum>::Typenum>,
 {
-    type Output = Dim::Output;
+    type Output = <Self as CanDimPowType<<Exponent<E> as HasTypenum>::Typenum>>::Output;
 }
 
 
@@ -371,10 +643,157 @@ pub trait CanDimRoot<const D: ExpInt>: DimType {
     type Output: DimType;
 }
 
-impl<Dim: DimType, const D: ExpInt> CanDimRoot<D> for Dim where
+impl<
+    L: Int, M: Int, T: Int, I: Int, K: Int, N: Int, J: Int, Q: Den, X: ExtList,
+    const D: ExpInt,
+> CanDimRoot<D> for Dimension<L, M, T, I, K, N, J, Q, X> where
     Exponent<D>: HasTypenum,
     <Exponent<D> as HasTypenum>::Typenum: NonZero,
-    Dim: CanDimRootType<<Exponent<D> as HasTypenum>::Typenum>,
+    Self: CanDimRootType<<Exponent<D> as HasTypenum>::Typenum>,
 {
-    type Output = Dim::Output;
+    type Output = <Self as CanDimRootType<<Exponent<D> as HasTypenum>::Typenum>>::Output;
+}
+
+
+/// Runtime representation of a [`Dimension`], as an array of exponents over a
+///     common denominator, followed by the exponents of any
+///     [extension axes](ExtAxis).
+///
+/// This is useful where dimensions can only be known at runtime, such as when
+///     a collection holds values of several different dimensions. As with
+///     [`Dimension`], the denominator is positive, and the exponents are kept
+///     in lowest terms. The exponents of extension axes are always integers,
+///     and are not affected by the denominator.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DynDimension(pub [ExpInt; LEN], pub ExpInt, pub DynExt);
+
+impl DynDimension {
+    /// The scalar dimension, with all exponents zero.
+    pub const ONE: Self = Self([0; LEN], 1, DynExt::NONE);
+
+    /// Return the runtime representation of a [`DimType`].
+    ///
+    /// # Panics
+    /// This function will panic if the dimension has more than [`EXT_MAX`]
+    ///     extension axes.
+    pub const fn of<D: DimType>() -> Self { Self(D::ARRAY, D::EXP_DEN, DynExt::of(D::EXT)) }
+
+    /// Return `true` if this dimension is [scalar](Self::ONE).
+    pub fn is_one(&self) -> bool { *self == Self::ONE }
+
+    /// Return `true` if this dimension is the same as a [`DimType`].
+    pub fn is<D: DimType>(&self) -> bool { *self == Self::of::<D>() }
+
+    /// Return `true` if every exponent of this dimension is an integer.
+    pub fn is_integer(&self) -> bool { self.1 == 1 }
+
+    /// Return the name of this dimension, if it is one of those listed in
+    ///     [`NAMES`].
+    pub fn name(&self) -> Option<&'static str> {
+        NAMES.iter().find(|(_, dim)| dim == self).map(|&(name, _)| name)
+    }
+
+    /// Return a wrapper that displays this dimension with its [name](Self::name),
+    ///     in the form `Energy [L²MT⁻²]`.
+    pub const fn named(self) -> NamedDim { NamedDim { dim: self } }
+
+    /// Raise this dimension to an integer power.
+    pub fn pow(self, exp: ExpInt) -> Self {
+        Self(self.0.map(|e| e * exp), self.1, self.2.map(|e| e * exp)).reduced()
+    }
+
+    /// Take this dimension to an integer root.
+    ///
+    /// # Panics
+    /// This method will panic if `deg` is zero, or if it does not divide the
+    ///     exponent of every extension axis.
+    pub fn root(self, deg: ExpInt) -> Self {
+        assert_ne!(deg, 0, "cannot take the zeroth root of a dimension");
+        let ext = self.2.map(|e| {
+            assert_eq!(e % deg, 0, "cannot take a fractional power of an extension axis");
+            e / deg
+        });
+
+        Self(self.0.map(|e| e * deg.signum()), self.1 * deg.abs(), ext).reduced()
+    }
+
+    /// Add the exponents of another dimension to those of this one.
+    fn sum(self, rhs: Self) -> Self {
+        Self(
+            core::array::from_fn(|i| self.0[i] * rhs.1 + rhs.0[i] * self.1),
+            self.1 * rhs.1,
+            self.2.sum(rhs.2),
+        ).reduced()
+    }
+
+    /// Reduce the exponents of this dimension to lowest terms.
+    fn reduced(self) -> Self {
+        let div = self.0.into_iter().fold(self.1, gcd);
+        Self(self.0.map(|e| e / div), self.1 / div, self.2)
+    }
+}
+
+impl Default for DynDimension {
+    fn default() -> Self { Self::ONE }
+}
+
+impl<D: DimType> From<D> for DynDimension {
+    fn from(_: D) -> Self { Self::of::<D>() }
+}
+
+impl core::fmt::Display for DynDimension {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_exponents(f, self.0, self.1, self.2.iter().map(|ext| (ext.symbol, ext.exp)))
+    }
+}
+
+impl Div for DynDimension {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output { self.sum(rhs.inv()) }
+}
+
+impl Mul for DynDimension {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output { self.sum(rhs) }
+}
+
+impl Inv for DynDimension {
+    type Output = Self;
+
+    fn inv(self) -> Self::Output { Self(self.0.map(Neg::neg), self.1, self.2.map(Neg::neg)) }
+}
+
+
+/// Wrapper that displays a dimension with its name, if it has one, followed by
+///     its exponents in superscript, such as `Energy [L²MT⁻²]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NamedDim {
+    dim: DynDimension,
+}
+
+impl NamedDim {
+    /// Return a wrapper for a [`DimType`], including any exponents of
+    ///     [extension axes](ExtAxis).
+    pub const fn of<D: DimType>() -> Self { DynDimension::of::<D>().named() }
+
+    /// Return the name of the wrapped dimension, if it has one. A dimension
+    ///     with any [extension axes](ExtAxis) never has a name.
+    pub fn name(&self) -> Option<&'static str> { self.dim.name() }
+}
+
+impl From<DynDimension> for NamedDim {
+    fn from(dim: DynDimension) -> Self { dim.named() }
+}
+
+impl core::fmt::Display for NamedDim {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(name) = self.name() {
+            write!(f, "{name} ")?;
+        }
+
+        write!(f, "[{:#}]", self.dim)
+    }
 }
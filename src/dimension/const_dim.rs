@@ -0,0 +1,413 @@
+//! Module for the const-generic dimension representation.
+//!
+//! This representation packs the seven exponents of a dimension into a single
+//!     const generic integer, rather than storing them as [`typenum`] integers.
+//!     Dimension types are then a single constant instead of seven nested
+//!     binary type trees, and each operation evaluates one constant expression
+//!     instead of resolving a trait for every exponent. Exponents are limited
+//!     to the range of `i16`, rather than the range of
+//!     [`HasTypenum`](super::HasTypenum). Unlike [`Dimension`], exponents are
+//!     always integers, so a root may only be taken where every exponent is
+//!     divisible by its degree, and there are no extension axes.
+//!
+//! [`ConstDim`] is an alternative to [`Dimension`], rather than a replacement.
+//!     The [`dim!`](crate::dim) macro and the dimension aliases still produce
+//!     [`Dimension`] types, and a [`ConstDim`] is named directly, with [`pack`]:
+//! ```
+//! #![feature(generic_const_exprs)]
+//! # #![allow(incomplete_features)]
+//! use dana::dimension::{ConstDim, DimType, pack};
+//!
+//! type Length = ConstDim<{ pack([1, 0,  0, 0, 0, 0, 0]) }>;
+//! type Time   = ConstDim<{ pack([0, 0,  1, 0, 0, 0, 0]) }>;
+//! type Speed  = ConstDim<{ pack([1, 0, -1, 0, 0, 0, 0]) }>;
+//!
+//! let _: Length = Speed::dimension() * Time::dimension();
+//! ```
+//!
+//! This requires the `generic_const_exprs` nightly feature, which must also be
+//!     enabled in any crate that performs operations on [`ConstDim`] types.
+//!     Because that feature does not yet normalize constant expressions
+//!     eagerly, the results of long chains of operations should be given
+//!     concrete types, as above.
+//!
+//! The `benches/compile_dim.sh` script compares the time taken to check a
+//!     crate with each representation.
+
+use core::ops::{Div, Mul};
+use num_traits::Inv;
+use typenum::NonZero;
+use super::*;
+
+
+/// Integer type holding the packed exponents of a [`ConstDim`].
+pub type PackedExp = u128;
+
+/// Number of bits holding each exponent.
+const BITS: u32 = 16;
+
+/// Offset added to each exponent, so that it can be stored unsigned.
+const BIAS: ExpInt = 1 << (BITS - 1);
+
+
+/// Zero-size type that serves as a const-generic array of exponents, packed
+///     into a single integer by [`pack`].
+///
+/// This is an alternative to [`Dimension`], with the same operations. See the
+///     [module documentation](self) for its limitations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConstDim<const E: PackedExp>;
+
+impl<const E: PackedExp> ConstDim<E> {
+    /// Return an instance of this dimension, the same as
+    ///     [`DimType::dimension`].
+    pub const fn new() -> Self { Self }
+}
+
+
+/// Pack an array of exponents into the parameter of a [`ConstDim`].
+///
+/// # Panics
+/// Panics if any exponent is outside the range of `i16`.
+pub const fn pack(exps: [ExpInt; LEN]) -> PackedExp {
+    let mut packed = 0;
+    let mut i = 0;
+
+    while i < LEN {
+        let exp = exps[i];
+        assert!(
+            i16::MIN as ExpInt <= exp && exp <= i16::MAX as ExpInt,
+            "dimension exponent out of range",
+        );
+
+        packed |= ((exp + BIAS) as PackedExp) << (BITS * i as u32);
+        i += 1;
+    }
+
+    packed
+}
+
+/// Unpack the parameter of a [`ConstDim`] into an array of exponents.
+pub const fn unpack(packed: PackedExp) -> [ExpInt; LEN] {
+    let mut exps = [0; LEN];
+    let mut i = 0;
+
+    while i < LEN {
+        exps[i] = exp_at(packed, i);
+        i += 1;
+    }
+
+    exps
+}
+
+/// Return a single exponent from the parameter of a [`ConstDim`].
+pub const fn exp_at(packed: PackedExp, i: usize) -> ExpInt {
+    let bits = (packed >> (BITS * i as u32)) & ((1 << BITS) - 1);
+    bits as ExpInt - BIAS
+}
+
+
+//region Operations on packed exponents.
+/// Return the packed exponents of the product of two dimensions.
+pub const fn exp_mul(a: PackedExp, b: PackedExp) -> PackedExp {
+    let (a, b) = (unpack(a), unpack(b));
+    let mut out = [0; LEN];
+    let mut i = 0;
+
+    while i < LEN {
+        out[i] = a[i] + b[i];
+        i += 1;
+    }
+
+    pack(out)
+}
+
+/// Return the packed exponents of the quotient of two dimensions.
+pub const fn exp_div(a: PackedExp, b: PackedExp) -> PackedExp {
+    exp_mul(a, exp_pow(b, -1))
+}
+
+/// Return the packed exponents of a dimension raised to a power.
+pub const fn exp_pow(a: PackedExp, e: ExpInt) -> PackedExp {
+    let a = unpack(a);
+    let mut out = [0; LEN];
+    let mut i = 0;
+
+    while i < LEN {
+        out[i] = a[i] * e;
+        i += 1;
+    }
+
+    pack(out)
+}
+
+/// Return the packed exponents of a root of a dimension. Exponents that are
+///     not divisible by the degree are truncated; see [`exp_divides`].
+pub const fn exp_root(a: PackedExp, d: ExpInt) -> PackedExp {
+    let a = unpack(a);
+    let mut out = [0; LEN];
+    let mut i = 0;
+
+    while i < LEN {
+        out[i] = if d == 0 { 0 } else { a[i] / d };
+        i += 1;
+    }
+
+    pack(out)
+}
+
+/// Return `true` if every exponent of a dimension is divisible by `d`.
+pub const fn exp_divides(a: PackedExp, d: ExpInt) -> bool {
+    let a = unpack(a);
+    let mut i = 0;
+
+    while i < LEN {
+        if d == 0 || a[i] % d != 0 {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+//endregion
+
+
+//region Conversion to typenum.
+//  NOTE: The typenum integer of an exponent is built one bit at a time, from
+//      the least significant. Each bit has its own level, rather than one
+//      recursive impl, because the compiler cannot prove that a recursive
+//      const-generic bound terminates.
+
+/// Return the sign of an exponent in the parameter of a [`ConstDim`].
+pub const fn exp_sign(packed: PackedExp, i: usize) -> i8 {
+    exp_at(packed, i).signum() as i8
+}
+
+/// Return the magnitude of an exponent in the parameter of a [`ConstDim`].
+pub const fn exp_mag(packed: PackedExp, i: usize) -> u32 {
+    exp_at(packed, i).unsigned_abs()
+}
+
+/// Return an integer with its least significant bit removed.
+pub const fn half(n: u32) -> u32 { n / 2 }
+/// Return `true` if an integer has no bits set.
+pub const fn is_zero(n: u32) -> bool { n == 0 }
+/// Return `true` if the least significant bit of an integer is set.
+pub const fn is_odd(n: u32) -> bool { n % 2 == 1 }
+
+
+/// Type-level bit, converted to a [`typenum`] [`Bit`](typenum::Bit).
+pub struct ConstBit<const B: bool>;
+
+/// Trait for converting a [`ConstBit`] to a [`typenum`] bit.
+pub trait ToBit {
+    /// The [`typenum`] bit.
+    type Output: typenum::Bit;
+}
+
+impl ToBit for ConstBit<false> { type Output = typenum::B0; }
+impl ToBit for ConstBit<true> { type Output = typenum::B1; }
+
+
+/// Type-level unsigned integer `N`, which is zero if `Z`, converted to a
+///     [`typenum`] [`Unsigned`] from bit `K` upwards.
+pub struct ConstUint<const K: u8, const Z: bool, const N: u32>;
+
+/// Trait for converting a [`ConstUint`] to a [`typenum`] unsigned integer.
+pub trait ToUnsigned {
+    /// The [`typenum`] unsigned integer.
+    type Output: Unsigned;
+}
+
+macro_rules! impl_uint {
+    ($($k:literal => $next:literal),+ $(,)?) => {$(
+        impl<const N: u32> ToUnsigned for ConstUint<$k, true, N> {
+            type Output = typenum::UTerm;
+        }
+
+        impl<const N: u32> ToUnsigned for ConstUint<$k, false, N> where
+            ConstUint<$next, { is_zero(half(N)) }, { half(N) }>: ToUnsigned,
+            ConstBit<{ is_odd(N) }>: ToBit,
+        {
+            type Output = typenum::UInt<
+                <ConstUint<$next, { is_zero(half(N)) }, { half(N) }> as ToUnsigned>::Output,
+                <ConstBit<{ is_odd(N) }> as ToBit>::Output,
+            >;
+        }
+    )+};
+}
+
+impl_uint!(
+    1 => 2, 2 => 3, 3 => 4, 4 => 5, 5 => 6, 6 => 7, 7 => 8, 8 => 9,
+    9 => 10, 10 => 11, 11 => 12, 12 => 13, 13 => 14, 14 => 15, 15 => 16,
+);
+
+//  The magnitude of an `i16` has at most 16 bits.
+impl<const N: u32> ToUnsigned for ConstUint<16, true, N> {
+    type Output = typenum::UTerm;
+}
+
+
+/// Type-level nonzero unsigned integer, converted to a [`typenum`]
+///     [`Unsigned`].
+pub struct ConstPos<const N: u32>;
+
+/// Trait for converting a [`ConstPos`] to a [`typenum`] nonzero unsigned
+///     integer.
+pub trait ToPos {
+    /// The [`typenum`] unsigned integer.
+    type Output: Unsigned + NonZero;
+}
+
+impl<const N: u32> ToPos for ConstPos<N> where
+    ConstUint<1, { is_zero(half(N)) }, { half(N) }>: ToUnsigned,
+    ConstBit<{ is_odd(N) }>: ToBit,
+{
+    type Output = typenum::UInt<
+        <ConstUint<1, { is_zero(half(N)) }, { half(N) }> as ToUnsigned>::Output,
+        <ConstBit<{ is_odd(N) }> as ToBit>::Output,
+    >;
+}
+
+
+/// Type-level exponent `I` of the parameter `E` of a [`ConstDim`], with the
+///     sign `S`, converted to a [`typenum`] [`Integer`].
+pub struct ConstExp<const S: i8, const E: PackedExp, const I: usize>;
+
+/// Trait for converting a [`ConstExp`] to a [`typenum`] integer.
+pub trait ToInt {
+    /// The [`typenum`] integer.
+    type Output: Int;
+}
+
+impl<const E: PackedExp, const I: usize> ToInt for ConstExp<0, E, I> {
+    type Output = typenum::Z0;
+}
+
+impl<const E: PackedExp, const I: usize> ToInt for ConstExp<1, E, I> where
+    ConstPos<{ exp_mag(E, I) }>: ToPos,
+{
+    type Output = typenum::PInt<<ConstPos<{ exp_mag(E, I) }> as ToPos>::Output>;
+}
+
+impl<const E: PackedExp, const I: usize> ToInt for ConstExp<-1, E, I> where
+    ConstPos<{ exp_mag(E, I) }>: ToPos,
+{
+    type Output = NInt<<ConstPos<{ exp_mag(E, I) }> as ToPos>::Output>;
+}
+//endregion
+
+
+impl<const E: PackedExp> Sealed for ConstDim<E> {}
+
+impl<const E: PackedExp> DimType for ConstDim<E> where
+    ConstExp<{ exp_sign(E, 0) }, E, 0>: ToInt,
+    ConstExp<{ exp_sign(E, 1) }, E, 1>: ToInt,
+    ConstExp<{ exp_sign(E, 2) }, E, 2>: ToInt,
+    ConstExp<{ exp_sign(E, 3) }, E, 3>: ToInt,
+    ConstExp<{ exp_sign(E, 4) }, E, 4>: ToInt,
+    ConstExp<{ exp_sign(E, 5) }, E, 5>: ToInt,
+    ConstExp<{ exp_sign(E, 6) }, E, 6>: ToInt,
+{
+    type ExpLen  = <ConstExp<{ exp_sign(E, 0) }, E, 0> as ToInt>::Output;
+    type ExpMass = <ConstExp<{ exp_sign(E, 1) }, E, 1> as ToInt>::Output;
+    type ExpTime = <ConstExp<{ exp_sign(E, 2) }, E, 2> as ToInt>::Output;
+    type ExpCurr = <ConstExp<{ exp_sign(E, 3) }, E, 3> as ToInt>::Output;
+    type ExpTemp = <ConstExp<{ exp_sign(E, 4) }, E, 4> as ToInt>::Output;
+    type ExpAmt  = <ConstExp<{ exp_sign(E, 5) }, E, 5> as ToInt>::Output;
+    type ExpLum  = <ConstExp<{ exp_sign(E, 6) }, E, 6> as ToInt>::Output;
+
+    type ExpDen = P1;
+
+    const EXP_LEN:  ExpInt = exp_at(E, 0);
+    const EXP_MASS: ExpInt = exp_at(E, 1);
+    const EXP_TIME: ExpInt = exp_at(E, 2);
+    const EXP_CURR: ExpInt = exp_at(E, 3);
+    const EXP_TEMP: ExpInt = exp_at(E, 4);
+    const EXP_AMT:  ExpInt = exp_at(E, 5);
+    const EXP_LUM:  ExpInt = exp_at(E, 6);
+
+    fn dimension() -> Self { Self }
+}
+
+impl<const E: PackedExp> core::fmt::Display for ConstDim<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_exponents(f, unpack(E), 1, core::iter::empty())
+    }
+}
+
+
+/// Division.
+impl<const A: PackedExp, const B: PackedExp> Div<ConstDim<B>> for ConstDim<A> where
+    ConstDim<{ exp_div(A, B) }>: Sized,
+{
+    type Output = ConstDim<{ exp_div(A, B) }>;
+    fn div(self, _: ConstDim<B>) -> Self::Output { ConstDim }
+}
+
+
+/// Multiplication.
+impl<const A: PackedExp, const B: PackedExp> Mul<ConstDim<B>> for ConstDim<A> where
+    ConstDim<{ exp_mul(A, B) }>: Sized,
+{
+    type Output = ConstDim<{ exp_mul(A, B) }>;
+    fn mul(self, _: ConstDim<B>) -> Self::Output { ConstDim }
+}
+
+
+/// Inversion.
+impl<const E: PackedExp> Inv for ConstDim<E> where
+    ConstDim<{ exp_pow(E, -1) }>: Sized,
+{
+    type Output = ConstDim<{ exp_pow(E, -1) }>;
+    fn inv(self) -> Self::Output { ConstDim }
+}
+
+
+impl<const E: PackedExp, const P: ExpInt> CanDimPow<P> for ConstDim<E> where
+    Self: DimType,
+    ConstDim<{ exp_pow(E, P) }>: DimType,
+{
+    type Output = ConstDim<{ exp_pow(E, P) }>;
+}
+
+
+impl<const E: PackedExp, const D: ExpInt> CanDimRoot<D> for ConstDim<E> where
+    Assert<{ exp_divides(E, D) }>: IsTrue,
+    Self: DimType,
+    ConstDim<{ exp_root(E, D) }>: DimType,
+{
+    type Output = ConstDim<{ exp_root(E, D) }>;
+}
+
+
+/// Exponentiation by a [`typenum`] integer, for compatibility with
+///     [`UnitPow`](crate::units::UnitPow).
+impl<const E: PackedExp, P: Int> CanDimPowType<P> for ConstDim<E> where
+    Self: DimType,
+    ConstDim<{ exp_pow(E, P::I32) }>: DimType,
+{
+    type Output = ConstDim<{ exp_pow(E, P::I32) }>;
+}
+
+
+/// Root by a [`typenum`] integer, for compatibility with
+///     [`UnitPow`](crate::units::UnitPow).
+impl<const E: PackedExp, D: Int + NonZero> CanDimRootType<D> for ConstDim<E> where
+    Assert<{ exp_divides(E, D::I32) }>: IsTrue,
+    Self: DimType,
+    ConstDim<{ exp_root(E, D::I32) }>: DimType,
+{
+    type Output = ConstDim<{ exp_root(E, D::I32) }>;
+}
+
+
+/// Compile-time assertion, implementing [`IsTrue`] only when its parameter is
+///     `true`.
+pub struct Assert<const B: bool>;
+
+/// Marker trait for a satisfied [`Assert`].
+pub trait IsTrue {}
+impl IsTrue for Assert<true> {}
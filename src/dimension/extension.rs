@@ -0,0 +1,375 @@
+//! Module for user-defined extension axes of dimensions.
+//!
+//! The seven fundamental quantities cover physical units, but many domains
+//!     also count things that should never be confused with each other, such
+//!     as currencies, parts, or pixels. An [`ExtAxis`] declares one of these
+//!     as an additional base axis, and the final parameter of a [`Dimension`]
+//!     holds a list of exponents of these axes.
+//!
+//! Extension axes are kept sorted by their [`ExtAxis::Id`], and an axis is
+//!     removed when its exponent becomes zero, so that equal dimensions are
+//!     always represented by the same type, regardless of the order of the
+//!     operations that produced them.
+
+use core::{fmt::Debug, marker::PhantomData, ops::{Add, Mul, Neg}};
+use typenum::{Cmp, Compare, Equal, Greater, Less, NInt, NonZero, PartialDiv, PInt, Unsigned, Z0};
+use super::*;
+
+
+/// Trait for a marker type declaring a user-defined base axis.
+///
+/// Each axis must have a unique [`Id`](Self::Id), which determines the order
+///     of axes within a [`Dimension`].
+///
+/// # Example
+/// ```
+/// use dana::{dimension::{AxisDim, DimensionExt, ExtAxis, Frequency}, prelude::*, symbols::time::*};
+///
+/// #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// pub struct Parts;
+///
+/// impl ExtAxis for Parts {
+///     type Id = typenum::U1;
+///     const SYMBOL: &'static str = "parts";
+/// }
+///
+/// type PartRate = DimensionExt<Frequency, Parts>;
+/// assert_eq!(PartRate::new().to_string(), "T^-1*parts");
+///
+/// let parts = Quantity::new(UnitAnon::<AxisDim<Parts>>::new(1.0), 120.0);
+/// let rate = parts / qty![2.0 h];
+/// let rate = rate.convert_to(UnitAnon::<PartRate>::new(1.0 / 60.0));
+/// assert_eq!(rate.value, 1.0);
+/// ```
+///
+/// Because the axis is orthogonal to the fundamental quantities, a rate of
+///     parts can never be confused with a plain frequency:
+/// ```compile_fail
+/// # use dana::{dimension::{AxisDim, ExtAxis}, prelude::*, symbols::{frequency::*, time::*}};
+/// #
+/// # #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// # pub struct Parts;
+/// #
+/// # impl ExtAxis for Parts {
+/// #     type Id = typenum::U1;
+/// #     const SYMBOL: &'static str = "parts";
+/// # }
+/// #
+/// let parts = Quantity::new(UnitAnon::<AxisDim<Parts>>::new(1.0), 120.0);
+/// let rate = parts / qty![2.0 h];
+/// let _ = rate + qty![1.0 Hz];
+/// ```
+pub trait ExtAxis: Copy + Default + Eq + Debug + 'static {
+    /// Unique identifier of this axis.
+    type Id: Unsigned;
+
+    /// Symbol used for this axis when displaying a dimension.
+    const SYMBOL: &'static str;
+}
+
+
+/// Exponent of an [extension axis](ExtAxis), as an element of a linked list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExtExp {
+    /// The [`Id`](ExtAxis::Id) of the axis.
+    pub id: u32,
+    /// The [symbol](ExtAxis::SYMBOL) of the axis.
+    pub symbol: &'static str,
+    /// The exponent of the axis.
+    pub exp: ExpInt,
+    /// The exponent of the next axis, if there is one.
+    pub next: Option<&'static ExtExp>,
+}
+
+impl ExtExp {
+    /// Return an iterator over this exponent and every one after it.
+    pub fn iter(&'static self) -> impl Iterator<Item=&'static ExtExp> {
+        core::iter::successors(Some(self), |ext| ext.next)
+    }
+}
+
+
+/// Maximum number of [extension axes](ExtAxis) held by a [`DynExt`].
+pub const EXT_MAX: usize = 4;
+
+
+/// Runtime representation of the exponent of an [extension axis](ExtAxis).
+///
+/// Axes are identified by their [`Id`](ExtAxis::Id) alone. The symbol is only
+///     used for display, and is not serialized.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DynExtExp {
+    /// The [`Id`](ExtAxis::Id) of the axis.
+    pub id: u32,
+    /// The [symbol](ExtAxis::SYMBOL) of the axis.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub symbol: &'static str,
+    /// The exponent of the axis.
+    pub exp: ExpInt,
+}
+
+impl PartialEq for DynExtExp {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.exp == other.exp
+    }
+}
+
+impl Eq for DynExtExp {}
+
+impl core::hash::Hash for DynExtExp {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.exp.hash(state);
+    }
+}
+
+
+/// Runtime representation of a list of exponents of [extension axes](ExtAxis),
+///     sorted by their [`Id`](ExtAxis::Id), with no zero exponents.
+///
+/// This holds at most [`EXT_MAX`] axes. Any operation that would produce more
+///     than this will panic.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DynExt([Option<DynExtExp>; EXT_MAX]);
+
+impl DynExt {
+    /// An empty list, with no extension axes.
+    pub const NONE: Self = Self([None; EXT_MAX]);
+
+    /// Return the runtime representation of a type-level list of exponents.
+    ///
+    /// # Panics
+    /// This function will panic if the list has more than [`EXT_MAX`] axes.
+    pub const fn of(mut ext: Option<&'static ExtExp>) -> Self {
+        let mut exps = [None; EXT_MAX];
+        let mut i = 0;
+
+        while let Some(next) = ext {
+            assert!(i < EXT_MAX, "too many extension axes for a runtime dimension");
+            exps[i] = Some(DynExtExp { id: next.id, symbol: next.symbol, exp: next.exp });
+            ext = next.next;
+            i += 1;
+        }
+
+        Self(exps)
+    }
+
+    /// Return `true` if there are no extension axes in this list.
+    pub const fn is_empty(&self) -> bool { self.0[0].is_none() }
+
+    /// Return an iterator over the exponents in this list.
+    pub fn iter(&self) -> impl Iterator<Item=&DynExtExp> { self.0.iter().flatten() }
+
+    /// Collect exponents, sorted by axis, into a list, skipping any zeros.
+    fn collect(exps: impl Iterator<Item=DynExtExp>) -> Self {
+        let mut out = Self::NONE;
+        let mut slots = out.0.iter_mut();
+
+        for exp in exps.filter(|ext| ext.exp != 0) {
+            *slots.next().expect("too many extension axes for a runtime dimension") = Some(exp);
+        }
+
+        out
+    }
+
+    /// Apply a function to every exponent in this list.
+    pub(super) fn map(self, f: impl Fn(ExpInt) -> ExpInt) -> Self {
+        Self::collect(self.iter().map(|&ext| DynExtExp { exp: f(ext.exp), ..ext }))
+    }
+
+    /// Merge this list with another, adding the exponents of shared axes.
+    pub(super) fn sum(self, rhs: Self) -> Self {
+        let mut lhs = self.iter().copied().peekable();
+        let mut rhs = rhs.iter().copied().peekable();
+
+        Self::collect(core::iter::from_fn(|| match (lhs.peek(), rhs.peek()) {
+            (Some(a), Some(b)) if a.id == b.id => {
+                let exp = a.exp + b.exp;
+                rhs.next();
+                lhs.next().map(|ext| DynExtExp { exp, ..ext })
+            }
+            (Some(a), Some(b)) if b.id < a.id => rhs.next(),
+            (Some(_), _) => lhs.next(),
+            (None, _) => rhs.next(),
+        }))
+    }
+}
+
+
+/// Trait for a type-level list of exponents of [extension axes](ExtAxis).
+///
+/// This is implemented by `()`, for an empty list, and by [`ExtCons`].
+pub trait ExtList: Sealed + Copy + Default + 'static {
+    /// The exponents in this list, if there are any.
+    const EXT: Option<&'static ExtExp>;
+}
+
+impl Sealed for () {}
+impl ExtList for () {
+    const EXT: Option<&'static ExtExp> = None;
+}
+
+
+/// Zero-size type that serves as a non-empty type-level list of exponents of
+///     [extension axes](ExtAxis), sorted by their [`Id`](ExtAxis::Id).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExtCons<A: ExtAxis, E: Int, R: ExtList> {
+    _axis: PhantomData<A>,
+    _exp: PhantomData<E>,
+    _rest: PhantomData<R>,
+}
+
+impl<A: ExtAxis, E: Int, R: ExtList> Sealed for ExtCons<A, E, R> {}
+impl<A: ExtAxis, E: Int, R: ExtList> ExtList for ExtCons<A, E, R> {
+    const EXT: Option<&'static ExtExp> = Some(&ExtExp {
+        id: A::Id::U32,
+        symbol: A::SYMBOL,
+        exp: E::I32,
+        next: R::EXT,
+    });
+}
+
+
+/// Dimension of a single [extension axis](ExtAxis), to the power `E`.
+pub type AxisDim<A, E = P1> = Dimension<Z0, Z0, Z0, Z0, Z0, Z0, Z0, P1, ExtCons<A, E, ()>>;
+
+/// A base dimension, extended by a power of an [extension axis](ExtAxis).
+pub type DimensionExt<B, A, E = P1> = <B as CanDimMul<AxisDim<A, E>>>::Output;
+
+
+/// Prepending of an [axis](ExtAxis) to a list, with `Self` as the exponent. A
+///     zero exponent leaves the list unchanged.
+pub trait ExtPrepend<A: ExtAxis, R: ExtList> {
+    type Output: ExtList;
+}
+
+impl<A: ExtAxis, R: ExtList> ExtPrepend<A, R> for Z0 {
+    type Output = R;
+}
+
+impl<A: ExtAxis, R: ExtList, U: Unsigned + NonZero> ExtPrepend<A, R> for PInt<U> {
+    type Output = ExtCons<A, Self, R>;
+}
+
+impl<A: ExtAxis, R: ExtList, U: Unsigned + NonZero> ExtPrepend<A, R> for NInt<U> {
+    type Output = ExtCons<A, Self, R>;
+}
+
+
+/// Multiplication of lists of exponents, by merging them.
+pub trait ExtMul<Rhs> {
+    type Output: ExtList;
+}
+
+impl<X: ExtList> ExtMul<X> for () {
+    type Output = X;
+}
+
+impl<A: ExtAxis, E: Int, R: ExtList> ExtMul<()> for ExtCons<A, E, R> {
+    type Output = Self;
+}
+
+impl<A1: ExtAxis, E1: Int, R1: ExtList, A2: ExtAxis, E2: Int, R2: ExtList>
+ExtMul<ExtCons<A2, E2, R2>> for ExtCons<A1, E1, R1> where
+    A1::Id: Cmp<A2::Id>,
+    Self: ExtMulOrdered<ExtCons<A2, E2, R2>, Compare<A1::Id, A2::Id>>,
+{
+    type Output = <Self as ExtMulOrdered<ExtCons<A2, E2, R2>, Compare<A1::Id, A2::Id>>>::Output;
+}
+
+
+/// Multiplication of non-empty lists of exponents, dispatched on the
+///     [ordering](Cmp) of their first axes.
+pub trait ExtMulOrdered<Rhs, O> {
+    type Output: ExtList;
+}
+
+/// Same axis: Add the exponents.
+impl<A: ExtAxis, E1: Int, R1: ExtList, E2: Int, R2: ExtList>
+ExtMulOrdered<ExtCons<A, E2, R2>, Equal> for ExtCons<A, E1, R1> where
+    R1: ExtMul<R2>,
+    E1: Add<E2>,
+    E1::Output: ExtPrepend<A, R1::Output>,
+{
+    type Output = <E1::Output as ExtPrepend<A, R1::Output>>::Output;
+}
+
+/// Left axis first.
+impl<A1: ExtAxis, E1: Int, R1: ExtList, A2: ExtAxis, E2: Int, R2: ExtList>
+ExtMulOrdered<ExtCons<A2, E2, R2>, Less> for ExtCons<A1, E1, R1> where
+    R1: ExtMul<ExtCons<A2, E2, R2>>,
+{
+    type Output = ExtCons<A1, E1, R1::Output>;
+}
+
+/// Right axis first.
+impl<A1: ExtAxis, E1: Int, R1: ExtList, A2: ExtAxis, E2: Int, R2: ExtList>
+ExtMulOrdered<ExtCons<A2, E2, R2>, Greater> for ExtCons<A1, E1, R1> where
+    Self: ExtMul<R2>,
+{
+    type Output = ExtCons<A2, E2, <Self as ExtMul<R2>>::Output>;
+}
+
+
+/// Division of lists of exponents.
+pub trait ExtDiv<Rhs> {
+    type Output: ExtList;
+}
+
+impl<X: ExtList, Rhs: ExtInv> ExtDiv<Rhs> for X where
+    X: ExtMul<Rhs::Output>,
+{
+    type Output = <X as ExtMul<Rhs::Output>>::Output;
+}
+
+
+/// Inversion of a list of exponents.
+pub trait ExtInv {
+    type Output: ExtList;
+}
+
+impl ExtInv for () {
+    type Output = ();
+}
+
+impl<A: ExtAxis, E: Int + Neg, R: ExtInv + ExtList> ExtInv for ExtCons<A, E, R> where
+    E::Output: Int,
+{
+    type Output = ExtCons<A, E::Output, R::Output>;
+}
+
+
+/// Multiplication of every exponent in a list by an [`Integer`].
+pub trait ExtPow<P> {
+    type Output: ExtList;
+}
+
+impl<P> ExtPow<P> for () {
+    type Output = ();
+}
+
+impl<A: ExtAxis, E: Int + Mul<P>, R: ExtPow<P> + ExtList, P> ExtPow<P> for ExtCons<A, E, R> where
+    E::Output: ExtPrepend<A, R::Output>,
+{
+    type Output = <E::Output as ExtPrepend<A, R::Output>>::Output;
+}
+
+
+/// Division of every exponent in a list by an [`Integer`]. Exponents of
+///     extension axes are always integers, so they must be divisible by it.
+pub trait ExtRoot<D> {
+    type Output: ExtList;
+}
+
+impl<D> ExtRoot<D> for () {
+    type Output = ();
+}
+
+impl<A: ExtAxis, E: Int + PartialDiv<D>, R: ExtRoot<D> + ExtList, D> ExtRoot<D> for ExtCons<A, E, R> where
+    E::Output: Int,
+{
+    type Output = ExtCons<A, E::Output, R::Output>;
+}
+
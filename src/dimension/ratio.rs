@@ -0,0 +1,111 @@
+//! Helpers for arithmetic on the rational exponents of a [`Dimension`].
+//!
+//! Each exponent of a [`Dimension`] is a numerator over a common denominator.
+//!     Operations on dimensions produce exponents over a new denominator, which
+//!     are then [reduced](Reduce) to lowest terms, so that any two equal
+//!     dimensions are always represented by the same type.
+
+use core::ops::{Add, Div, Mul, Sub};
+use typenum::{Diff, Gcd, Gcf, Prod, Quot, Sum};
+use super::{Den, DimType, Dimension, ExtList, Int};
+
+
+/// Numerator of the sum of the fractions `Self/Q1` and `L2/Q2`, over the
+///     product of their denominators.
+pub trait CrossAdd<Q1, L2, Q2> {
+    type Output: Int;
+}
+
+impl<L1, Q1, L2, Q2> CrossAdd<Q1, L2, Q2> for L1 where
+    L1: Mul<Q2>, L2: Mul<Q1>,
+    Prod<L1, Q2>: Add<Prod<L2, Q1>>,
+    Sum<Prod<L1, Q2>, Prod<L2, Q1>>: Int,
+{
+    type Output = Sum<Prod<L1, Q2>, Prod<L2, Q1>>;
+}
+
+pub type CrossSum<L1, Q1, L2, Q2> = <L1 as CrossAdd<Q1, L2, Q2>>::Output;
+
+
+/// Numerator of the difference of the fractions `Self/Q1` and `L2/Q2`, over
+///     the product of their denominators.
+pub trait CrossSub<Q1, L2, Q2> {
+    type Output: Int;
+}
+
+impl<L1, Q1, L2, Q2> CrossSub<Q1, L2, Q2> for L1 where
+    L1: Mul<Q2>, L2: Mul<Q1>,
+    Prod<L1, Q2>: Sub<Prod<L2, Q1>>,
+    Diff<Prod<L1, Q2>, Prod<L2, Q1>>: Int,
+{
+    type Output = Diff<Prod<L1, Q2>, Prod<L2, Q1>>;
+}
+
+pub type CrossDiff<L1, Q1, L2, Q2> = <L1 as CrossSub<Q1, L2, Q2>>::Output;
+
+
+/// Greatest common divisor of a list of integers, given as nested pairs ending
+///     in `()`.
+pub trait GcdOf {
+    type Output: Int;
+}
+
+impl<A: Int> GcdOf for (A, ()) {
+    type Output = A;
+}
+
+impl<A: Int, B, R> GcdOf for (A, (B, R)) where
+    (B, R): GcdOf,
+    A: Gcd<<(B, R) as GcdOf>::Output>,
+    Gcf<A, <(B, R) as GcdOf>::Output>: Int,
+{
+    type Output = Gcf<A, <(B, R) as GcdOf>::Output>;
+}
+
+
+/// Division of every exponent and the denominator of a [`Dimension`] by a
+///     common divisor.
+pub trait DivideBy<G> {
+    type Output: DimType;
+}
+
+impl<
+    L: Int + Div<G>, M: Int + Div<G>, T: Int + Div<G>,
+    I: Int + Div<G>, K: Int + Div<G>, N: Int + Div<G>,
+    J: Int + Div<G>,
+    Q: Den + Div<G>,
+    X: ExtList,
+    G,
+> DivideBy<G> for Dimension<L, M, T, I, K, N, J, Q, X> where
+    Quot<L, G>: Int, Quot<M, G>: Int, Quot<T, G>: Int,
+    Quot<I, G>: Int, Quot<K, G>: Int, Quot<N, G>: Int,
+    Quot<J, G>: Int,
+    Quot<Q, G>: Den,
+{
+    type Output = Dimension<
+        Quot<L, G>, Quot<M, G>, Quot<T, G>,
+        Quot<I, G>, Quot<K, G>, Quot<N, G>,
+        Quot<J, G>,
+        Quot<Q, G>,
+        X,
+    >;
+}
+
+
+/// Reduction of the exponents of a [`Dimension`] to lowest terms.
+pub trait Reduce {
+    type Output: DimType;
+}
+
+type DimGcd<L, M, T, I, K, N, J, Q> =
+    <(Q, (L, (M, (T, (I, (K, (N, (J, ())))))))) as GcdOf>::Output;
+
+impl<L: Int, M: Int, T: Int, I: Int, K: Int, N: Int, J: Int, Q: Den, X: ExtList> Reduce
+for Dimension<L, M, T, I, K, N, J, Q, X> where
+    (Q, (L, (M, (T, (I, (K, (N, (J, ())))))))): GcdOf,
+    Self: DivideBy<DimGcd<L, M, T, I, K, N, J, Q>>,
+{
+    type Output = <Self as DivideBy<DimGcd<L, M, T, I, K, N, J, Q>>>::Output;
+}
+
+pub type Reduced<D> = <D as Reduce>::Output;
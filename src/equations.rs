@@ -1,11 +1,13 @@
 //! Functions for mathematical relationships between quantities.
 
+use num_traits::real::Real;
 use crate::{
     constants::*,
     Quantity,
     symbols::physics::*,
     units::*,
     Value,
+    value::Complex,
 };
 
 
@@ -62,6 +64,39 @@ impl<V: Value> Quantity<Temp, V> {
 }
 
 
+impl<V: Value + Real> Quantity<Resistance, V> where Complex<V>: Value {
+    /// Return the impedance of a resistor with this resistance. The impedance
+    ///     of a resistor is real, and does not depend on frequency.
+    pub fn impedance(self) -> Quantity<Resistance, Complex<V>> {
+        self.into_complex()
+    }
+}
+
+impl<V: Value + Real> Quantity<Inductance, V> where Complex<V>: Value {
+    /// Return the impedance of an inductor with this inductance, at the given
+    ///     frequency.
+    pub fn impedance(self, freq: Quantity<Frequency, V>) -> Quantity<Resistance, Complex<V>> {
+        //  Z = jωL
+        let omega = freq.value_as_base() * crate::_conv_f64(core::f64::consts::TAU);
+        let reactance = omega * self.value_as_base();
+
+        Resistance::Ohm.quantity(Complex::new(V::zero(), reactance))
+    }
+}
+
+impl<V: Value + Real> Quantity<Capacitance, V> where Complex<V>: Value {
+    /// Return the impedance of a capacitor with this capacitance, at the given
+    ///     frequency.
+    pub fn impedance(self, freq: Quantity<Frequency, V>) -> Quantity<Resistance, Complex<V>> {
+        //  Z = 1/(jωC) = -j/(ωC)
+        let omega = freq.value_as_base() * crate::_conv_f64(core::f64::consts::TAU);
+        let reactance = -(omega * self.value_as_base()).recip();
+
+        Resistance::Ohm.quantity(Complex::new(V::zero(), reactance))
+    }
+}
+
+
 /// Calculate the [Standard Gravitational Parameter] for a given mass.
 ///
 /// [Standard Gravitational Parameter]: https://en.wikipedia.org/wiki/Standard_gravitational_parameter
@@ -12,3 +12,98 @@ pub enum TimeDeltaError<V: core::fmt::Debug> {
     #[error("duration is out of bounds")]
     OutOfBounds,
 }
+
+
+/// Error while parsing a [`Quantity`](crate::Quantity) from a string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror_no_std::Error)]
+pub enum ParseQuantityError {
+    /// The string does not contain any components.
+    #[error("no quantity found")]
+    Empty,
+    /// A component does not begin with a valid number.
+    #[error("invalid numeric value")]
+    InvalidValue,
+    /// A component has a number, but no unit symbol after it.
+    #[error("missing unit symbol")]
+    MissingUnit,
+    /// A unit symbol does not match any unit of the expected type.
+    #[error("unrecognized unit symbol")]
+    UnknownUnit,
+    /// The string has a leading minus sign, but the value type cannot be
+    ///     negative.
+    #[error("negative value for unsigned type")]
+    NegativeValue,
+    /// A component cannot be converted exactly to the unit of the last
+    ///     component, because the value type has no fractional part.
+    #[error("inexact conversion between components")]
+    InexactValue,
+}
+
+
+/// Error while parsing a [`Complex`](crate::value::Complex) number from a
+///     string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror_no_std::Error)]
+pub enum ParseComplexError<E: core::fmt::Debug> {
+    /// One of the parts could not be parsed.
+    #[error("invalid complex part: {0:?}")]
+    Part(E),
+}
+
+
+/// Error while parsing a [fixed-point](crate::value::fixed) number from a
+///     string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror_no_std::Error)]
+pub enum ParseFixedError {
+    /// The string does not contain any digits.
+    #[error("no digits found")]
+    Empty,
+    /// The string contains a character that is not a digit in the radix.
+    #[error("invalid digit found")]
+    InvalidDigit,
+    /// The number is outside the range of the type.
+    #[error("number out of range")]
+    Overflow,
+}
+
+
+/// Error from an operation on values with dimensions known only at runtime.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror_no_std::Error)]
+pub enum DimensionError {
+    /// A value does not have the dimension required by the operation.
+    #[error("expected dimension {}, found {}", .expected.named(), .found.named())]
+    Mismatch {
+        /// Dimension required by the operation.
+        expected: crate::dimension::DynDimension,
+        /// Dimension actually found.
+        found: crate::dimension::DynDimension,
+    },
+    /// A matrix cannot be inverted.
+    #[error("matrix is singular")]
+    Singular,
+}
+
+
+/// Error while parsing a unit expression or quantity with a
+///     [`UnitRegistry`](crate::registry::UnitRegistry).
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror_no_std::Error)]
+pub enum ParseUnitError {
+    /// The string does not contain a unit expression.
+    #[error("no unit found")]
+    Empty,
+    /// The string does not begin with a valid number.
+    #[error("invalid numeric value")]
+    InvalidValue,
+    /// An exponent is not a valid integer.
+    #[error("invalid exponent")]
+    InvalidExponent,
+    /// The expression is not well-formed, such as with unbalanced parentheses.
+    #[error("invalid unit expression")]
+    InvalidSyntax,
+    /// A unit symbol is not in the registry.
+    #[error("unrecognized unit symbol")]
+    UnknownUnit,
+    /// The unit does not have the dimension required.
+    #[error(transparent)]
+    Mismatch(#[from] DimensionError),
+}
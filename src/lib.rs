@@ -92,10 +92,15 @@
 
 #![no_std]
 #![cfg_attr(feature = "simd", feature(portable_simd))]
+#![cfg_attr(feature = "const_dim", feature(generic_const_exprs))]
+#![cfg_attr(feature = "const_dim", allow(incomplete_features))]
 
 //  NOTE: Hack to allow proc macros to work both inside and outside the crate.
 extern crate self as dana;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 pub mod macros;
 pub mod prelude;
@@ -108,6 +113,11 @@ pub mod quantity;
 pub mod symbols;
 pub mod units;
 pub mod value;
+pub mod vector;
+pub mod matrix;
+pub mod analysis;
+#[cfg(feature = "alloc")]
+pub mod registry;
 
 pub mod error;
 
@@ -240,11 +240,11 @@ macro_rules! qty {($($t:tt)*) => {$crate::macros::proc::qty!($($t)*)}}
 /// The only variation is to specify a type for the dimensionless value. This
 ///     can be expressed in a few ways:
 /// - Before the unit specifier, followed by a semicolon, similar to the `[T;N]`
-///     syntax of array types
+///   syntax of array types
 /// - Before the unit specifier, followed by `as`, closer to a natural-language
-///     expression
+///   expression
 /// - After the unit specifier, separated by a comma, mirroring the generic
-///     parameters of `Quantity<U, V>`
+///   parameters of `Quantity<U, V>`
 /// ```
 /// # use dana::{prelude::*, symbols::time::*};
 /// #
@@ -347,8 +347,14 @@ macro_rules! utype {($($t:tt)*) => {$crate::macros::proc::utype!($($t)*)}}
 ///     their difference (in the units of the first quantity) to a limit
 ///     (default `1e-12`).
 ///
-/// The limit can be changed by passing `<=` and a literal ahead of the first
-///     argument.
+/// The limit can be changed by passing `<=` and a limit ahead of the first
+///     argument. The limit may be a plain value, in the units of the first
+///     quantity, or a [`Quantity`] of the same dimension. Alternatively, the
+///     quantities can be compared by their relative difference, by passing
+///     `rel <=` and a limit, or by their distance in units in the last place,
+///     by passing `ulps <=` and a count.
+///
+/// Each argument is evaluated exactly once.
 ///
 /// On panic, this macro will print the values of the quantities.
 ///
@@ -358,7 +364,7 @@ macro_rules! utype {($($t:tt)*) => {$crate::macros::proc::utype!($($t)*)}}
 /// # Examples
 ///
 /// ```
-/// # use dana::{*, symbols::{length_si::*, volume_si::*}};
+/// # use dana::{*, symbols::{length_huge::*, length_si::*, volume_si::*}};
 /// let a = qty![1.0 cm^3];
 /// let b = qty![1.0 mL];
 ///
@@ -369,45 +375,81 @@ macro_rules! utype {($($t:tt)*) => {$crate::macros::proc::utype!($($t)*)}}
 /// //  Custom limit:
 /// assert_qty_approx!(<= 3e-16, a, b);
 /// assert_qty_approx!(<= 3e-16, a, b, "testing {} and {} more tightly", a, b);
+/// assert_qty_approx!(<= qty![1.0 mm^3], a, b);
+///
+/// //  Relative and ULP limits:
+/// assert_qty_approx!(rel <= 1e-9, qty![1.0 Mpc], qty![1.0000000001 Mpc]);
+/// assert_qty_approx!(ulps <= 4, a, b);
 /// ```
 #[macro_export]
 macro_rules! assert_qty_approx {
-    ($lhs:expr, $rhs:expr $(, $($t:tt)*)?) => {
-        $crate::assert_qty_approx!(<= 1e-12, $lhs, $rhs $(, $($t)*)?);
+    (rel <= $limit:expr, $lhs:expr, $rhs:expr $(, $($t:tt)*)?) => {
+        match ($lhs, $rhs, $limit) {
+            (lhs, rhs, limit) => if lhs.almost_eq_rel(rhs, limit) {} else {
+                $crate::assert_qty_approx!(
+                    @fail lhs, rhs, "relative delta", lhs.relative_diff(rhs),
+                    limit $(, $($t)*)?
+                );
+            }
+        }
     };
-    (<= $limit:expr, $lhs:expr, $rhs:expr $(,)?) => {
-        if !(($lhs - $rhs).abs().value <= $limit) {
-            panic!(
-                "assertion `left ~= right` failed\
-                \n  left: {lhs}\
-                \n right: {rhs_conv} (from {rhs})\
-                \n delta: {delta}\
-                \n limit: {limit}",
-                lhs = $lhs,
-                rhs = $rhs,
-                rhs_conv = $rhs.convert_to($lhs.unit),
-                delta = ($lhs - $rhs).abs().value,
-                limit = $limit,
-            );
+    (ulps <= $limit:expr, $lhs:expr, $rhs:expr $(, $($t:tt)*)?) => {
+        match ($lhs, $rhs, $limit) {
+            (lhs, rhs, limit) => if lhs.almost_eq_ulps(rhs, limit) {} else {
+                $crate::assert_qty_approx!(
+                    @fail lhs, rhs, "ulps", lhs.ulp_distance(rhs).unwrap_or(u64::MAX),
+                    limit $(, $($t)*)?
+                );
+            }
         }
     };
-    (<= $limit:expr, $lhs:expr, $rhs:expr, $($t:tt)+) => {
-        if !(($lhs - $rhs).abs().value <= $limit) {
-            panic!(
-                "assertion `left ~= right` failed: {message}\
-                \n  left: {lhs}\
-                \n right: {rhs_conv} ({rhs})\
-                \n delta: {delta}\
-                \n limit: {limit}",
-                lhs = $lhs,
-                rhs = $rhs,
-                rhs_conv = $rhs.convert_to($lhs.unit),
-                delta = ($lhs - $rhs).abs().value,
-                limit = $limit,
-                message = format_args!($($t)+),
-            );
+    (<= $limit:expr, $lhs:expr, $rhs:expr $(, $($t:tt)*)?) => {
+        match ($lhs, $rhs, $limit) {
+            //  NOTE: Written this way around so that a NaN delta fails.
+            (lhs, rhs, limit) => if (lhs - rhs).abs().value
+                <= $crate::quantity::ApproxLimit::limit_in(&limit, lhs.unit)
+            {} else {
+                $crate::assert_qty_approx!(
+                    @fail lhs, rhs, "delta", (lhs - rhs).abs().value,
+                    limit $(, $($t)*)?
+                );
+            }
         }
     };
+    (@fail $lhs:expr, $rhs:expr, $name:literal, $delta:expr, $limit:expr $(,)?) => {
+        panic!(
+            "assertion `left ~= right` failed\
+            \n  left: {lhs}\
+            \n right: {rhs_conv} (from {rhs})\
+            \n {name}: {delta}\
+            \n limit: {limit}",
+            lhs = $lhs,
+            rhs = $rhs,
+            rhs_conv = $rhs.convert_to($lhs.unit),
+            name = $name,
+            delta = $delta,
+            limit = $limit,
+        );
+    };
+    (@fail $lhs:expr, $rhs:expr, $name:literal, $delta:expr, $limit:expr, $($t:tt)+) => {
+        panic!(
+            "assertion `left ~= right` failed: {message}\
+            \n  left: {lhs}\
+            \n right: {rhs_conv} ({rhs})\
+            \n {name}: {delta}\
+            \n limit: {limit}",
+            lhs = $lhs,
+            rhs = $rhs,
+            rhs_conv = $rhs.convert_to($lhs.unit),
+            name = $name,
+            delta = $delta,
+            limit = $limit,
+            message = format_args!($($t)+),
+        );
+    };
+    ($lhs:expr, $rhs:expr $(, $($t:tt)*)?) => {
+        $crate::assert_qty_approx!(<= 1e-12, $lhs, $rhs $(, $($t)*)?);
+    };
 }
 
 
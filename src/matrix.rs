@@ -0,0 +1,414 @@
+//! Module for vectors and matrices whose elements have differing dimensions.
+//!
+//! Values are stored in base units, alongside the [`DynDimension`] of each row
+//!     and column. Dimensions are checked at runtime whenever a [`Quantity`] is
+//!     read or written, and whenever two containers are combined.
+//!
+//! The dimension of a matrix entry is the dimension of its row divided by the
+//!     dimension of its column. This way, a matrix with row dimensions `Y` and
+//!     column dimensions `X` maps a vector with dimensions `X` to a vector with
+//!     dimensions `Y`.
+//!
+//! Unit scales are `f64`, so quantities can only be read and written with
+//!     [`Real`] values, such as floats. Integer values would be truncated.
+
+use core::{fmt::Display, ops::{Add, Sub}};
+use num_traits::{Inv, real::Real};
+use crate::{
+    dimension::{DimType, DynDimension},
+    error::DimensionError,
+    Quantity,
+    Unit,
+    Value,
+};
+
+
+/// Return an error if two dimensions are not the same.
+fn check(expected: DynDimension, found: DynDimension) -> Result<(), DimensionError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(DimensionError::Mismatch { expected, found })
+    }
+}
+
+
+/// Find the single dimension `s` such that `lhs[i] * s == rhs[i]` for every
+///     `i`. This is the factor that must be applied to the dimensions on one
+///     side of a product to produce the dimensions of the result.
+fn common_factor<const N: usize>(
+    lhs: &[DynDimension; N],
+    rhs: &[DynDimension; N],
+) -> Result<DynDimension, DimensionError> {
+    let Some(first) = lhs.iter().zip(rhs).map(|(l, r)| *r / *l).next() else {
+        return Ok(DynDimension::ONE);
+    };
+
+    for (l, r) in lhs.iter().zip(rhs) {
+        check(*l * first, *r)?;
+    }
+
+    Ok(first)
+}
+
+
+/// Convert a quantity to a value in base units, after checking its dimension.
+fn to_base<U: Unit, V: Value + Real>(qty: Quantity<U, V>, dim: DynDimension) -> Result<V, DimensionError> {
+    check(dim, DynDimension::of::<U::Dim>())?;
+    Ok(qty.value * crate::_conv_f64(qty.unit.scale()))
+}
+
+
+/// Convert a value in base units to a quantity, after checking its dimension.
+fn from_base<U: Unit, V: Value + Real>(value: V, dim: DynDimension, unit: U) -> Result<Quantity<U, V>, DimensionError> {
+    check(dim, DynDimension::of::<U::Dim>())?;
+    Ok(Quantity::new(unit, value / crate::_conv_f64(unit.scale())))
+}
+
+
+/// A vector whose components may each have a different dimension.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DimVector<V: Value, const N: usize> {
+    /// Values of the components, in base units.
+    pub values: [V; N],
+    /// Dimensions of the components.
+    pub dims: [DynDimension; N],
+}
+
+impl<V: Value, const N: usize> DimVector<V, N> {
+    /// Construct a new vector of zeros with the given dimensions.
+    pub fn new(dims: [DynDimension; N]) -> Self {
+        Self::from_base(dims, core::array::from_fn(|_| V::zero()))
+    }
+
+    /// Construct a new vector from dimensions and values in base units.
+    pub const fn from_base(dims: [DynDimension; N], values: [V; N]) -> Self {
+        Self { values, dims }
+    }
+
+    /// Return the value of a component as a quantity in the given unit.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if the unit does not have the
+    ///     dimension of the component.
+    pub fn get<U: Unit>(&self, i: usize, unit: U) -> Result<Quantity<U, V>, DimensionError> where
+        V: Real,
+    {
+        from_base(self.values[i], self.dims[i], unit)
+    }
+
+    /// Set the value of a component from a quantity.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if the quantity does not have the
+    ///     dimension of the component.
+    pub fn set<U: Unit>(&mut self, i: usize, qty: Quantity<U, V>) -> Result<(), DimensionError> where
+        V: Real,
+    {
+        self.values[i] = to_base(qty, self.dims[i])?;
+        Ok(())
+    }
+
+    /// Add another vector to this one, component-wise.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if any pair of components have
+    ///     different dimensions.
+    pub fn try_add(self, rhs: Self) -> Result<Self, DimensionError> where
+        V: Add<Output=V>,
+    {
+        for (l, r) in self.dims.into_iter().zip(rhs.dims) {
+            check(l, r)?;
+        }
+
+        let mut rhs = rhs.values.into_iter();
+        Ok(Self::from_base(self.dims, self.values.map(|v| v + rhs.next().unwrap())))
+    }
+
+    /// Subtract another vector from this one, component-wise.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if any pair of components have
+    ///     different dimensions.
+    pub fn try_sub(self, rhs: Self) -> Result<Self, DimensionError> where
+        V: Sub<Output=V>,
+    {
+        for (l, r) in self.dims.into_iter().zip(rhs.dims) {
+            check(l, r)?;
+        }
+
+        let mut rhs = rhs.values.into_iter();
+        Ok(Self::from_base(self.dims, self.values.map(|v| v - rhs.next().unwrap())))
+    }
+}
+
+impl<V: Value + Display, const N: usize> Display for DimVector<V, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[")?;
+
+        for (i, (value, dim)) in self.values.iter().zip(&self.dims).enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            <V as Display>::fmt(value, f)?;
+            write!(f, " [{dim}]")?;
+        }
+
+        f.write_str("]")
+    }
+}
+
+
+/// A matrix whose entries may each have a different dimension.
+///
+/// The dimension of the entry at row `i` and column `j` is `rows[i]/cols[j]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DimMatrix<V: Value, const R: usize, const C: usize> {
+    /// Values of the entries, in base units, indexed by row and then column.
+    pub values: [[V; C]; R],
+    /// Dimensions of the rows.
+    pub rows: [DynDimension; R],
+    /// Dimensions of the columns.
+    pub cols: [DynDimension; C],
+}
+
+impl<V: Value, const R: usize, const C: usize> DimMatrix<V, R, C> {
+    /// Construct a new matrix of zeros with the given row and column
+    ///     dimensions.
+    pub fn new(rows: [DynDimension; R], cols: [DynDimension; C]) -> Self {
+        let values = core::array::from_fn(|_| core::array::from_fn(|_| V::zero()));
+        Self::from_base(rows, cols, values)
+    }
+
+    /// Construct a new matrix from dimensions and values in base units.
+    pub const fn from_base(
+        rows: [DynDimension; R],
+        cols: [DynDimension; C],
+        values: [[V; C]; R],
+    ) -> Self {
+        Self { values, rows, cols }
+    }
+
+    /// Return the dimension of the entry at a given row and column.
+    pub fn entry_dim(&self, row: usize, col: usize) -> DynDimension {
+        self.rows[row] / self.cols[col]
+    }
+
+    /// Return the value of an entry as a quantity in the given unit.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if the unit does not have the
+    ///     dimension of the entry.
+    pub fn get<U: Unit>(&self, row: usize, col: usize, unit: U) -> Result<Quantity<U, V>, DimensionError> where
+        V: Real,
+    {
+        from_base(self.values[row][col], self.entry_dim(row, col), unit)
+    }
+
+    /// Set the value of an entry from a quantity.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if the quantity does not have the
+    ///     dimension of the entry.
+    pub fn set<U: Unit>(&mut self, row: usize, col: usize, qty: Quantity<U, V>) -> Result<(), DimensionError> where
+        V: Real,
+    {
+        self.values[row][col] = to_base(qty, self.entry_dim(row, col))?;
+        Ok(())
+    }
+
+    /// Return the transpose of this matrix.
+    pub fn transpose(self) -> DimMatrix<V, C, R> {
+        let values = core::array::from_fn(|j| {
+            core::array::from_fn(|i| self.values[i][j].clone())
+        });
+
+        DimMatrix::from_base(self.cols.map(Inv::inv), self.rows.map(Inv::inv), values)
+    }
+
+    /// Multiply this matrix by a vector.
+    ///
+    /// The dimensions of the vector must match the column dimensions of this
+    ///     matrix, up to a single common factor, which is then also applied to
+    ///     the row dimensions to give the dimensions of the result.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if the dimensions of the vector are
+    ///     not compatible with the columns of this matrix.
+    pub fn try_mul_vec(&self, rhs: &DimVector<V, C>) -> Result<DimVector<V, R>, DimensionError> {
+        let factor = common_factor(&self.cols, &rhs.dims)?;
+
+        let values = core::array::from_fn(|i| {
+            let mut sum = V::zero();
+
+            for j in 0..C {
+                sum = sum + self.values[i][j].clone() * rhs.values[j].clone();
+            }
+
+            sum
+        });
+
+        Ok(DimVector::from_base(self.rows.map(|d| d * factor), values))
+    }
+
+    /// Multiply this matrix by another.
+    ///
+    /// The row dimensions of the other matrix must match the column dimensions
+    ///     of this one, up to a single common factor, which is then also
+    ///     applied to the row dimensions of the result.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if the dimensions of the matrices
+    ///     are not compatible.
+    pub fn try_mul<const K: usize>(&self, rhs: &DimMatrix<V, C, K>) -> Result<DimMatrix<V, R, K>, DimensionError> {
+        let factor = common_factor(&self.cols, &rhs.rows)?;
+
+        let values = core::array::from_fn(|i| core::array::from_fn(|k| {
+            let mut sum = V::zero();
+
+            for j in 0..C {
+                sum = sum + self.values[i][j].clone() * rhs.values[j][k].clone();
+            }
+
+            sum
+        }));
+
+        Ok(DimMatrix::from_base(self.rows.map(|d| d * factor), rhs.cols, values))
+    }
+
+    /// Add another matrix to this one, entry-wise.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if any pair of entries have
+    ///     different dimensions.
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, DimensionError> {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+
+    /// Subtract another matrix from this one, entry-wise.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if any pair of entries have
+    ///     different dimensions.
+    pub fn try_sub(&self, rhs: &Self) -> Result<Self, DimensionError> {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+
+    fn zip_with(&self, rhs: &Self, f: impl Fn(V, V) -> V) -> Result<Self, DimensionError> {
+        for i in 0..R {
+            for j in 0..C {
+                check(self.entry_dim(i, j), rhs.entry_dim(i, j))?;
+            }
+        }
+
+        let values = core::array::from_fn(|i| core::array::from_fn(|j| {
+            f(self.values[i][j].clone(), rhs.values[i][j].clone())
+        }));
+
+        Ok(Self::from_base(self.rows, self.cols, values))
+    }
+}
+
+impl<V: Value, const N: usize> DimMatrix<V, N, N> {
+    /// Construct an identity matrix, mapping vectors with the given dimensions
+    ///     to themselves.
+    pub fn identity(dims: [DynDimension; N]) -> Self {
+        let mut out = Self::new(dims, dims);
+
+        for i in 0..N {
+            out.values[i][i] = V::one();
+        }
+
+        out
+    }
+
+    /// Construct a matrix whose entry dimensions are the products of the given
+    ///     dimensions, such as the covariance of a vector with dimensions
+    ///     `dims`.
+    pub fn outer(dims: [DynDimension; N]) -> Self {
+        Self::new(dims, dims.map(Inv::inv))
+    }
+
+    /// Return the inverse of this matrix, mapping vectors with its row
+    ///     dimensions back to vectors with its column dimensions.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Singular`] if this matrix cannot be inverted.
+    pub fn inverse(&self) -> Result<Self, DimensionError> where
+        V: Real,
+    {
+        let mut a = self.values;
+        let mut inv = Self::identity(self.cols).values;
+
+        //  Gauss-Jordan elimination with partial pivoting.
+        for col in 0..N {
+            let pivot = (col..N).max_by(|&x, &y| {
+                a[x][col].abs().partial_cmp(&a[y][col].abs())
+                    .unwrap_or(core::cmp::Ordering::Equal)
+            }).unwrap();
+
+            if a[pivot][col].is_zero() {
+                return Err(DimensionError::Singular);
+            }
+
+            a.swap(col, pivot);
+            inv.swap(col, pivot);
+
+            let div = a[col][col];
+
+            for j in 0..N {
+                a[col][j] = a[col][j] / div;
+                inv[col][j] = inv[col][j] / div;
+            }
+
+            for row in 0..N {
+                if row != col {
+                    let factor = a[row][col];
+
+                    for j in 0..N {
+                        a[row][j] = a[row][j] - factor * a[col][j];
+                        inv[row][j] = inv[row][j] - factor * inv[col][j];
+                    }
+                }
+            }
+        }
+
+        Ok(Self::from_base(self.cols, self.rows, inv))
+    }
+}
+
+
+/// Return the runtime dimensions of a tuple of [`DimType`]s, for use as the
+///     dimensions of a [`DimVector`] or [`DimMatrix`].
+pub fn dims<D: DimArray>() -> D::Array { D::dims() }
+
+
+/// Trait for tuples of [`DimType`]s that can be converted into arrays of
+///     [`DynDimension`]s.
+pub trait DimArray {
+    /// Array type produced.
+    type Array;
+
+    /// Return the dimensions of this tuple.
+    fn dims() -> Self::Array;
+}
+
+macro_rules! impl_dim_array {
+    ($($n:literal: ($($d:ident),+);)+) => {$(
+        impl<$($d: DimType),+> DimArray for ($($d,)+) {
+            type Array = [DynDimension; $n];
+
+            fn dims() -> Self::Array { [$(DynDimension::of::<$d>()),+] }
+        }
+    )+};
+}
+
+impl_dim_array!(
+    1: (D0);
+    2: (D0, D1);
+    3: (D0, D1, D2);
+    4: (D0, D1, D2, D3);
+    5: (D0, D1, D2, D3, D4);
+    6: (D0, D1, D2, D3, D4, D5);
+);
@@ -3,14 +3,35 @@
 #[cfg(feature = "rand")]
 pub mod rand;
 
+mod approx;
+mod checked;
+mod complex;
+mod dual;
+mod interval;
+mod normalize;
 mod qty_from;
+mod split;
+mod total;
+
+pub use approx::ApproxLimit;
+pub use dual::{derivative, value_and_derivative};
+pub use normalize::NormalizeOptions;
+pub use split::QuantitySplit;
+pub use total::TotalQty;
 
 use core::{
     iter::Sum,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign,
+        Neg, Range, Rem, RemAssign, Sub, SubAssign,
+    },
+};
+use num_traits::{Euclid, Inv, MulAdd, NumCast, Pow, real::Real, Signed, Zero};
+use crate::{
+    dimension::DimType,
+    units::{system::MeasurementSystem, traits::*, unit_anon::AnonScale, UnitAnon},
+    Value,
 };
-use num_traits::{Inv, MulAdd, NumCast, Pow, real::Real, Signed, Zero};
-use crate::{units::{traits::*, UnitAnon}, Value};
 
 
 type ValueDefault = f64;
@@ -90,55 +111,219 @@ impl<U: Unit, V: Value> Quantity<U, V> {
         self.value_as(U::base())
     }
 
+    /// Return the value of this quantity, scaled to another unit by an exact
+    ///     [`ScaleRatio`](crate::units::ScaleRatio) rather than through `f64`.
+    ///
+    /// Returns `None` if either unit has no exact ratio, or if the ratio cannot
+    ///     be represented by the value type.
+    pub fn value_as_ratio<W: Unit<Dim=U::Dim>>(self, unit: W) -> Option<V> {
+        self.unit.scale_factor_ratio(unit)?.apply(self.value)
+    }
+
+    /// Return an equivalent quantity with the given unit of the same type,
+    ///     converting without floating point arithmetic. See
+    ///     [`Self::value_as_ratio`].
+    pub fn with_unit_ratio(self, unit: U) -> Option<Self> {
+        if unit == self.unit {
+            Some(self)
+        } else {
+            Some(Self {
+                value: self.value_as_ratio(unit)?,
+                unit,
+            })
+        }
+    }
+
+    /// Return an equivalent quantity with a unit of any type with the same
+    ///     dimension, converting without floating point arithmetic. See
+    ///     [`Self::value_as_ratio`].
+    pub fn convert_to_ratio<W: Unit<Dim=U::Dim>>(self, unit: W) -> Option<Quantity<W, V>> {
+        Some(unit.quantity(self.value_as_ratio(unit)?))
+    }
+
     /// Return an equivalent quantity with its value as close as possible to
     ///     being within the range `[1,1000)`.
     ///
-    /// This is done by repeatedly "stepping" the unit up or down. As such, it
-    ///     may be quite expensive for more complex compound units.
+    /// The unit chosen is the largest one that keeps the absolute value at or
+    ///     above `1`. If there is no such unit, the smallest one is chosen.
+    ///     Units are found with [`UnitStep::step_to_floor`], which is a binary
+    ///     search for unit types that provide a [table](UnitStep::step_table).
     pub fn normalize(self) -> Self where
         U: UnitStep,
         V: Real,
     {
         if self.value.is_zero() {
-            self.with_base()
-        } else {
-            let limit = crate::_conv_i32(3);
-            let mut log: V = self.value.log10();
-            let mut unit: U = self.unit;
-
-            if log.is_sign_positive() {
-                while log >= limit {
-                    let Some(next) = unit.step_up() else { break };
-
-                    let log_rel = unit.scale_factor_v::<U, V>(next).unwrap().log10();
-                    let log_new = log + log_rel;
-
-                    if log_new >= V::zero() {
-                        log = log_new;
-                        unit = next;
-                    } else {
-                        break;
-                    }
-                }
-            } else {
-                while log < V::zero() {
-                    let Some(next) = unit.step_down() else { break };
-
-                    let log_rel = unit.scale_factor_v::<U, V>(next).unwrap().log10();
-                    let log_new = log + log_rel;
-
-                    //  TODO: `<=` or `<`?
-                    if log_new <= limit {
-                        log = log_new;
-                        unit = next;
-                    } else {
-                        break;
-                    }
-                }
+            return self.with_base();
+        }
+
+        match self.value.abs().to_f64() {
+            Some(value) if value.is_finite() => {
+                let unit = self.unit.step_to_floor(value * self.unit.scale());
+                self.with_unit(unit)
+            }
+            _ => self,
+        }
+    }
+
+    /// Return an equivalent quantity with its value as close as possible to
+    ///     being within the range specified by the [options](NormalizeOptions).
+    ///
+    /// Units are considered by stepping up and down from the starting unit. If
+    ///     any acceptable unit brings the absolute value within the range, the
+    ///     first one found is chosen. Otherwise, values above the range are
+    ///     preferred over values below it, so that a quantity will be shown as
+    ///     `900 m` rather than `0.9 km` when no unit can do better.
+    pub fn normalize_with(self, options: &NormalizeOptions<U>) -> Self where
+        U: UnitStep,
+        V: Real,
+    {
+        if self.value.is_zero() {
+            return self.with_base();
+        }
+
+        let min: V = crate::_conv_f64(options.min);
+        let max: V = crate::_conv_f64(options.max);
+
+        let in_range = |v: V| min <= v && v < max;
+        let is_better = |new: V, old: V| normalize::is_better(new, old, min, max);
+
+        let origin: U = self.unit;
+        let value: V = self.value.abs();
+        let value_in = |unit: U| value * origin.scale_factor_v::<U, V>(unit).unwrap();
+
+        let start: U = (options.start)(&origin);
+        let start_value: V = value_in(start);
+
+        let mut best: Option<(U, V)> = None;
+
+        if options.accepts(&start) {
+            if in_range(start_value) {
+                return self.with_unit(start);
+            }
+
+            best = Some((start, start_value));
+        }
+
+        let mut consider = |unit: U, v: V| match best {
+            Some((_, old)) if !is_better(v, old) => {}
+            _ => best = Some((unit, v)),
+        };
+
+        //  Step up, decreasing the value, until it falls below the range.
+        let mut unit: U = start;
+        while let Some(next) = (options.step_up)(&unit) {
+            unit = next;
+            let v = value_in(unit);
+
+            if options.accepts(&unit) {
+                consider(unit, v);
+                if in_range(v) { break; }
             }
 
-            self.with_unit(unit)
+            if v < min { break; }
         }
+
+        //  Step down, increasing the value, until it rises above the range.
+        let mut unit: U = start;
+        while let Some(next) = (options.step_down)(&unit) {
+            unit = next;
+            let v = value_in(unit);
+
+            if options.accepts(&unit) {
+                consider(unit, v);
+                if in_range(v) { break; }
+            }
+
+            if v >= max { break; }
+        }
+
+        match best {
+            Some((unit, _)) => self.with_unit(unit),
+            None => self,
+        }
+    }
+
+    /// Return an equivalent quantity in whichever of the given units brings its
+    ///     value closest to being within a range, such as `1.0..1000.0`, or
+    ///     `None` if no units are given.
+    ///
+    /// Unlike [`normalize`](Self::normalize), the units may be of any type with
+    ///     the same dimension, in any order. If several units bring the value
+    ///     within the range, the largest is chosen. As with
+    ///     [`normalize_with`](Self::normalize_with), values above the range are
+    ///     preferred over values below it.
+    /// ```
+    /// use dana::prelude::*;
+    ///
+    /// let metric = [Length::MilliMeter, Length::CentiMeter, Length::Meter, Length::KiloMeter];
+    /// let imperial = [Length::Inch, Length::Foot, Length::Mile];
+    ///
+    /// let dist = Length::Meter.quantity(1_500.0);
+    /// assert_eq!(dist.best_of(&metric, 1.0..1000.0).unwrap().to_string(), "1.5 km");
+    /// assert_eq!(dist.best_of(&metric, 2.0..2000.0).unwrap().to_string(), "1500 m");
+    /// assert_eq!(dist.best_of(&imperial, 1.0..1000.0).unwrap().unit, Length::Foot);
+    /// ```
+    pub fn best_of<W: Unit<Dim=U::Dim>>(self, units: &[W], range: Range<f64>) -> Option<Quantity<W, V>> where
+        V: Real,
+    {
+        let (unit, value) = self.best_by(units, range, |unit| unit.scale())?;
+        Some(Quantity::new(*unit, value))
+    }
+
+    /// Return whichever of the given units brings the value of this quantity
+    ///     closest to being within a range, and the value in that unit, or
+    ///     `None` if no units are given.
+    ///
+    /// This is the same as [`best_of`](Self::best_of), but the units may each
+    ///     be of a different type. The result is a tuple rather than a
+    ///     [`Quantity`], because a `&dyn UnitAny` does not implement [`Unit`].
+    /// ```
+    /// use dana::{prelude::*, units::traits::UnitAny};
+    ///
+    /// let league = UnitRescale::new(Length::Mile, 3.0);
+    /// let units: [&dyn UnitAny<_>; 3] = [&Length::Foot, &Length::Mile, &league];
+    ///
+    /// let dist = Length::KiloMeter.quantity(9_656.064);
+    /// let (unit, value) = dist.best_of_any(&units, 1.0..1000.0).unwrap();
+    /// assert_eq!(unit.to_string(), league.to_string());
+    /// assert!((value - 2_000.0f64).abs() < 1e-9);
+    /// ```
+    pub fn best_of_any<'a>(
+        self,
+        units: &[&'a dyn UnitAny<U::Dim>],
+        range: Range<f64>,
+    ) -> Option<(&'a dyn UnitAny<U::Dim>, V)> where
+        V: Real,
+    {
+        let (unit, value) = self.best_by(units, range, |unit| unit.scale_any())?;
+        Some((*unit, value))
+    }
+
+    /// Find the unit in a list that brings the value of this quantity closest
+    ///     to being within a range.
+    fn best_by<T>(self, units: &[T], range: Range<f64>, scale: impl Fn(&T) -> f64) -> Option<(&T, V)> where
+        V: Real,
+    {
+        let min: V = crate::_conv_f64(range.start);
+        let max: V = crate::_conv_f64(range.end);
+        let value_in = |unit: &T| self.value * crate::_conv_f64(self.unit.scale() / scale(unit));
+
+        let mut units = units.iter();
+        let first = units.next()?;
+
+        let in_range = |v: V| min <= v && v < max;
+        let is_better = |new: V, old: V| normalize::is_better(new, old, min, max)
+            || (in_range(new) && in_range(old) && new < old);
+
+        Some(units.fold((first, value_in(first)), |best, unit| {
+            let value = value_in(unit);
+
+            if is_better(value.abs(), best.1.abs()) {
+                (unit, value)
+            } else {
+                best
+            }
+        }))
     }
 
     /// Return an equivalent quantity with the SI unit nearest to the current
@@ -156,6 +341,31 @@ impl<U: Unit, V: Value> Quantity<U, V> {
         }
     }
 
+    /// Return an equivalent quantity with the unit nearest to the current unit
+    ///     in a [system](UnitSystem) of measurement. Compound units are
+    ///     converted by converting each of their components.
+    /// ```
+    /// use dana::{prelude::*, symbols::basic::*, units::system::*};
+    ///
+    /// let speed = qty![100.0 km/h].into_system::<UsCustomary>();
+    /// assert_eq!(speed.unit, Length::Mile / Time::Hour);
+    /// ```
+    pub fn into_system<S: UnitSystem>(self) -> Self where
+        U: InSystem<S>,
+    {
+        let unit: U = self.unit.to_system();
+        self.with_unit(unit)
+    }
+
+    /// Return an equivalent quantity with the unit nearest to the current unit
+    ///     in a system of measurement chosen at runtime.
+    pub fn into_measurement_system(self, system: MeasurementSystem) -> Self where
+        U: InAnySystem,
+    {
+        let unit: U = self.unit.to_measurement_system(system);
+        self.with_unit(unit)
+    }
+
     /// Return a [`QtySimd`](crate::simd::QtySimd) array, for SIMD operations,
     ///     populated by this quantity.
     #[cfg(feature = "simd")]
@@ -247,6 +457,63 @@ impl<U: Unit, V: Value> Quantity<U, V> {
         }
     }
     //endregion
+
+    //region Quantization.
+    /// Return the multiple of `step` nearest to this quantity, in the unit of
+    ///     this quantity.
+    pub fn round_to<W>(self, step: Quantity<W, V>) -> Self where
+        W: Unit + ConvertInto<U>,
+        V: Real,
+    {
+        let step = step.convert_to(self.unit).value;
+        Self::new(self.unit, (self.value / step).round() * step)
+    }
+
+    /// Return the largest multiple of `step` less than or equal to this
+    ///     quantity, in the unit of this quantity.
+    pub fn floor_to<W>(self, step: Quantity<W, V>) -> Self where
+        W: Unit + ConvertInto<U>,
+        V: Real,
+    {
+        let step = step.convert_to(self.unit).value;
+        Self::new(self.unit, (self.value / step).floor() * step)
+    }
+
+    /// Return the smallest multiple of `step` greater than or equal to this
+    ///     quantity, in the unit of this quantity.
+    pub fn ceil_to<W>(self, step: Quantity<W, V>) -> Self where
+        W: Unit + ConvertInto<U>,
+        V: Real,
+    {
+        let step = step.convert_to(self.unit).value;
+        Self::new(self.unit, (self.value / step).ceil() * step)
+    }
+
+    /// Perform Euclidean division by another quantity of the same dimension,
+    ///     returning the dimensionless number of times it fits into this one,
+    ///     and the non-negative [remainder](Self::rem_euclid).
+    pub fn div_euclid<W>(self, rhs: Quantity<W, V>) -> (V, Self) where
+        W: Unit + ConvertInto<U>,
+        V: Euclid,
+    {
+        let rhs = rhs.convert_to(self.unit).value;
+
+        (
+            self.value.div_euclid(&rhs),
+            Self::new(self.unit, self.value.rem_euclid(&rhs)),
+        )
+    }
+
+    /// Return the non-negative remainder of Euclidean division by another
+    ///     quantity of the same dimension, in the unit of this quantity.
+    pub fn rem_euclid<W>(self, rhs: Quantity<W, V>) -> Self where
+        W: Unit + ConvertInto<U>,
+        V: Euclid,
+    {
+        let rhs = rhs.convert_to(self.unit).value;
+        Self::new(self.unit, self.value.rem_euclid(&rhs))
+    }
+    //endregion
 }
 //endregion
 
@@ -293,6 +560,14 @@ impl<U: Unit, V: Value> Quantity<U, V> {
         self.unit.conversion_into(unit).quantity(self.value)
     }
 
+    /// Perform unit conversion to a specific unit, without checking that it
+    ///     has the same dimension. This is only for use by operators whose
+    ///     bounds check the dimension in some other way.
+    pub(crate) fn convert_unchecked<W: Unit>(self, unit: W) -> Quantity<W, V> {
+        let factor: V = crate::_conv_f64(self.unit.scale() / unit.scale());
+        unit.quantity(self.value * factor)
+    }
+
     /// Cancel out units entirely, returning a scalar.
     pub fn cancel(self) -> V where
         U: Cancel,
@@ -302,13 +577,48 @@ impl<U: Unit, V: Value> Quantity<U, V> {
 }
 
 impl<U: Unit, V: Value> Quantity<crate::units::UnitPow<U, typenum::P1>, V> where
-    U::Dim: crate::dimension::CanDimPowType<typenum::P1>,
+    U::Dim: crate::dimension::CanDimPowRatio<typenum::P1, typenum::P1>,
 {
     /// Return an equivalent quantity with its exponent removed.
     pub fn cancel_exponent(self) -> Quantity<U, V> {
         self.unit.0.quantity(self.value)
     }
 }
+
+impl<D: DimType, S: AnonScale, V: Value + Real> Quantity<UnitAnon<D, S>, V> {
+    /// Convert this anonymous quantity to a unit type with the same dimension.
+    ///
+    /// If the scale of the anonymous unit matches one of the
+    ///     [variants](UnitStep::variants) of the target type, or a unit in the
+    ///     scale of its [base](Unit::base), that unit is used. Otherwise, the
+    ///     quantity is [normalized](Self::normalize) in the target type.
+    /// ```
+    /// use dana::{prelude::*, symbols::basic::*};
+    ///
+    /// let speed = qty![90.0 km/h as ?];
+    /// assert_eq!(speed.to_unit::<Speed>().to_string(), "25 m/s");
+    ///
+    /// let dist = qty![(speed * qty![2.0 min]) as ?];
+    /// assert_eq!(dist.to_unit::<Length>(), qty![3.0 km]);
+    ///
+    /// let dist = Length::Mile.quantity(3.0).with_anonymous();
+    /// assert_eq!(dist.to_unit::<Length>().to_string(), "3 mi");
+    /// ```
+    pub fn to_unit<U: UnitStep<Dim=D>>(self) -> Quantity<U, V> {
+        use crate::units::unit_anon::{same_scale, SCALE_TOLERANCE};
+
+        let scale = self.unit.scale();
+        let unit = U::variants()
+            .and_then(|units| units.iter().copied().find(|unit| same_scale(unit.scale(), scale)))
+            .or_else(|| Some(U::base().step_to_floor(scale * (1.0 + SCALE_TOLERANCE)))
+                .filter(|unit| same_scale(unit.scale(), scale)));
+
+        match unit {
+            Some(unit) => self.convert_unchecked(unit),
+            None => self.convert_unchecked(U::base()).normalize(),
+        }
+    }
+}
 //endregion
 
 
@@ -330,48 +640,73 @@ impl<U: Unit, V: Value> Neg for Quantity<U, V> where
 
 //region Addition/subtraction between same-unit quantities.
 impl<U: Unit, V: Value, W: Unit, X: Value> Add<Quantity<W, X>> for Quantity<U, V> where
-    W: ConvertInto<U>,
+    U: CanUnitAdd<W>,
     V: Add<X>, <V as Add<X>>::Output: Value,
 {
     type Output = Quantity<U, <V as Add<X>>::Output>;
 
     fn add(self, rhs: Quantity<W, X>) -> Self::Output {
         Quantity {
-            value: self.value + rhs.convert_to(self.unit).value,
+            value: self.value + rhs.convert_unchecked(self.unit).value,
             unit: self.unit,
         }
     }
 }
 
 impl<U: Unit, V: Value, W: Unit, X: Value> Sub<Quantity<W, X>> for Quantity<U, V> where
-    W: ConvertInto<U>,
+    U: CanUnitSub<W>,
     V: Sub<X>, <V as Sub<X>>::Output: Value,
 {
     type Output = Quantity<U, <V as Sub<X>>::Output>;
 
     fn sub(self, rhs: Quantity<W, X>) -> Self::Output {
         Quantity {
-            value: self.value - rhs.convert_to(self.unit).value,
+            value: self.value - rhs.convert_unchecked(self.unit).value,
             unit: self.unit,
         }
     }
 }
 
 impl<U: Unit, V: Value, W: Unit, X: Value> AddAssign<Quantity<W, X>> for Quantity<U, V> where
-    W: ConvertInto<U>,
+    U: CanUnitAdd<W>,
     V: AddAssign<X>,
 {
     fn add_assign(&mut self, rhs: Quantity<W, X>) {
-        self.value += rhs.convert_to(self.unit).value;
+        self.value += rhs.convert_unchecked(self.unit).value;
     }
 }
 
 impl<U: Unit, V: Value, W: Unit, X: Value> SubAssign<Quantity<W, X>> for Quantity<U, V> where
-    W: ConvertInto<U>,
+    U: CanUnitSub<W>,
     V: SubAssign<X>,
 {
     fn sub_assign(&mut self, rhs: Quantity<W, X>) {
-        self.value -= rhs.convert_to(self.unit).value;
+        self.value -= rhs.convert_unchecked(self.unit).value;
+    }
+}
+//endregion
+
+//region Remainder between same-unit quantities.
+impl<U: Unit, V: Value, W: Unit, X: Value> Rem<Quantity<W, X>> for Quantity<U, V> where
+    W: ConvertInto<U>,
+    V: Rem<X>, <V as Rem<X>>::Output: Value,
+{
+    type Output = Quantity<U, <V as Rem<X>>::Output>;
+
+    fn rem(self, rhs: Quantity<W, X>) -> Self::Output {
+        Quantity {
+            value: self.value % rhs.convert_to(self.unit).value,
+            unit: self.unit,
+        }
+    }
+}
+
+impl<U: Unit, V: Value, W: Unit, X: Value> RemAssign<Quantity<W, X>> for Quantity<U, V> where
+    W: ConvertInto<U>,
+    V: RemAssign<X>,
+{
+    fn rem_assign(&mut self, rhs: Quantity<W, X>) {
+        self.value %= rhs.convert_to(self.unit).value;
     }
 }
 //endregion
@@ -0,0 +1,80 @@
+//! Module for approximate comparison of quantities.
+
+use num_traits::{real::Real, Signed};
+use crate::{units::traits::*, value::UlpDistance, Quantity, Value};
+
+
+impl<U: Unit, V: Value> Quantity<U, V> {
+    /// Return `true` if another quantity of the same dimension is within the
+    ///     given limit of this one. The limit is also a quantity, so it can be
+    ///     given in any unit of the same dimension.
+    pub fn almost_eq_within<W, T>(self, rhs: Quantity<W, V>, limit: Quantity<T, V>) -> bool where
+        W: Unit<Dim=U::Dim>,
+        T: Unit<Dim=U::Dim>,
+        V: Signed + PartialOrd,
+    {
+        let limit = limit.value_as(self.unit);
+        (self - rhs).abs().value <= limit
+    }
+
+    /// Return the difference between this quantity and another of the same
+    ///     dimension, relative to the larger of their magnitudes. If both are
+    ///     zero, the result is zero.
+    pub fn relative_diff<W: Unit<Dim=U::Dim>>(self, rhs: Quantity<W, V>) -> V where
+        V: Real,
+    {
+        let rhs = rhs.value_as(self.unit);
+        let scale = self.value.abs().max(rhs.abs());
+
+        if scale.is_zero() {
+            V::zero()
+        } else {
+            (self.value - rhs).abs() / scale
+        }
+    }
+
+    /// Return `true` if the [relative difference](Self::relative_diff) between
+    ///     this quantity and another is no greater than the given limit.
+    pub fn almost_eq_rel<W: Unit<Dim=U::Dim>>(self, rhs: Quantity<W, V>, limit: V) -> bool where
+        V: Real,
+    {
+        //  NOTE: Written this way around so that a NaN difference fails.
+        self.relative_diff(rhs) <= limit
+    }
+
+    /// Return the distance between this quantity and another of the same
+    ///     dimension, in units in the last place of the value type, after the
+    ///     other is converted to the unit of this one.
+    pub fn ulp_distance<W: Unit<Dim=U::Dim>>(self, rhs: Quantity<W, V>) -> Option<u64> where
+        V: UlpDistance,
+    {
+        self.value.ulp_distance(&rhs.value_as(self.unit))
+    }
+
+    /// Return `true` if another quantity is no more than the given number of
+    ///     [ULPs](Self::ulp_distance) away from this one.
+    pub fn almost_eq_ulps<W: Unit<Dim=U::Dim>>(self, rhs: Quantity<W, V>, ulps: u64) -> bool where
+        V: UlpDistance,
+    {
+        matches!(self.ulp_distance(rhs), Some(n) if n <= ulps)
+    }
+}
+
+
+/// Trait for a limit on the absolute difference between two quantities, as
+///     used by [`assert_qty_approx!`](crate::assert_qty_approx).
+///
+/// A plain value is a limit in the unit of the quantities being compared,
+///     while a [`Quantity`] is converted to that unit.
+pub trait ApproxLimit<U: Unit, V: Value> {
+    /// Return this limit as a value in the given unit.
+    fn limit_in(&self, unit: U) -> V;
+}
+
+impl<U: Unit, V: Value> ApproxLimit<U, V> for V {
+    fn limit_in(&self, _unit: U) -> V { self.clone() }
+}
+
+impl<U: Unit, V: Value, W: Unit<Dim=U::Dim>> ApproxLimit<U, V> for Quantity<W, V> {
+    fn limit_in(&self, unit: U) -> V { self.clone().value_as(unit) }
+}
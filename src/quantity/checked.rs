@@ -0,0 +1,198 @@
+//! Module for overflow-aware arithmetic on quantities, mainly useful with
+//!     integer values.
+
+use core::ops::{Div, Mul};
+use num_traits::{
+    Bounded,
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub,
+    SaturatingAdd, SaturatingMul, SaturatingSub,
+    WrappingAdd, WrappingMul, WrappingSub,
+};
+use crate::{units::traits::*, Quantity, Value};
+
+
+//region Conversion.
+impl<U: Unit, V: Value> Quantity<U, V> {
+    /// Convert this quantity to another unit of the same dimension, returning
+    ///     `None` if the result would overflow the value type.
+    ///
+    /// Units with an exact [`ScaleRatio`](crate::units::ScaleRatio) are
+    ///     converted with integer arithmetic, so that no precision is lost to
+    ///     floating point for large values. Other units are converted through
+    ///     `f64`. Integer values are rounded toward zero.
+    pub fn try_convert<W: Unit<Dim=U::Dim>>(self, unit: W) -> Option<Quantity<W, V>> where
+        V: CheckedMul + CheckedDiv,
+    {
+        if self.value.is_zero() {
+            return Some(unit.quantity(self.value));
+        }
+
+        let value = match self.unit.scale_factor_ratio(unit) {
+            Some(ratio) => ratio.checked_apply(self.value)?,
+            None => V::from_f64(self.value.to_f64()? * self.unit.scale_factor(unit))?,
+        };
+
+        Some(unit.quantity(value))
+    }
+
+    /// Convert this quantity to another unit of the same dimension. If the
+    ///     result would overflow the value type, it is saturated at the bound
+    ///     of the type instead.
+    pub fn saturating_convert<W: Unit<Dim=U::Dim>>(self, unit: W) -> Quantity<W, V> where
+        V: CheckedMul + CheckedDiv + Bounded + PartialOrd,
+    {
+        let negative = self.value < V::zero();
+
+        self.try_convert(unit).unwrap_or_else(|| unit.quantity(
+            if negative { V::min_value() } else { V::max_value() }
+        ))
+    }
+
+    /// Convert this quantity to another unit of the same dimension. If the
+    ///     conversion is a multiplication by an integer, it wraps around at
+    ///     the bounds of the value type.
+    ///
+    /// # Panics
+    /// Panics if the conversion is not a multiplication by an integer and its
+    ///     result would overflow the value type.
+    pub fn wrapping_convert<W: Unit<Dim=U::Dim>>(self, unit: W) -> Quantity<W, V> where
+        V: CheckedMul + CheckedDiv + WrappingMul,
+    {
+        if let Some(ratio) = self.unit.scale_factor_ratio(unit) {
+            if let (1, Some(factor)) = (ratio.den, V::from_u64(ratio.num)) {
+                return unit.quantity(self.value.wrapping_mul(&factor));
+            }
+        }
+
+        self.try_convert(unit).expect("quantity conversion overflowed")
+    }
+}
+//endregion
+
+
+//region Checked arithmetic.
+impl<U: Unit, V: Value> Quantity<U, V> {
+    /// Add another quantity, converted to the unit of this one, returning
+    ///     `None` on overflow.
+    pub fn checked_add<W: Unit<Dim=U::Dim>>(self, rhs: Quantity<W, V>) -> Option<Self> where
+        V: CheckedAdd + CheckedMul + CheckedDiv,
+    {
+        let rhs = rhs.try_convert(self.unit)?;
+        Some(Self::new(self.unit, self.value.checked_add(&rhs.value)?))
+    }
+
+    /// Subtract another quantity, converted to the unit of this one, returning
+    ///     `None` on overflow.
+    pub fn checked_sub<W: Unit<Dim=U::Dim>>(self, rhs: Quantity<W, V>) -> Option<Self> where
+        V: CheckedSub + CheckedMul + CheckedDiv,
+    {
+        let rhs = rhs.try_convert(self.unit)?;
+        Some(Self::new(self.unit, self.value.checked_sub(&rhs.value)?))
+    }
+
+    /// Multiply by another quantity, returning `None` on overflow.
+    pub fn checked_mul<W: Unit>(self, rhs: Quantity<W, V>) -> Option<Quantity<U::Output, V>> where
+        U: Mul<W>, U::Output: Unit,
+        V: CheckedMul,
+    {
+        Some(Quantity::new(self.unit * rhs.unit, self.value.checked_mul(&rhs.value)?))
+    }
+
+    /// Divide by another quantity, returning `None` on overflow or division by
+    ///     zero.
+    pub fn checked_div<W: Unit>(self, rhs: Quantity<W, V>) -> Option<Quantity<U::Output, V>> where
+        U: Div<W>, U::Output: Unit,
+        V: CheckedDiv,
+    {
+        Some(Quantity::new(self.unit / rhs.unit, self.value.checked_div(&rhs.value)?))
+    }
+
+    /// Return the remainder of division by another quantity, converted to the
+    ///     unit of this one, returning `None` on overflow or division by zero.
+    pub fn checked_rem<W: Unit<Dim=U::Dim>>(self, rhs: Quantity<W, V>) -> Option<Self> where
+        V: CheckedRem + CheckedMul + CheckedDiv,
+    {
+        let rhs = rhs.try_convert(self.unit)?;
+        Some(Self::new(self.unit, self.value.checked_rem(&rhs.value)?))
+    }
+
+    /// Negate this quantity, returning `None` on overflow.
+    pub fn checked_neg(self) -> Option<Self> where
+        V: CheckedNeg,
+    {
+        Some(Self::new(self.unit, self.value.checked_neg()?))
+    }
+}
+//endregion
+
+
+//region Saturating arithmetic.
+impl<U: Unit, V: Value> Quantity<U, V> {
+    /// Add another quantity, converted to the unit of this one, saturating at
+    ///     the bounds of the value type.
+    pub fn saturating_add<W: Unit<Dim=U::Dim>>(self, rhs: Quantity<W, V>) -> Self where
+        V: SaturatingAdd + CheckedMul + CheckedDiv + Bounded + PartialOrd,
+    {
+        let rhs = rhs.saturating_convert(self.unit);
+        Self::new(self.unit, self.value.saturating_add(&rhs.value))
+    }
+
+    /// Subtract another quantity, converted to the unit of this one,
+    ///     saturating at the bounds of the value type.
+    pub fn saturating_sub<W: Unit<Dim=U::Dim>>(self, rhs: Quantity<W, V>) -> Self where
+        V: SaturatingSub + CheckedMul + CheckedDiv + Bounded + PartialOrd,
+    {
+        let rhs = rhs.saturating_convert(self.unit);
+        Self::new(self.unit, self.value.saturating_sub(&rhs.value))
+    }
+
+    /// Multiply by another quantity, saturating at the bounds of the value
+    ///     type.
+    pub fn saturating_mul<W: Unit>(self, rhs: Quantity<W, V>) -> Quantity<U::Output, V> where
+        U: Mul<W>, U::Output: Unit,
+        V: SaturatingMul,
+    {
+        Quantity::new(self.unit * rhs.unit, self.value.saturating_mul(&rhs.value))
+    }
+}
+//endregion
+
+
+//region Wrapping arithmetic.
+impl<U: Unit, V: Value> Quantity<U, V> {
+    /// Add another quantity, converted to the unit of this one, wrapping
+    ///     around at the bounds of the value type.
+    ///
+    /// # Panics
+    /// Panics if the right-hand side cannot be converted, as described by
+    ///     [`Self::wrapping_convert`].
+    pub fn wrapping_add<W: Unit<Dim=U::Dim>>(self, rhs: Quantity<W, V>) -> Self where
+        V: WrappingAdd + WrappingMul + CheckedMul + CheckedDiv,
+    {
+        let rhs = rhs.wrapping_convert(self.unit);
+        Self::new(self.unit, self.value.wrapping_add(&rhs.value))
+    }
+
+    /// Subtract another quantity, converted to the unit of this one, wrapping
+    ///     around at the bounds of the value type.
+    ///
+    /// # Panics
+    /// Panics if the right-hand side cannot be converted, as described by
+    ///     [`Self::wrapping_convert`].
+    pub fn wrapping_sub<W: Unit<Dim=U::Dim>>(self, rhs: Quantity<W, V>) -> Self where
+        V: WrappingSub + WrappingMul + CheckedMul + CheckedDiv,
+    {
+        let rhs = rhs.wrapping_convert(self.unit);
+        Self::new(self.unit, self.value.wrapping_sub(&rhs.value))
+    }
+
+    /// Multiply by another quantity, wrapping around at the bounds of the
+    ///     value type.
+    pub fn wrapping_mul<W: Unit>(self, rhs: Quantity<W, V>) -> Quantity<U::Output, V> where
+        U: Mul<W>, U::Output: Unit,
+        V: WrappingMul,
+    {
+        Quantity::new(self.unit * rhs.unit, self.value.wrapping_mul(&rhs.value))
+    }
+}
+//endregion
@@ -0,0 +1,56 @@
+//! Module for methods specific to quantities with [`Complex`] values.
+
+use num_traits::real::Real;
+use crate::{units::traits::*, value::Complex, Quantity, Value};
+
+
+impl<U: Unit, V: Value> Quantity<U, V> {
+    /// Return an equivalent quantity with a [`Complex`] value, with no
+    ///     imaginary part.
+    pub fn into_complex(self) -> Quantity<U, Complex<V>> where
+        Complex<V>: Value,
+    {
+        Quantity::new(self.unit, Complex::real(self.value))
+    }
+}
+
+
+impl<U: Unit, V: Value + Real> Quantity<U, Complex<V>> where
+    Complex<V>: Value,
+{
+    /// Construct a new phasor quantity from a magnitude and a phase angle in
+    ///     radians. The unit of the magnitude is used for the result.
+    pub fn from_polar(magnitude: Quantity<U, V>, phase: V) -> Self {
+        Self::new(magnitude.unit, Complex::from_polar(magnitude.value, phase))
+    }
+
+    /// Return the real part of this quantity.
+    pub fn re(self) -> Quantity<U, V> {
+        Quantity::new(self.unit, self.value.re)
+    }
+
+    /// Return the imaginary part of this quantity.
+    pub fn im(self) -> Quantity<U, V> {
+        Quantity::new(self.unit, self.value.im)
+    }
+
+    /// Return the magnitude of this quantity.
+    pub fn magnitude(self) -> Quantity<U, V> {
+        Quantity::new(self.unit, self.value.norm())
+    }
+
+    /// Return the phase angle of this quantity, in radians.
+    pub fn phase(self) -> V {
+        self.value.arg()
+    }
+
+    /// Return the magnitude and phase angle of this quantity.
+    pub fn to_polar(self) -> (Quantity<U, V>, V) {
+        (self.magnitude(), self.phase())
+    }
+
+    /// Return the complex conjugate of this quantity.
+    pub fn conj(self) -> Self {
+        Self::new(self.unit, self.value.conj())
+    }
+}
@@ -0,0 +1,65 @@
+//! Module for methods specific to quantities with [`Dual`] values.
+
+use crate::{units::{traits::*, UnitDiv}, value::Dual, Quantity, Value};
+
+
+impl<U: Unit, V: Value> Quantity<U, V> {
+    /// Return an equivalent quantity with a [`Dual`] value, representing the
+    ///     variable of differentiation.
+    pub fn into_variable(self) -> Quantity<U, Dual<V>> where
+        Dual<V>: Value,
+    {
+        Quantity::new(self.unit, Dual::variable(self.value))
+    }
+
+    /// Return an equivalent quantity with a [`Dual`] value, representing a
+    ///     constant.
+    pub fn into_constant(self) -> Quantity<U, Dual<V>> where
+        Dual<V>: Value,
+    {
+        Quantity::new(self.unit, Dual::constant(self.value))
+    }
+}
+
+
+impl<U: Unit, V: Value> Quantity<U, Dual<V>> where
+    Dual<V>: Value,
+{
+    /// Return the real part of this quantity.
+    pub fn re(self) -> Quantity<U, V> {
+        Quantity::new(self.unit, self.value.re)
+    }
+}
+
+
+/// Evaluate a function at a point, returning both its result and its
+///     derivative with respect to the input.
+///
+/// The derivative has the unit of the output divided by the unit of the input.
+///     For example, the derivative of a heat loss in watts with respect to a
+///     temperature in kelvin will be in watts per kelvin.
+pub fn value_and_derivative<X: Unit, Y: Unit, V: Value>(
+    f: impl FnOnce(Quantity<X, Dual<V>>) -> Quantity<Y, Dual<V>>,
+    at: Quantity<X, V>,
+) -> (Quantity<Y, V>, Quantity<UnitDiv<Y, X>, V>) where
+    Dual<V>: Value,
+    UnitDiv<Y, X>: Unit,
+{
+    let unit = at.unit;
+    let Quantity { unit: out, value: Dual { re, eps } } = f(at.into_variable());
+
+    (Quantity::new(out, re), Quantity::new(UnitDiv::new(out, unit), eps))
+}
+
+
+/// Evaluate the derivative of a function at a point, with respect to its input.
+///     See [`value_and_derivative`].
+pub fn derivative<X: Unit, Y: Unit, V: Value>(
+    f: impl FnOnce(Quantity<X, Dual<V>>) -> Quantity<Y, Dual<V>>,
+    at: Quantity<X, V>,
+) -> Quantity<UnitDiv<Y, X>, V> where
+    Dual<V>: Value,
+    UnitDiv<Y, X>: Unit,
+{
+    value_and_derivative(f, at).1
+}
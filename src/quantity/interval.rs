@@ -0,0 +1,85 @@
+//! Module for methods specific to quantities with [`Interval`] values.
+
+use num_traits::real::Real;
+use crate::{units::traits::*, value::Interval, Quantity, Value};
+
+
+impl<U: Unit, V: Value + Real> Quantity<U, V> where
+    Interval<V>: Value,
+{
+    /// Return an equivalent quantity with a degenerate [`Interval`] value.
+    pub fn into_interval(self) -> Quantity<U, Interval<V>> {
+        Quantity::new(self.unit, Interval::point(self.value))
+    }
+
+    /// Return a quantity with an [`Interval`] value, containing every value
+    ///     within the given tolerance of this quantity.
+    pub fn with_tolerance<W>(self, tolerance: Quantity<W, V>) -> Quantity<U, Interval<V>> where
+        W: Unit + ConvertInto<U>,
+    {
+        let tolerance = tolerance.into_interval().convert_to(self.unit).value.hi;
+        Quantity::new(self.unit, Interval::with_tolerance(self.value, tolerance))
+    }
+}
+
+
+impl<U: Unit, V: Value + Real> Quantity<U, Interval<V>> where
+    Interval<V>: Value,
+{
+    /// Construct a new quantity with an [`Interval`] value between two bounds,
+    ///     in the unit of the first.
+    pub fn between<W>(lo: Quantity<U, V>, hi: Quantity<W, V>) -> Self where
+        W: Unit + ConvertInto<U>,
+    {
+        let hi = hi.into_interval().convert_to(lo.unit).value;
+        Self::new(lo.unit, Interval::point(lo.value).hull(&hi))
+    }
+
+    /// Return the lower bound of this quantity.
+    pub fn lo(self) -> Quantity<U, V> {
+        Quantity::new(self.unit, self.value.lo)
+    }
+
+    /// Return the upper bound of this quantity.
+    pub fn hi(self) -> Quantity<U, V> {
+        Quantity::new(self.unit, self.value.hi)
+    }
+
+    /// Return the midpoint of this quantity.
+    pub fn midpoint(self) -> Quantity<U, V> {
+        Quantity::new(self.unit, self.value.midpoint())
+    }
+
+    /// Return the width of this quantity, rounded upward.
+    pub fn width(self) -> Quantity<U, V> {
+        Quantity::new(self.unit, self.value.width())
+    }
+
+    /// Return `true` if this quantity is guaranteed to contain the given value.
+    ///     The value is converted into the unit of this quantity, and must be
+    ///     contained even after accounting for rounding of the conversion.
+    pub fn contains<W>(self, qty: Quantity<W, V>) -> bool where
+        W: Unit + ConvertInto<U>,
+    {
+        let qty = qty.into_interval().convert_to(self.unit).value;
+        self.value.contains_interval(&qty)
+    }
+
+    /// Return `true` if every value in this quantity is guaranteed to be less
+    ///     than or equal to the given limit.
+    pub fn is_below<W>(self, limit: Quantity<W, V>) -> bool where
+        W: Unit + ConvertInto<U>,
+    {
+        let limit = limit.into_interval().convert_to(self.unit).value;
+        self.value.hi <= limit.lo
+    }
+
+    /// Return `true` if every value in this quantity is guaranteed to be
+    ///     greater than or equal to the given limit.
+    pub fn is_above<W>(self, limit: Quantity<W, V>) -> bool where
+        W: Unit + ConvertInto<U>,
+    {
+        let limit = limit.into_interval().convert_to(self.unit).value;
+        self.value.lo >= limit.hi
+    }
+}
@@ -0,0 +1,130 @@
+//! Module for options controlling [`Quantity::normalize_with`].
+//!
+//! [`Quantity::normalize_with`]: crate::Quantity::normalize_with
+
+use num_traits::real::Real;
+use crate::units::traits::*;
+
+
+/// Options controlling how [`Quantity::normalize_with`] chooses a unit.
+///
+/// The default options use the same target range as [`Quantity::normalize`],
+///     `[1, 1000)`. Every unit is acceptable, and the unit is stepped one
+///     variant at a time according to its [`UnitStep`] implementation.
+///
+/// [`Quantity::normalize`]: crate::Quantity::normalize
+/// [`Quantity::normalize_with`]: crate::Quantity::normalize_with
+#[derive(Clone, Copy, Debug)]
+pub struct NormalizeOptions<U: UnitStep> {
+    /// Lower bound of the target range (inclusive).
+    pub min: f64,
+    /// Upper bound of the target range (exclusive).
+    pub max: f64,
+    /// If `true`, only units with scales that are integer powers of 1000 may
+    ///     be chosen.
+    pub engineering: bool,
+    /// Predicate that a unit must satisfy in order to be chosen.
+    pub filter: fn(&U) -> bool,
+    /// Function applied to the unit before any stepping is done.
+    pub start: fn(&U) -> U,
+    /// Function used to step the unit down.
+    pub step_down: fn(&U) -> Option<U>,
+    /// Function used to step the unit up.
+    pub step_up: fn(&U) -> Option<U>,
+}
+
+impl<U: UnitStep> NormalizeOptions<U> {
+    /// Construct a new set of options, with the default range of `[1, 1000)`.
+    pub fn new() -> Self {
+        Self {
+            min: 1.0,
+            max: 1000.0,
+            engineering: false,
+            filter: |_| true,
+            start: |unit| *unit,
+            step_down: U::step_down,
+            step_up: U::step_up,
+        }
+    }
+
+    /// Set the target range to `[min, max)`.
+    pub fn with_range(self, min: f64, max: f64) -> Self {
+        Self { min, max, ..self }
+    }
+
+    /// Only allow units with scales that are integer powers of 1000.
+    pub fn engineering(self) -> Self {
+        Self { engineering: true, ..self }
+    }
+
+    /// Only allow units that satisfy the given predicate.
+    pub fn with_filter(self, filter: fn(&U) -> bool) -> Self {
+        Self { filter, ..self }
+    }
+
+    /// Only allow SI units, according to [`UnitMixed::is_si`]. Stepping will
+    ///     begin from the [nearest SI unit](UnitMixed::to_si).
+    pub fn si_only(self) -> Self where U: UnitMixed {
+        Self { filter: U::is_si, start: U::to_si, ..self }
+    }
+
+    /// Only allow units in a [system](UnitSystem) of measurement. Stepping will
+    ///     begin from the [nearest unit](InSystem::to_system) in the system.
+    pub fn system_only<S: UnitSystem>(self) -> Self where U: InSystem<S> {
+        Self {
+            filter: <U as InSystem<S>>::is_in_system,
+            start: <U as InSystem<S>>::to_system,
+            ..self
+        }
+    }
+
+    /// Only step the left-hand side of a [binary unit](UnitBinary). For a
+    ///     [`UnitDiv`](crate::units::UnitDiv), this means that only the
+    ///     numerator will be prefixed (`km/h`, never `m/ms`).
+    pub fn numerator_only(self) -> Self where
+        U: UnitBinary,
+        U::Lhs: UnitStep,
+    {
+        Self {
+            step_down: U::step_lhs_down,
+            step_up: U::step_lhs_up,
+            ..self
+        }
+    }
+
+    /// Return `true` if the given unit may be chosen under these options.
+    pub fn accepts(&self, unit: &U) -> bool {
+        (self.filter)(unit) && (!self.engineering || is_engineering(unit.scale()))
+    }
+}
+
+impl<U: UnitStep> Default for NormalizeOptions<U> {
+    fn default() -> Self { Self::new() }
+}
+
+
+/// Return `true` if a new value is closer than an old value to being within
+///     the range `[min, max)`. Values above the range are preferred over values
+///     below it.
+pub(super) fn is_better<V: PartialOrd>(new: V, old: V, min: V, max: V) -> bool {
+    let in_range = |v: &V| min <= *v && *v < max;
+
+    match (in_range(&new), in_range(&old)) {
+        (true, true) => false,
+        (true, false) => true,
+        (false, true) => false,
+        (false, false) => match (new >= max, old >= max) {
+            (true, true) => new < old,
+            (true, false) => true,
+            (false, true) => false,
+            (false, false) => new > old,
+        },
+    }
+}
+
+
+/// Return `true` if a scale is an integer power of 1000.
+fn is_engineering(scale: f64) -> bool {
+    let exp = Real::log10(scale) / 3.0;
+    Real::abs(exp - Real::round(exp)) < 1e-9
+}
@@ -0,0 +1,252 @@
+//! Module for splitting quantities into components of several units, and for
+//!     parsing them back.
+
+use core::{fmt::Display, str::FromStr};
+use num_traits::real::Real;
+use crate::{error::ParseQuantityError, Quantity, units::traits::*, Value};
+
+
+/// A [`Quantity`] split into components of several units, as returned by
+///     [`Quantity::display_split`].
+///
+/// When displayed, components with a value of zero are omitted, and only the
+///     final component is affected by formatting options such as precision:
+/// ```
+/// use dana::prelude::*;
+///
+/// let duration = Time::Second.quantity(5_004.5);
+/// let split = duration.display_split(&[Time::Hour, Time::Minute, Time::Second]);
+///
+/// assert_eq!(format!("{split}"), "1 h 23 min 24.5 s");
+/// assert_eq!(format!("{split:.2}"), "1 h 23 min 24.50 s");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct QuantitySplit<U: Unit, V: Value, const N: usize>(pub [Quantity<U, V>; N]);
+
+impl<U: Unit, V: Value + Real + Display, const N: usize> Display
+for QuantitySplit<U, V, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Some((last, parts)) = self.0.split_last() else { return Ok(()) };
+
+        if self.0.iter().any(|part| part.value < V::zero()) {
+            f.write_str("-")?;
+        }
+
+        let abs = |part: &Quantity<U, V>| Quantity::new(part.unit, part.value.abs());
+
+        for part in parts.iter().filter(|part| !part.value.is_zero()) {
+            write!(f, "{} ", abs(part))?;
+        }
+
+        Display::fmt(&abs(last), f)
+    }
+}
+
+
+impl<U: UnitConcrete, V: Value + Real> Quantity<U, V> {
+    /// Split this quantity into components of the given units, which must be
+    ///     in descending order of scale.
+    ///
+    /// Every component except the last has an integer value. The last holds
+    ///     the remainder, which may have a fractional part. If the quantity is
+    ///     negative, every component is negative.
+    /// ```
+    /// use dana::prelude::*;
+    ///
+    /// let height = Length::Inch.quantity(63.5);
+    /// let [ft, inch] = height.split_into(&[Length::Foot, Length::Inch]);
+    ///
+    /// assert_eq!(ft, Length::Foot.quantity(5.0));
+    /// assert_eq!(inch, Length::Inch.quantity(3.5));
+    /// ```
+    pub fn split_into<const N: usize>(self, units: &[U; N]) -> [Self; N] {
+        debug_assert!(
+            units.windows(2).all(|pair| pair[0].scale() > pair[1].scale()),
+            "units must be in descending order of scale",
+        );
+
+        let Some(&last) = units.last() else { return [self; N] };
+        let negative = self.value < V::zero();
+        let mut rest: V = self.value_as(last).abs();
+
+        core::array::from_fn(|i| {
+            let unit = units[i];
+
+            let value = if i + 1 < N {
+                let ratio: V = unit.scale_factor_ratio(last)
+                    .and_then(|ratio| ratio.apply(V::one()))
+                    .unwrap_or_else(|| crate::_conv_f64(snap(unit.scale_factor(last))));
+                let whole = (rest / ratio).floor();
+
+                rest = (rest - whole * ratio).max(V::zero());
+                whole
+            } else {
+                rest
+            };
+
+            unit.quantity(if negative { -value } else { value })
+        })
+    }
+
+    /// Split this quantity into components of the given units, returning a
+    ///     value that will [display](QuantitySplit) them together.
+    ///
+    /// See [`Quantity::split_into`] for details.
+    pub fn display_split<const N: usize>(self, units: &[U; N]) -> QuantitySplit<U, V, N> {
+        QuantitySplit(self.split_into(units))
+    }
+}
+
+
+/// Parse a quantity from one or more components, each made up of a number and
+///     a [unit symbol](UnitConcrete::from_symbol), such as `"5ft 3in"` or
+///     `"1h30m"`. Whitespace between components, and between a number and its
+///     symbol, is optional. A symbol may itself contain spaces, such as
+///     `"2 fl oz"`. A leading sign applies to the whole quantity.
+///
+/// The result is expressed in the unit of the last component.
+/// ```
+/// use dana::prelude::*;
+///
+/// let height: Quantity<Length> = "5ft 3in".parse().unwrap();
+/// assert_eq!(height, Length::Inch.quantity(63.0));
+///
+/// let duration: Quantity<Time> = "1h30m".parse().unwrap();
+/// assert_eq!(duration, Time::Minute.quantity(90.0));
+/// ```
+impl<U: UnitConcrete, V: Value> FromStr for Quantity<U, V> {
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with(s, U::from_symbol)
+    }
+}
+
+
+impl<U: Unit, V: Value> Quantity<U, V> {
+    /// Parse a quantity in the same format as its [`FromStr`] implementation,
+    ///     using a function to look up the unit for each symbol. This allows
+    ///     parsing units which are only known at runtime, such as
+    ///     [named units](crate::units::UnitNamed):
+    /// ```
+    /// use dana::{prelude::*, units::UnitNamed};
+    ///
+    /// let units = [
+    ///     UnitRescale::new(Length::Meter, 201.168).named("fur", "furlong"),
+    ///     UnitRescale::new(Length::Meter, 20.1168).named("ch", "chain"),
+    /// ];
+    ///
+    /// let dist = Quantity::<_, f64>::from_str_with("2fur 5ch", |s| UnitNamed::find(&units, s));
+    /// assert_eq!(dist.unwrap(), units[1].quantity(25.0));
+    /// ```
+    ///
+    /// Components are converted to the unit of the last component with an
+    ///     exact [`ScaleRatio`](crate::units::ScaleRatio) where both units have
+    ///     one.
+    ///
+    /// # Errors
+    /// Returns [`ParseQuantityError::UnknownUnit`] if the function returns
+    ///     `None` for any symbol, [`ParseQuantityError::NegativeValue`] or
+    ///     [`ParseQuantityError::InexactValue`] if the value type cannot hold
+    ///     the result, such as `"-3 m"` or `"1 in 1 ft"` for an unsigned integer,
+    ///     or another [`ParseQuantityError`] if the string is not in the
+    ///     correct format.
+    pub fn from_str_with(s: &str, lookup: impl Fn(&str) -> Option<U>) -> Result<Self, ParseQuantityError> {
+        let s = s.trim();
+        let (negative, mut rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let is_numeric = |c: char| c.is_ascii_digit() || c == '.';
+        let mut total: Option<Self> = None;
+
+        while !rest.is_empty() {
+            let end = rest.find(|c: char| !is_numeric(c)).unwrap_or(rest.len());
+            let (number, tail) = rest.split_at(end);
+
+            if number.is_empty() {
+                return Err(ParseQuantityError::InvalidValue);
+            }
+
+            let value = V::from_str_radix(number, 10)
+                .map_err(|_| ParseQuantityError::InvalidValue)?;
+
+            //  A symbol runs until the next number, so that symbols containing
+            //      spaces, such as `fl oz`, are kept whole.
+            let tail = tail.trim_start();
+            let end = tail.find(is_numeric).unwrap_or(tail.len());
+            let (symbol, tail) = tail.split_at(end);
+            let symbol = symbol.trim_end();
+
+            if symbol.is_empty() {
+                return Err(ParseQuantityError::MissingUnit);
+            }
+
+            let unit = lookup(symbol).ok_or(ParseQuantityError::UnknownUnit)?;
+
+            total = Some(match total {
+                Some(total) => {
+                    let prev = convert_exact(total.value, total.unit, unit)
+                        .ok_or(ParseQuantityError::InexactValue)?;
+                    unit.quantity(prev + value)
+                }
+                None => unit.quantity(value),
+            });
+
+            rest = tail.trim_start();
+        }
+
+        match total {
+            Some(total) if negative => {
+                let neg = V::from_i8(-1).ok_or(ParseQuantityError::NegativeValue)?;
+                Ok(Quantity::new(total.unit, total.value * neg))
+            }
+            Some(total) => Ok(total),
+            None => Err(ParseQuantityError::Empty),
+        }
+    }
+}
+
+
+/// Convert a value from one unit to another, with an exact
+///     [`ScaleRatio`](crate::units::ScaleRatio) where both units have one. Returns `None` if the value type has no
+///     fractional part, such as an integer, and the conversion is not exact.
+fn convert_exact<U: Unit, V: Value>(value: V, from: U, to: U) -> Option<V> {
+    let integral = V::from_f64(0.5).is_none_or(|half| half.is_zero());
+
+    match from.scale_factor_ratio(to) {
+        Some(ratio) => {
+            let out = ratio.apply(value.clone())?;
+
+            if integral && ratio.recip().apply(out.clone())? != value {
+                None
+            } else {
+                Some(out)
+            }
+        }
+        None => {
+            let factor = snap(from.scale_factor(to));
+
+            if integral && Real::fract(factor) != 0.0 {
+                None
+            } else {
+                Some(value * crate::_conv_f64(factor))
+            }
+        }
+    }
+}
+
+
+/// Round a conversion factor to the nearest integer if it is within rounding
+///     error of one, so that splitting into units such as feet and inches is
+///     done with exact multiples.
+fn snap(ratio: f64) -> f64 {
+    let rounded = Real::round(ratio);
+
+    if Real::abs(ratio - rounded) <= ratio * 1e-12 {
+        rounded
+    } else {
+        ratio
+    }
+}
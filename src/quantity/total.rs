@@ -0,0 +1,99 @@
+//! Module for total ordering and hashing of quantities.
+
+use core::{cmp::Ordering, hash::{Hash, Hasher}};
+use crate::{units::traits::*, value::TotalValue, Quantity, Value};
+
+
+impl<U: Unit, V: TotalValue> Quantity<U, V> {
+    /// Compare this quantity to another of the same dimension, by converting
+    ///     both to the base unit and comparing the values with
+    ///     [`TotalValue::total_cmp`]. Unlike [`PartialOrd`], this is defined for
+    ///     every pair of quantities, including those with NaN values.
+    ///
+    /// Units with an exact [`ScaleRatio`](crate::units::ScaleRatio) are
+    ///     converted without `f64` scales, so that `12 in` and `1 ft` are equal.
+    pub fn total_cmp<W: Unit<Dim=U::Dim>>(&self, other: &Quantity<W, V>) -> Ordering {
+        let lhs = base_value::<U, U, V>(self);
+        let rhs = base_value::<U, W, V>(other);
+
+        lhs.total_cmp(&rhs)
+    }
+
+    /// Feed this quantity into a hasher, using its value in the base unit. Any
+    ///     two quantities that are equal according to [`Self::total_cmp`] will
+    ///     produce the same hash, regardless of their units.
+    pub fn hash_canonical<H: Hasher>(&self, state: &mut H) {
+        base_value::<U, U, V>(self).hash_canonical(state);
+    }
+
+    /// Wrap this quantity in a [`TotalQty`], which implements [`Eq`], [`Ord`],
+    ///     and [`Hash`] in terms of [`Self::total_cmp`] and
+    ///     [`Self::hash_canonical`].
+    pub fn total(self) -> TotalQty<U, V> { TotalQty(self) }
+}
+
+
+/// Return the value of a quantity in the base unit of `U`. Where both units
+///     have an exact [`ScaleRatio`](crate::units::ScaleRatio), the value is
+///     converted with it rather than through `f64` scales, so that equal
+///     quantities such as `12 in` and `1 ft` have the same base value, instead
+///     of values differing in the last place.
+fn base_value<U: Unit, W: Unit<Dim=U::Dim>, V: Value>(qty: &Quantity<W, V>) -> V {
+    let qty = qty.clone();
+
+    match qty.clone().value_as_ratio(U::base()) {
+        Some(value) => value,
+        None => qty.value_as(U::base()),
+    }
+}
+
+
+/// A [`Quantity`] wrapper with a total order, and a hash consistent with it.
+///
+/// Quantities are compared and hashed by their values in the base unit, so
+///     that `1000 m` and `1 km` are equal and have the same hash. This allows
+///     quantities with float values to be used as the keys of maps and sets.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct TotalQty<U: Unit, V: Value = f64>(pub Quantity<U, V>);
+
+impl<U: Unit, V: Value> TotalQty<U, V> {
+    /// Return the wrapped quantity.
+    pub fn into_inner(self) -> Quantity<U, V> { self.0 }
+}
+
+impl<U: Unit, V: Value> From<Quantity<U, V>> for TotalQty<U, V> {
+    fn from(qty: Quantity<U, V>) -> Self { Self(qty) }
+}
+
+impl<U: Unit, V: TotalValue> PartialEq for TotalQty<U, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0).is_eq()
+    }
+}
+
+impl<U: Unit, V: TotalValue> Eq for TotalQty<U, V> {}
+
+impl<U: Unit, V: TotalValue> PartialOrd for TotalQty<U, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<U: Unit, V: TotalValue> Ord for TotalQty<U, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<U: Unit, V: TotalValue> Hash for TotalQty<U, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_canonical(state);
+    }
+}
+
+impl<U: Unit, V: Value> core::fmt::Display for TotalQty<U, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
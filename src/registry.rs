@@ -0,0 +1,431 @@
+//! Module for looking up and parsing units at runtime.
+//!
+//! Where units are only known at runtime, such as when reading them from a
+//!     configuration file or user input, a [`UnitRegistry`] can resolve unit
+//!     expressions such as `"kWh/day"` into a [`DynUnit`], made up of a
+//!     [`DynDimension`] and a scale. Quantities parsed this way are returned as
+//!     a [`DynQuantity`], which can then be checked against the dimension of a
+//!     [`Quantity`] type.
+//!
+//! This module requires the `alloc` feature.
+
+use alloc::{collections::BTreeMap, string::String};
+use core::ops::{Div, Mul};
+use num_traits::{Inv, real::Real};
+use crate::{
+    dimension::{DynDimension, ExpInt},
+    error::{DimensionError, ParseUnitError},
+    symbols,
+    units::{concrete::{visit_concrete, ConcreteVisitor}, UnitConcrete, UnitNamed},
+    Quantity,
+    Unit,
+    Value,
+};
+
+
+/// Runtime representation of a unit, as its dimension and its scale relative
+///     to the SI base unit of that dimension.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynUnit {
+    /// Dimension of the unit.
+    pub dim: DynDimension,
+    /// Scale of the unit, as in [`Unit::scale`].
+    pub scale: f64,
+}
+
+impl DynUnit {
+    /// The dimensionless unit, with a scale of one.
+    pub const ONE: Self = Self::new(DynDimension::ONE, 1.0);
+
+    /// Construct a new [`DynUnit`] from a dimension and a scale.
+    pub const fn new(dim: DynDimension, scale: f64) -> Self { Self { dim, scale } }
+
+    /// Return the runtime representation of a [`Unit`].
+    pub fn of<U: Unit>(unit: U) -> Self {
+        Self::new(DynDimension::of::<U::Dim>(), unit.scale())
+    }
+
+    /// Raise this unit to an integer power.
+    pub fn pow(self, exp: ExpInt) -> Self {
+        Self::new(self.dim.pow(exp), Real::powi(self.scale, exp))
+    }
+
+    /// Return the factor needed to convert values of this unit to values of
+    ///     another unit.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if the units do not have the same
+    ///     dimension.
+    pub fn scale_factor(self, target: Self) -> Result<f64, DimensionError> {
+        if self.dim == target.dim {
+            Ok(self.scale / target.scale)
+        } else {
+            Err(DimensionError::Mismatch { expected: target.dim, found: self.dim })
+        }
+    }
+}
+
+impl Default for DynUnit {
+    fn default() -> Self { Self::ONE }
+}
+
+impl<U: Unit> From<U> for DynUnit {
+    fn from(unit: U) -> Self { Self::of(unit) }
+}
+
+impl Div for DynUnit {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.dim / rhs.dim, self.scale / rhs.scale)
+    }
+}
+
+impl Mul for DynUnit {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.dim * rhs.dim, self.scale * rhs.scale)
+    }
+}
+
+impl Inv for DynUnit {
+    type Output = Self;
+
+    fn inv(self) -> Self::Output { Self::new(self.dim.inv(), 1.0 / self.scale) }
+}
+
+
+/// A quantity whose unit is only known at runtime.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynQuantity<V: Value = f64> {
+    /// Unit of the quantity.
+    pub unit: DynUnit,
+    /// Value of the quantity, in its unit.
+    pub value: V,
+}
+
+impl<V: Value> DynQuantity<V> {
+    /// Construct a new [`DynQuantity`] from a unit and a value.
+    pub const fn new(unit: DynUnit, value: V) -> Self { Self { unit, value } }
+
+    /// Return the dimension of this quantity.
+    pub const fn dim(&self) -> DynDimension { self.unit.dim }
+}
+
+/// Conversions are only implemented for [`Real`] values, because the scales of
+///     runtime units are `f64`, and would be truncated by integer values.
+impl<V: Value + Real> DynQuantity<V> {
+    /// Return the value of this quantity in the SI base unit of its dimension.
+    pub fn value_base(self) -> V {
+        self.value * crate::_conv_f64(self.unit.scale)
+    }
+
+    /// Convert this quantity to another unit of the same dimension.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if the units do not have the same
+    ///     dimension.
+    pub fn convert_to(self, unit: DynUnit) -> Result<Self, DimensionError> {
+        let factor = self.unit.scale_factor(unit)?;
+        Ok(Self::new(unit, self.value * crate::_conv_f64(factor)))
+    }
+
+    /// Convert this quantity to a [`Quantity`] with a unit of a known type,
+    ///     checking that it has the same dimension.
+    ///
+    /// # Errors
+    /// Returns [`DimensionError::Mismatch`] if the dimension of this quantity
+    ///     is not the dimension of the unit.
+    pub fn to_qty<U: Unit>(self, unit: U) -> Result<Quantity<U, V>, DimensionError> {
+        let factor = self.unit.scale_factor(DynUnit::of(unit))?;
+        Ok(Quantity::new(unit, self.value * crate::_conv_f64(factor)))
+    }
+}
+
+impl<U: Unit, V: Value> From<Quantity<U, V>> for DynQuantity<V> {
+    fn from(qty: Quantity<U, V>) -> Self { Self::new(DynUnit::of(qty.unit), qty.value) }
+}
+
+impl<V: Value> Div for DynQuantity<V> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.unit / rhs.unit, self.value / rhs.value)
+    }
+}
+
+impl<V: Value> Mul for DynQuantity<V> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.unit * rhs.unit, self.value * rhs.value)
+    }
+}
+
+
+/// A table of units, looked up by their symbols and names at runtime.
+///
+/// A registry [constructed with `new`](Self::new) contains every variant of
+///     every [concrete unit](UnitConcrete), under its symbol and the lowercase
+///     name of its variant, as well as every alias in the [`symbols`] modules.
+///     Additional units can be [registered](Self::register) under any name.
+///
+/// Unit expressions may combine units with `*`, `/`, whitespace, parentheses,
+///     and integer powers with `^`. Division applies only to the unit directly
+///     after it, so `m/s/s` is the same as `m/s^2`.
+/// ```
+/// use dana::{prelude::*, registry::*};
+///
+/// let mut units = UnitRegistry::new();
+/// let usage = units.parse::<f64>("12 kWh/day").unwrap();
+/// assert_eq!(usage.to_qty(Power::Watt).unwrap(), Power::Watt.quantity(500.0));
+///
+/// //  Typed quantities are checked against the parsed dimension.
+/// assert!(units.parse_qty::<_, f64>("12 kWh/day", Energy::Joule).is_err());
+///
+/// //  Custom units can be registered with any name.
+/// units.register("pallet", UnitRescale::new(Length::Meter, 1.2));
+/// let stack: Quantity<Length> = units.parse_qty("3 pallet", Length::Meter).unwrap();
+/// assert!((stack.value - 3.6).abs() < 1e-12);
+/// ```
+#[derive(Clone, Debug)]
+pub struct UnitRegistry {
+    units: BTreeMap<String, DynUnit>,
+}
+
+impl UnitRegistry {
+    /// Construct a new registry, containing every built-in unit.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+
+        visit_concrete(&mut RegisterConcrete(&mut registry));
+
+        symbols::register_all(&mut registry);
+        registry
+    }
+
+    /// Construct a new registry with no units.
+    pub const fn empty() -> Self { Self { units: BTreeMap::new() } }
+
+    /// Register a unit under a symbol or name, returning the unit previously
+    ///     registered under it, if any.
+    pub fn register(&mut self, symbol: impl Into<String>, unit: impl Into<DynUnit>) -> Option<DynUnit> {
+        self.units.insert(symbol.into(), unit.into())
+    }
+
+    /// Register a [named unit](UnitNamed) under both its symbol and its name.
+    pub fn register_named<U: Unit>(&mut self, unit: UnitNamed<U>) {
+        self.register(unit.symbol, unit);
+        self.register(unit.name, unit);
+    }
+
+    /// Register a unit under a symbol or name, unless another unit is already
+    ///     registered under it.
+    pub(crate) fn register_missing(&mut self, symbol: &str, unit: impl Into<DynUnit>) {
+        self.units.entry(symbol.into()).or_insert_with(|| unit.into());
+    }
+
+    /// Register every variant of a concrete unit type.
+    fn register_concrete<U: UnitConcrete>(&mut self) {
+        for &unit in U::VARIANTS {
+            self.register_missing(unit.symbol(), unit);
+            self.register_missing(&alloc::format!("{unit:?}").to_lowercase(), unit);
+        }
+    }
+
+    /// Return the unit registered under a symbol or name, if any.
+    pub fn get(&self, symbol: &str) -> Option<DynUnit> {
+        self.units.get(symbol).copied()
+    }
+
+    /// Return `true` if a unit is registered under a symbol or name.
+    pub fn contains(&self, symbol: &str) -> bool {
+        self.units.contains_key(symbol)
+    }
+
+    /// Return an iterator over every symbol and name, and its unit, in order.
+    pub fn iter(&self) -> impl Iterator<Item=(&str, DynUnit)> {
+        self.units.iter().map(|(symbol, &unit)| (symbol.as_str(), unit))
+    }
+
+    /// Resolve a unit expression, such as `"kg*m/s^2"`.
+    ///
+    /// # Errors
+    /// Returns a [`ParseUnitError`] if the expression is not well-formed, or
+    ///     uses a unit that is not registered.
+    pub fn parse_unit(&self, expr: &str) -> Result<DynUnit, ParseUnitError> {
+        let mut parser = Parser { registry: self, rest: expr.trim() };
+
+        if parser.rest.is_empty() {
+            return Err(ParseUnitError::Empty);
+        }
+
+        let unit = parser.expr()?;
+
+        if parser.rest.is_empty() {
+            Ok(unit)
+        } else {
+            Err(ParseUnitError::InvalidSyntax)
+        }
+    }
+
+    /// Parse a quantity made up of a number and a unit expression, such as
+    ///     `"12 kWh/day"`. The number may have a sign and an exponent, as in
+    ///     `"-1.5e3 m"`. Whitespace between the number and the expression is
+    ///     optional.
+    ///
+    /// # Errors
+    /// Returns [`ParseUnitError::InvalidValue`] if the string does not begin
+    ///     with a valid number, or another [`ParseUnitError`] if the unit
+    ///     expression cannot be [resolved](Self::parse_unit).
+    pub fn parse<V: Value>(&self, s: &str) -> Result<DynQuantity<V>, ParseUnitError> {
+        let (number, expr) = split_number(s.trim());
+
+        let value = V::from_str_radix(number, 10)
+            .map_err(|_| ParseUnitError::InvalidValue)?;
+
+        Ok(DynQuantity::new(self.parse_unit(expr)?, value))
+    }
+
+    /// Parse a quantity as with [`parse`](Self::parse), and convert it to a
+    ///     [`Quantity`] with a unit of a known type.
+    ///
+    /// # Errors
+    /// Returns [`ParseUnitError::Mismatch`] if the parsed quantity does not
+    ///     have the dimension of the unit, or another [`ParseUnitError`] if it
+    ///     cannot be parsed.
+    pub fn parse_qty<U: Unit, V: Value + Real>(&self, s: &str, unit: U) -> Result<Quantity<U, V>, ParseUnitError> {
+        Ok(self.parse(s)?.to_qty(unit)?)
+    }
+}
+
+impl Default for UnitRegistry {
+    fn default() -> Self { Self::new() }
+}
+
+
+/// Visitor to register every variant of every concrete unit type.
+struct RegisterConcrete<'a>(&'a mut UnitRegistry);
+
+impl ConcreteVisitor for RegisterConcrete<'_> {
+    type Output = ();
+
+    fn visit<U: UnitConcrete>(&mut self) -> Option<()> {
+        self.0.register_concrete::<U>();
+        None
+    }
+}
+
+
+/// Split a string after the number at its start, including any sign,
+///     fractional part, and exponent. An `e` is only taken as an exponent if
+///     digits follow it, so that it may begin a unit symbol instead.
+fn split_number(s: &str) -> (&str, &str) {
+    let digits = |s: &str, dot: bool| s.find(|c: char| !(c.is_ascii_digit() || (dot && c == '.')))
+        .unwrap_or(s.len());
+
+    let sign = usize::from(s.starts_with(['+', '-']));
+    let mut end = sign + digits(&s[sign..], true);
+
+    if let Some(exp) = s[end..].strip_prefix(['e', 'E']) {
+        let sign = usize::from(exp.starts_with(['+', '-']));
+        let len = digits(&exp[sign..], false);
+
+        if len > 0 {
+            end += 1 + sign + len;
+        }
+    }
+
+    s.split_at(end)
+}
+
+
+/// Recursive descent parser for unit expressions.
+struct Parser<'r, 's> {
+    registry: &'r UnitRegistry,
+    rest: &'s str,
+}
+
+impl Parser<'_, '_> {
+    /// Return `true` if a character cannot be part of a unit symbol.
+    fn is_special(c: char) -> bool {
+        c.is_whitespace() || matches!(c, '*' | '·' | '/' | '^' | '(' | ')')
+    }
+
+    /// Return the length of the longest registered symbol that contains
+    ///     whitespace, such as `fl oz`, and begins the remaining input, if any.
+    fn spaced_symbol(&self) -> Option<usize> {
+        self.registry.units.keys()
+            .filter(|symbol| symbol.contains(char::is_whitespace))
+            .filter(|symbol| self.rest.strip_prefix(symbol.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(Self::is_special)))
+            .map(String::len)
+            .max()
+    }
+
+    /// Consume a character if it is next, ignoring leading whitespace.
+    fn eat(&mut self, c: char) -> bool {
+        match self.rest.trim_start().strip_prefix(c) {
+            Some(rest) => { self.rest = rest; true }
+            None => false,
+        }
+    }
+
+    /// Parse a product or quotient of factors.
+    fn expr(&mut self) -> Result<DynUnit, ParseUnitError> {
+        let mut unit = self.factor()?;
+
+        loop {
+            if self.eat('/') {
+                unit = unit / self.factor()?;
+            } else if self.eat('*') || self.eat('·') {
+                unit = unit * self.factor()?;
+            } else if self.rest.starts_with(char::is_whitespace)
+                && !self.rest.trim_start().starts_with([')', '^'])
+                && !self.rest.trim_start().is_empty()
+            {
+                //  Whitespace between two factors is an implicit product.
+                unit = unit * self.factor()?;
+            } else {
+                return Ok(unit);
+            }
+        }
+    }
+
+    /// Parse a unit, or a parenthesized expression, with an optional power.
+    fn factor(&mut self) -> Result<DynUnit, ParseUnitError> {
+        let unit = if self.eat('(') {
+            let unit = self.expr()?;
+            if !self.eat(')') { return Err(ParseUnitError::InvalidSyntax); }
+            unit
+        } else {
+            self.rest = self.rest.trim_start();
+            let end = self.spaced_symbol()
+                .or_else(|| self.rest.find(Self::is_special))
+                .unwrap_or(self.rest.len());
+            let (symbol, rest) = self.rest.split_at(end);
+
+            if symbol.is_empty() {
+                return Err(ParseUnitError::InvalidSyntax);
+            }
+
+            self.rest = rest;
+            self.registry.get(symbol).ok_or(ParseUnitError::UnknownUnit)?
+        };
+
+        if self.eat('^') {
+            let rest = self.rest.trim_start();
+            let end = rest.char_indices()
+                .find(|&(i, c)| !(c.is_ascii_digit() || (i == 0 && c == '-')))
+                .map_or(rest.len(), |(i, _)| i);
+            let (exp, rest) = rest.split_at(end);
+
+            let exp = exp.parse().map_err(|_| ParseUnitError::InvalidExponent)?;
+            self.rest = rest;
+            Ok(unit.pow(exp))
+        } else {
+            Ok(unit)
+        }
+    }
+}
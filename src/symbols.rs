@@ -16,11 +16,11 @@
 //!
 //! Contains two broad classes of submodules:
 //! - **Symbol Modules** define type aliases and unit constants. Inside each
-//!     Symbol Module, type aliases are defined in `types`, and unit constants
-//!     are defined in `units`. The Symbol Module itself also re-exports
-//!     everything from both.
+//!   Symbol Module, type aliases are defined in `types`, and unit constants
+//!   are defined in `units`. The Symbol Module itself also re-exports
+//!   everything from both.
 //! - **Group Modules** re-export the contents of multiple Symbol Modules that
-//!     are often used together.
+//!   are often used together.
 //!
 //! There are also two unique submodules:
 //! - [`dimensions`] defines type aliases for [`Dimension`]s.
@@ -74,25 +74,36 @@ pub mod dimensions {
     //endregion
 
     //region Derived dimensions.
-    //  TODO: How many of these should be provided (if any at all)?
-    /*pub type _f = Frequency;
+    /// [`Dimension`] alias for [`Frequency`].
+    pub type _f = Frequency;
+    /// [`Dimension`] alias for [`Velocity`].
     pub type _v = Velocity;
+    /// [`Dimension`] alias for [`Accel`].
     pub type _a = Accel;
+    /// [`Dimension`] alias for [`Force`].
     pub type _F = Force;
     // pub type _p = Pressure;
     // pub type _A = Area;
-    // pub type _V = Volume;
+    /// [`Dimension`] alias for [`Density`].
     pub type _D = Density;
+    /// [`Dimension`] alias for [`Density`].
     pub type _ρ = Density;
 
+    /// [`Dimension`] alias for [`Charge`].
     pub type _Q = Charge;
+    /// [`Dimension`] alias for [`Torque`].
     pub type _τ = Torque;
+    /// [`Dimension`] alias for [`Energy`].
     pub type _E = Energy;
+    /// [`Dimension`] alias for [`Power`].
     pub type _P = Power;
+    /// [`Dimension`] alias for [`Voltage`].
     pub type _V = Voltage;
     // pub type _U = Voltage;
+    /// [`Dimension`] alias for [`Resistance`].
     pub type _R = Resistance;
-    pub type _C = Capacitance;*/
+    /// [`Dimension`] alias for [`Capacitance`].
+    pub type _C = Capacitance;
     //endregion
 }
 
@@ -129,8 +140,9 @@ define_groups! {
 
     /// Group module for units related to electricity.
     pub mod electrical(
-        [P],   [Q],    [I],     [U,V],   [R],
-        power, charge, current, voltage, resistance,
+        [P],   [Q],    [I],     [U,V],   [R],        [C],
+        power, charge, current, voltage, resistance, capacitance,
+        inductance,
     );
 
     /// Group module for the ISQ base quantities.
@@ -141,10 +153,26 @@ define_groups! {
 
     /// Group module for the [US Customary] system.
     ///
+    /// Quantities can be converted into this system with
+    ///     [`Quantity::into_system`](crate::Quantity::into_system), using
+    ///     [`UsCustomary`](crate::units::system::UsCustomary).
+    ///
     /// [US Customary]: https://en.wikipedia.org/wiki/United_States_customary_units
     pub mod us_customary(
         [L],       [M],     /*[V],*/
-        length_us, mass_us, volume_us,
+        length_us, mass_us, volume_us, volume_us_dry,
+    );
+
+    /// Group module for the British [Imperial] system.
+    ///
+    /// Quantities can be converted into this system with
+    ///     [`Quantity::into_system`](crate::Quantity::into_system), using
+    ///     [`Imperial`](crate::units::system::Imperial).
+    ///
+    /// [Imperial]: https://en.wikipedia.org/wiki/Imperial_units
+    pub mod imperial(
+        [L],       [M],      /*[V],*/
+        length_us, mass_imp, volume_imp,
     );
 }
 
@@ -214,13 +242,38 @@ define_symbols! {
         const GT = GigaTon;
     }
 
-    /// Symbol module for [`Mass`] units in the US Customary system.
-    pub mod mass_us for type Mass in mod mass_si {
+    /// Symbol module for avoirdupois [`Mass`] units, shared by the US Customary
+    ///     and Imperial systems.
+    pub mod mass_avdp for type Mass in mod mass_si {
         const gr = Grain;
         const oz = Ounce;
         const lb = Pound;
     }
 
+    /// Symbol module for [`Mass`] units in the US Customary system.
+    pub mod mass_us for type Mass in mod mass_si {
+        use mass_avdp;
+
+        /// Unit alias for the [short hundredweight](Mass::ShortHundredweight)
+        ///     of 100 pounds.
+        const sh_cwt = ShortHundredweight;
+        /// Unit alias for the [short ton](Mass::ShortTon) of 2,000 pounds.
+        const sh_tn = ShortTon;
+    }
+
+    /// Symbol module for [`Mass`] units in the Imperial system.
+    pub mod mass_imp for type Mass in mod mass_si {
+        use mass_avdp;
+
+        const st = Stone;
+
+        /// Unit alias for the [long hundredweight](Mass::LongHundredweight) of
+        ///     112 pounds.
+        const long_cwt = LongHundredweight;
+        /// Unit alias for the [long ton](Mass::LongTon) of 2,240 pounds.
+        const long_tn = LongTon;
+    }
+
     /// Symbol module for [`Time`].
     pub mod time for type Time as T {
         const ps   = PicoSecond;
@@ -328,6 +381,22 @@ define_symbols! {
         const gal   = Gallon;
     }
 
+    /// Symbol module for [`Volume`] units of dry measure in the US Customary
+    ///     system.
+    pub mod volume_us_dry for type Volume in mod volume_si {
+        const dry_pt = DryPint;
+        const dry_qt = DryQuart;
+        const pk     = Peck;
+        const bu     = Bushel;
+    }
+
+    /// Symbol module for [`Volume`] units in the Imperial system.
+    pub mod volume_imp for type Volume in mod volume_si {
+        const imp_fl_oz = ImpFlOunce;
+        const imp_pt    = ImpPint;
+        const imp_gal   = ImpGallon;
+    }
+
     /// Symbol module for [`Pressure`].
     pub mod pressure for type Pressure {
         const μPa = MicroPascal;
@@ -442,4 +511,23 @@ define_symbols! {
         const GO = GigaOhm;
         const TO = TeraOhm;
     }
+
+    /// Symbol module for [`Capacitance`].
+    pub mod capacitance for type Capacitance as C {
+        const pF = PicoFarad;
+        const nF = NanoFarad;
+        const μF = MicroFarad;
+        const uF = MicroFarad;
+        const mF = MilliFarad;
+        const  F = Farad;
+    }
+
+    /// Symbol module for [`Inductance`].
+    pub mod inductance for type Inductance {
+        const nH = NanoHenry;
+        const μH = MicroHenry;
+        const uH = MicroHenry;
+        const mH = MilliHenry;
+        const  H = Henry;
+    }
 }
@@ -88,6 +88,16 @@ macro_rules! define_symbols {
         pub mod types {
             $($(pub use super::$module::types::{$($alias_type),+};)?)*
         }
+
+        /// Register every unit alias constant in a registry, under the name of
+        ///     the constant.
+        #[cfg(feature = "alloc")]
+        pub(crate) fn register_all(registry: &mut crate::registry::UnitRegistry) {
+            $($(registry.register_missing(
+                stringify!($alias_const),
+                self::$module::units::$alias_const,
+            );)*)*
+        }
     };
 
     //region Internal.
@@ -4,15 +4,20 @@
 pub mod compound;
 pub mod concrete;
 // pub mod si;
+pub mod scale_ratio;
+pub mod system;
 pub mod traits;
 pub mod unit_anon;
+pub mod unit_named;
 pub mod unit_rescale;
 
 pub use compound::*;
 pub use concrete::*;
 pub use derived::*;
+pub use scale_ratio::ScaleRatio;
 pub use traits::{Unit, UnitCompound, UnitConcrete};
 pub use unit_anon::UnitAnon;
+pub use unit_named::UnitNamed;
 pub use unit_rescale::UnitRescale;
 
 
@@ -92,6 +97,7 @@ pub mod types {
         concrete::*,
         derived::*,
         unit_anon::UnitAnon,
+        unit_named::UnitNamed,
         unit_rescale::UnitRescale,
     };
 }
@@ -31,7 +31,7 @@ pub use unit_pow::*;
 mod impl_ops {
     use core::ops::{Div, Mul};
     use num_traits::Inv;
-    use typenum::Integer;
+    use typenum::{Integer, NonZero, P1};
     use crate::{dimension::*, units::compound::*};
 
     //region `Div`/`Mul` impls.
@@ -63,7 +63,7 @@ mod impl_ops {
         PerUnit<U: Unit>,
         UnitDiv<A: Unit, B: Unit>,
         UnitMul<A: Unit, B: Unit>,
-        UnitPow<U: Unit, E: Integer>,
+        UnitPow<U: Unit, E: Integer, Q: Den>,
     );
     //endregion
 
@@ -87,7 +87,7 @@ mod impl_ops {
         PerUnit<U: Unit>,
         // UnitDiv<A: Unit, B: Unit>,
         UnitMul<A: Unit, B: Unit>,
-        UnitPow<U: Unit, E: Integer>,
+        UnitPow<U: Unit, E: Integer, Q: Den>,
     );
 
     impl<A: Unit, B: Unit> Inv for UnitDiv<A, B> {
@@ -108,7 +108,7 @@ mod impl_ops {
             CanPow<__E> for $name$(<$($param),*>)? where
                 Exponent<__E>: HasTypenum,
                 Self: Unit,
-                Self::Dim: CanDimPowType<<Exponent<__E> as HasTypenum>::Typenum>,
+                Self::Dim: CanDimPowRatio<<Exponent<__E> as HasTypenum>::Typenum, typenum::P1>,
             {
                 type Output = UnitPowN<Self, __E>;
 
@@ -126,4 +126,33 @@ mod impl_ops {
         // UnitPow<U: Unit, E: Integer>,
     );
     //endregion
+
+    //region `CanRoot` impls.
+    macro_rules! impl_root {
+        ($($name:ident $(<$(
+        $param:ident $(: $bound:ident)?
+        ),*>)?),* $(,)?) => {
+            $(impl<$($($param $(: $bound)?,)*)? const __D: i32>
+            CanRoot<__D> for $name$(<$($param),*>)? where
+                Exponent<__D>: HasTypenum,
+                <Exponent<__D> as HasTypenum>::Typenum: NonZero,
+                P1: ReduceExp<<Exponent<__D> as HasTypenum>::Typenum>,
+                Self: Unit,
+                UnitRootN<Self, __D>: Unit,
+            {
+                type Output = UnitRootN<Self, __D>;
+
+                fn root(self) -> Self::Output {
+                    Self::Output::new(self)
+                }
+            })*
+        };
+    }
+
+    impl_root!(
+        PerUnit<U: Unit>,
+        UnitDiv<A: Unit, B: Unit>,
+        UnitMul<A: Unit, B: Unit>,
+    );
+    //endregion
 }
@@ -1,6 +1,6 @@
 //! Module for the reciprocal unit type.
 
-use crate::{dimension::*, units::traits::*};
+use crate::{dimension::*, units::{traits::*, ScaleRatio}};
 
 
 /// The reciprocal of a unit.
@@ -28,6 +28,10 @@ impl<U: Unit> Unit for PerUnit<U> where
     fn scale(&self) -> f64 {
         1.0 / self.0.scale()
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(self.0.scale_ratio()?.recip())
+    }
 }
 
 impl<U: Unit> UnitCompound for PerUnit<U> where Self: Unit {}
@@ -47,6 +51,19 @@ impl<U: Unit> core::fmt::Display for PerUnit<U> where Self: Unit {
 }
 
 
+impl<U: UnitMixed> UnitMixed for PerUnit<U> where Self: Unit {
+    fn to_si(&self) -> Self {
+        Self::new(self.0.to_si())
+    }
+}
+
+impl<S: UnitSystem, U: InSystem<S>> InSystem<S> for PerUnit<U> where Self: Unit {
+    fn to_system(&self) -> Self {
+        Self::new(self.0.to_system())
+    }
+}
+
+
 impl<U: UnitStep> UnitStep for PerUnit<U> where Self: Unit {
     fn step_down(&self) -> Option<Self> {
         Some(Self(self.0.step_up()?))
@@ -55,4 +72,8 @@ impl<U: UnitStep> UnitStep for PerUnit<U> where Self: Unit {
     fn step_up(&self) -> Option<Self> {
         Some(Self(self.0.step_down()?))
     }
+
+    fn step_to_floor(&self, scale: f64) -> Self {
+        Self(self.0.step_to_ceil(scale.recip()))
+    }
 }
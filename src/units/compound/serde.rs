@@ -7,7 +7,7 @@ use serde::{
 };
 use typenum::Integer;
 use crate::{
-    dimension::{CanDimDiv, CanDimInv, CanDimMul, CanDimPowType, DimType},
+    dimension::{CanDimDiv, CanDimInv, CanDimMul, CanDimPowRatio, Den, DimType},
     prelude::*,
 };
 
@@ -110,38 +110,52 @@ impl<'de, U: Unit> Deserialize<'de> for PerUnit<U> where
 struct SerdePow<U: Unit> {
     base: U,
     exp: i32,
+    #[serde(default = "den_one", skip_serializing_if = "is_one")]
+    den: i32,
 }
 
-impl<U: Unit, E: Integer> Serialize for UnitPow<U, E> where
-    U::Dim: CanDimPowType<E>,
+const fn den_one() -> i32 { 1 }
+fn is_one(den: &i32) -> bool { *den == 1 }
+
+impl<U: Unit, E: Integer, Q: Den> Serialize for UnitPow<U, E, Q> where
+    U::Dim: CanDimPowRatio<E, Q>,
     U: Serialize,
 {
     fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
-        SerdePow { base: self.0, exp: E::I32 }.serialize(s)
+        SerdePow { base: self.0, exp: E::I32, den: Q::I32 }.serialize(s)
     }
 }
 
-struct ExpectedInt(i32);
+struct ExpectedExp(i32, i32);
 
-impl serde::de::Expected for ExpectedInt {
+impl serde::de::Expected for ExpectedExp {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(f, "integer `{}`", self.0)
+        if self.1 == 1 {
+            write!(f, "integer `{}`", self.0)
+        } else {
+            write!(f, "exponent `{}/{}`", self.0, self.1)
+        }
     }
 }
 
-impl<'de, U: Unit, E: Integer> Deserialize<'de> for UnitPow<U, E> where
-    U::Dim: CanDimPowType<E>,
+impl<'de, U: Unit, E: Integer, Q: Den> Deserialize<'de> for UnitPow<U, E, Q> where
+    U::Dim: CanDimPowRatio<E, Q>,
     U: DeserializeOwned,
 {
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
-        let SerdePow { base, exp } = Deserialize::deserialize(d)?;
+        let SerdePow { base, exp, den } = Deserialize::deserialize(d)?;
 
-        if exp == E::I32 {
+        if exp == E::I32 && den == Q::I32 {
             Ok(Self::new(base))
-        } else {
+        } else if den == 1 {
             Err(serde::de::Error::invalid_value(
                 Unexpected::Signed(exp as _),
-                &ExpectedInt(E::I32),
+                &ExpectedExp(E::I32, Q::I32),
+            ))
+        } else {
+            Err(serde::de::Error::invalid_value(
+                Unexpected::Other("fractional exponent"),
+                &ExpectedExp(E::I32, Q::I32),
             ))
         }
     }
@@ -1,6 +1,6 @@
 //! Module for the divided unit type.
 
-use crate::{dimension::*, units::traits::*};
+use crate::{dimension::*, units::{traits::*, ScaleRatio}};
 
 
 /// One unit divided by another; For example, Meters per Second.
@@ -29,6 +29,10 @@ impl<A: Unit, B: Unit> Unit for UnitDiv<A, B> where
     fn scale(&self) -> f64 {
         self.0.scale() / self.1.scale()
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        self.0.scale_ratio()?.checked_div(self.1.scale_ratio()?)
+    }
 }
 
 impl<A: Unit, B: Unit> core::fmt::Display for UnitDiv<A, B> where Self: Unit {
@@ -55,6 +59,19 @@ impl<A: Unit, B: Unit> UnitBinary for UnitDiv<A, B> where Self: Unit {
 }
 
 
+impl<A: UnitMixed, B: UnitMixed> UnitMixed for UnitDiv<A, B> where Self: Unit {
+    fn to_si(&self) -> Self {
+        Self::new(self.0.to_si(), self.1.to_si())
+    }
+}
+
+impl<S: UnitSystem, A: InSystem<S>, B: InSystem<S>> InSystem<S> for UnitDiv<A, B> where Self: Unit {
+    fn to_system(&self) -> Self {
+        Self::new(self.0.to_system(), self.1.to_system())
+    }
+}
+
+
 impl<A: UnitStep, B: UnitStep> UnitStep for UnitDiv<A, B> where Self: Unit {
     fn step_down(&self) -> Option<Self> {
         match (self.step_lhs_down(), self.step_rhs_up()) {
@@ -79,4 +96,17 @@ impl<A: UnitStep, B: UnitStep> UnitStep for UnitDiv<A, B> where Self: Unit {
             (None, rhs) => rhs,
         }
     }
+
+    fn step_to_floor(&self, scale: f64) -> Self {
+        //  Fit the numerator first, keeping the denominator. If the numerator
+        //      runs out of room in either direction, fit the denominator too.
+        let lhs = self.0.step_to_floor(scale * self.1.scale());
+        let unit = Self::new(lhs, self.1);
+
+        if unit.scale() > scale || lhs.step_up().is_none() {
+            Self::new(lhs, self.1.step_to_ceil(lhs.scale() / scale))
+        } else {
+            unit
+        }
+    }
 }
@@ -1,6 +1,6 @@
 //! Module for the multiplied unit type.
 
-use crate::{dimension::*, units::traits::*};
+use crate::{dimension::*, units::{traits::*, ScaleRatio}};
 
 
 /// Two units multiplied; For example, Newton-Meters.
@@ -21,6 +21,10 @@ impl<A: Unit, B: Unit> Unit for UnitMul<A, B> where
     fn scale(&self) -> f64 {
         self.0.scale() * self.1.scale()
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        self.0.scale_ratio()?.checked_mul(self.1.scale_ratio()?)
+    }
 }
 
 impl<A: Unit, B: Unit> core::fmt::Display for UnitMul<A, B> where Self: Unit {
@@ -47,6 +51,19 @@ impl<A: Unit, B: Unit> UnitBinary for UnitMul<A, B> where Self: Unit {
 }
 
 
+impl<A: UnitMixed, B: UnitMixed> UnitMixed for UnitMul<A, B> where Self: Unit {
+    fn to_si(&self) -> Self {
+        Self::new(self.0.to_si(), self.1.to_si())
+    }
+}
+
+impl<S: UnitSystem, A: InSystem<S>, B: InSystem<S>> InSystem<S> for UnitMul<A, B> where Self: Unit {
+    fn to_system(&self) -> Self {
+        Self::new(self.0.to_system(), self.1.to_system())
+    }
+}
+
+
 impl<A: UnitStep, B: UnitStep> UnitStep for UnitMul<A, B> where Self: Unit {
     fn step_down(&self) -> Option<Self> {
         match (self.step_lhs_down(), self.step_rhs_down()) {
@@ -71,4 +88,17 @@ impl<A: UnitStep, B: UnitStep> UnitStep for UnitMul<A, B> where Self: Unit {
             (None, rhs) => rhs,
         }
     }
+
+    fn step_to_floor(&self, scale: f64) -> Self {
+        //  Fit the left side first, keeping the right side. If the left side
+        //      runs out of room in either direction, fit the right side too.
+        let lhs = self.0.step_to_floor(scale / self.1.scale());
+        let unit = Self::new(lhs, self.1);
+
+        if unit.scale() > scale || lhs.step_up().is_none() {
+            Self::new(lhs, self.1.step_to_floor(scale / lhs.scale()))
+        } else {
+            unit
+        }
+    }
 }
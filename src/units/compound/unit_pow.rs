@@ -1,8 +1,9 @@
 //! Module for the exponentiated unit type.
 
-use core::{cmp::Ordering, fmt::{Debug, Display}, marker::PhantomData, ops::Mul};
-use typenum::{Integer, PartialDiv};
-use crate::{dimension::*, units::traits::*};
+use core::{cmp::Ordering, fmt::{Debug, Display}, hash::{Hash, Hasher}, marker::PhantomData, ops::{Div, Mul}};
+use num_traits::real::Real;
+use typenum::{Abs, AbsVal, Gcd, Gcf, Integer, NonZero, P1, Prod, Quot};
+use crate::{dimension::*, units::{traits::*, ScaleRatio}};
 
 
 /// Type alias allowing specification of a [`UnitPow`] by integer parameter.
@@ -14,51 +15,133 @@ pub type UnitSquared<U> = UnitPowN<U, 2>;
 /// Type alias for a unit to the third power.
 pub type UnitCubed<U> = UnitPowN<U, 3>;
 
+/// Type alias allowing specification of a [`UnitPow`] with a fractional
+///     exponent by integer parameters.
+pub type UnitPowRatio<U, const E: i32, const Q: i32> = UnitPow<
+    U,
+    <<Exponent<E> as HasTypenum>::Typenum as ReduceExp<<Exponent<Q> as HasTypenum>::Typenum>>::Numer,
+    <<Exponent<E> as HasTypenum>::Typenum as ReduceExp<<Exponent<Q> as HasTypenum>::Typenum>>::Denom,
+>;
+
+/// Type alias allowing specification of a unit taken to a root by integer
+///     parameter.
+pub type UnitRootN<U, const D: i32> = UnitPowRatio<U, 1, D>;
+
+/// Type alias for the second root of a unit.
+pub type UnitSquareRoot<U> = UnitRootN<U, 2>;
+
+/// Type alias for the third root of a unit.
+pub type UnitCubeRoot<U> = UnitRootN<U, 3>;
+
+
+/// Trait for reducing a fractional exponent, `Self / Q`, to lowest terms, with
+///     a positive denominator.
+pub trait ReduceExp<Q: Integer + NonZero> {
+    /// The numerator of the reduced exponent.
+    type Numer: Integer;
+    /// The denominator of the reduced exponent.
+    type Denom: Den;
+}
+
+impl<E: Integer, Q: Integer + NonZero> ReduceExp<Q> for E where
+    E: Gcd<Q>,
+    Q: Abs + Div<AbsVal<Q>>,
+    Gcf<E, Q>: Mul<Quot<Q, AbsVal<Q>>>,
+    E: Div<Prod<Gcf<E, Q>, Quot<Q, AbsVal<Q>>>>,
+    Q: Div<Prod<Gcf<E, Q>, Quot<Q, AbsVal<Q>>>>,
+    Quot<E, Prod<Gcf<E, Q>, Quot<Q, AbsVal<Q>>>>: Integer,
+    Quot<Q, Prod<Gcf<E, Q>, Quot<Q, AbsVal<Q>>>>: Den,
+{
+    type Numer = Quot<E, Prod<Gcf<E, Q>, Quot<Q, AbsVal<Q>>>>;
+    type Denom = Quot<Q, Prod<Gcf<E, Q>, Quot<Q, AbsVal<Q>>>>;
+}
+
 
 /// A unit raised to an arbitrary power.
-#[derive(Clone, Copy, Default, Hash)]
+///
+/// The power is the fraction `E / Q`, where `Q` is positive, and the fraction
+///     is in lowest terms. For an integer power, `Q` is [`P1`].
+#[derive(Clone, Copy, Default)]
 #[repr(transparent)]
 //  TODO: Switch `E` to `i32` const param.
-pub struct UnitPow<U: Unit, E: Integer>(pub U, pub PhantomData<E>);
+pub struct UnitPow<U: Unit, E: Integer, Q: Den = P1>(pub U, pub PhantomData<(E, Q)>);
 
-impl<U: Unit, E: Integer> UnitPow<U, E> {
+impl<U: Unit, E: Integer, Q: Den> UnitPow<U, E, Q> {
     /// Construct a new [`UnitPow`] around the input.
     pub const fn new(unit: U) -> Self { Self(unit, PhantomData) }
+
+    /// Return the power as a float.
+    fn exp_f64() -> f64 {
+        f64::from(E::I32) / f64::from(Q::I32)
+    }
 }
 
-impl<U: Unit, E: Integer> Unit for UnitPow<U, E> where
-    U::Dim: CanDimPowType<E>,
+impl<U: Unit, E: Integer, Q: Den> Unit for UnitPow<U, E, Q> where
+    U::Dim: CanDimPowRatio<E, Q>,
 {
-    type Dim = <U::Dim as CanDimPowType<E>>::Output;
+    type Dim = <U::Dim as CanDimPowRatio<E, Q>>::Output;
     // type ScaleType = f64;
 
     fn scale(&self) -> f64 {
-        num_traits::Pow::pow(self.0.scale(), E::I32)
+        if Q::I32 == 1 {
+            num_traits::Pow::pow(self.0.scale(), E::I32)
+        } else {
+            Real::powf(self.0.scale(), Self::exp_f64())
+        }
+    }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        if Q::I32 == 1 {
+            self.0.scale_ratio()?.checked_pow(E::I32)
+        } else {
+            None
+        }
     }
 }
 
-impl<U: Unit, E: Integer> UnitCompound for UnitPow<U, E> where Self: Unit {}
+impl<U: Unit, E: Integer, Q: Den> UnitCompound for UnitPow<U, E, Q> where Self: Unit {}
 
-impl<U: Unit, E: Integer> UnitUnary for UnitPow<U, E> where Self: Unit {
+impl<U: Unit, E: Integer, Q: Den> UnitUnary for UnitPow<U, E, Q> where Self: Unit {
     type Inner = U;
     fn unary(inner: Self::Inner) -> Self { Self::new(inner) }
     fn inner(&self) -> Self::Inner { self.0 }
 }
 
-impl<U: Unit + Debug, E: Integer> Debug for UnitPow<U, E> where Self: Unit {
+impl<U: Unit + Debug, E: Integer, Q: Den> Debug for UnitPow<U, E, Q> where Self: Unit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "UnitPow({:?}, {:?})", self.0, E::I32)
+        if Q::I32 == 1 {
+            write!(f, "UnitPow({:?}, {:?})", self.0, E::I32)
+        } else {
+            write!(f, "UnitPow({:?}, {:?}/{:?})", self.0, E::I32, Q::I32)
+        }
     }
 }
 
-impl<U: Unit, E: Integer> Display for UnitPow<U, E> where Self: Unit {
+impl<U: Unit, E: Integer, Q: Den> Display for UnitPow<U, E, Q> where Self: Unit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:#}^{}", self.0, E::I32)
+        if Q::I32 == 1 {
+            write!(f, "{:#}^{}", self.0, E::I32)
+        } else {
+            write!(f, "{:#}^({}/{})", self.0, E::I32, Q::I32)
+        }
     }
 }
 
 
-impl<U: UnitStep, E: Integer> UnitStep for UnitPow<U, E> where Self: Unit {
+impl<U: UnitMixed, E: Integer, Q: Den> UnitMixed for UnitPow<U, E, Q> where Self: Unit {
+    fn to_si(&self) -> Self {
+        Self::new(self.0.to_si())
+    }
+}
+
+impl<S: UnitSystem, U: InSystem<S>, E: Integer, Q: Den> InSystem<S> for UnitPow<U, E, Q> where Self: Unit {
+    fn to_system(&self) -> Self {
+        Self::new(self.0.to_system())
+    }
+}
+
+
+impl<U: UnitStep, E: Integer, Q: Den> UnitStep for UnitPow<U, E, Q> where Self: Unit {
     fn step_down(&self) -> Option<Self> {
         Some(Self::new(self.0.step_down()?))
     }
@@ -66,19 +149,35 @@ impl<U: UnitStep, E: Integer> UnitStep for UnitPow<U, E> where Self: Unit {
     fn step_up(&self) -> Option<Self> {
         Some(Self::new(self.0.step_up()?))
     }
+
+    fn step_to_floor(&self, scale: f64) -> Self {
+        let root = Real::powf(scale, Self::exp_f64().recip());
+
+        match E::I32.cmp(&0) {
+            Ordering::Greater => Self::new(self.0.step_to_floor(root)),
+            Ordering::Less => Self::new(self.0.step_to_ceil(root)),
+            Ordering::Equal => *self,
+        }
+    }
 }
 
 
 //region Non-derivable comparison traits.
-impl<U: Unit, E: Integer> PartialEq for UnitPow<U, E> where Self: Unit {
+impl<U: Unit, E: Integer, Q: Den> PartialEq for UnitPow<U, E, Q> where Self: Unit {
     fn eq(&self, other: &Self) -> bool {
         self.scale().eq(&other.scale())
     }
 }
 
-impl<U: Unit, E: Integer> Eq for UnitPow<U, E> where Self: Unit {}
+impl<U: Unit, E: Integer, Q: Den> Eq for UnitPow<U, E, Q> where Self: Unit {}
+
+impl<U: Unit + Hash, E: Integer, Q: Den> Hash for UnitPow<U, E, Q> where Self: Unit {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
 
-impl<U: Unit, E: Integer> PartialOrd for UnitPow<U, E> where Self: Unit {
+impl<U: Unit, E: Integer, Q: Den> PartialOrd for UnitPow<U, E, Q> where Self: Unit {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.scale().partial_cmp(&other.scale())
     }
@@ -93,25 +192,42 @@ impl<U: Unit, E: Integer> PartialOrd for UnitPow<U, E> where Self: Unit {
 
 
 //region Exponential traits.
-impl<U: Unit, E1: Integer, const E2: i32> CanPow<E2> for UnitPow<U, E1> where
+impl<U: Unit, E1: Integer, Q: Den, const E2: i32> CanPow<E2> for UnitPow<U, E1, Q> where
     Exponent<E2>: HasTypenum,
     E1: Mul<<Exponent<E2> as HasTypenum>::Typenum>,
-    <E1 as Mul<<Exponent<E2> as HasTypenum>::Typenum>>::Output: Integer,
-    UnitPow<U, E1>: Unit,
-    UnitPow<U, E1::Output>: Unit,
+    Prod<E1, <Exponent<E2> as HasTypenum>::Typenum>: ReduceExp<Q>,
+    UnitPow<U, E1, Q>: Unit,
+    UnitPow<
+        U,
+        <Prod<E1, <Exponent<E2> as HasTypenum>::Typenum> as ReduceExp<Q>>::Numer,
+        <Prod<E1, <Exponent<E2> as HasTypenum>::Typenum> as ReduceExp<Q>>::Denom,
+    >: Unit,
 {
-    type Output = UnitPow<U, E1::Output>;
+    type Output = UnitPow<
+        U,
+        <Prod<E1, <Exponent<E2> as HasTypenum>::Typenum> as ReduceExp<Q>>::Numer,
+        <Prod<E1, <Exponent<E2> as HasTypenum>::Typenum> as ReduceExp<Q>>::Denom,
+    >;
     fn pow(self) -> Self::Output { UnitPow::new(self.0) }
 }
 
-impl<U: Unit, E: Integer, const D: i32> CanRoot<D> for UnitPow<U, E> where
+impl<U: Unit, E: Integer, Q: Den, const D: i32> CanRoot<D> for UnitPow<U, E, Q> where
     Exponent<D>: HasTypenum,
-    E: PartialDiv<<Exponent<D> as HasTypenum>::Typenum>,
-    <E as PartialDiv<<Exponent<D> as HasTypenum>::Typenum>>::Output: Integer,
-    UnitPow<U, E>: Unit,
-    UnitPow<U, E::Output>: Unit,
+    Q: Mul<<Exponent<D> as HasTypenum>::Typenum>,
+    Prod<Q, <Exponent<D> as HasTypenum>::Typenum>: Integer + NonZero,
+    E: ReduceExp<Prod<Q, <Exponent<D> as HasTypenum>::Typenum>>,
+    UnitPow<U, E, Q>: Unit,
+    UnitPow<
+        U,
+        <E as ReduceExp<Prod<Q, <Exponent<D> as HasTypenum>::Typenum>>>::Numer,
+        <E as ReduceExp<Prod<Q, <Exponent<D> as HasTypenum>::Typenum>>>::Denom,
+    >: Unit,
 {
-    type Output = UnitPow<U, E::Output>;
+    type Output = UnitPow<
+        U,
+        <E as ReduceExp<Prod<Q, <Exponent<D> as HasTypenum>::Typenum>>>::Numer,
+        <E as ReduceExp<Prod<Q, <Exponent<D> as HasTypenum>::Typenum>>>::Denom,
+    >;
     fn root(self) -> Self::Output { UnitPow::new(self.0) }
 }
 //endregion
@@ -9,14 +9,16 @@
 
 use core::ops::{Div, Mul};
 use crate::{
-    dimension::{CanDimPowType, Exponent, HasTypenum},
+    dimension::{CanDimPowRatio, Exponent, HasTypenum},
     units::{compound::*, traits::*},
 };
 
 
 /// # New Unit Checklist
 /// 1. Source file.
-/// 2. Add to `concrete_types!` and `impl_scale!` calls below.
+/// 2. Add to `concrete_types!`, `impl_root!`, `impl_system!`, and `impl_scale!` calls below.
+///    Types in `concrete_types!` are also registered by `UnitRegistry::new`,
+///    and their symbols are used to display `UnitAnon`.
 /// 3. Add to [`crate::symbols`].
 struct _Notes;
 
@@ -97,7 +99,7 @@ macro_rules! concrete_types {
         //  Unit exponentiation.
         impl<const E: i32> CanPow<E> for $unit where
             Exponent<E>: HasTypenum,
-            Self::Dim: CanDimPowType<<Exponent<E> as HasTypenum>::Typenum>,
+            Self::Dim: CanDimPowRatio<<Exponent<E> as HasTypenum>::Typenum, typenum::P1>,
         {
             type Output = UnitPow<Self, <Exponent<E> as HasTypenum>::Typenum>;
 
@@ -106,9 +108,30 @@ macro_rules! concrete_types {
             }
         }
         )+
+
+        /// Call a [`ConcreteVisitor`] with every concrete unit type, in order,
+        ///     until it returns `Some`.
+        pub(crate) fn visit_concrete<T: ConcreteVisitor>(visitor: &mut T) -> Option<T::Output> {
+            $(if let Some(out) = visitor.visit::<$unit>() {
+                return Some(out);
+            })+
+
+            None
+        }
     };
 }
 
+
+/// Trait for an operation over every concrete unit type, as performed by
+///     [`visit_concrete`].
+pub(crate) trait ConcreteVisitor {
+    /// Result of the operation.
+    type Output;
+
+    /// Visit a concrete unit type. Returning `Some` stops the operation.
+    fn visit<U: UnitConcrete>(&mut self) -> Option<Self::Output>;
+}
+
 concrete_types!(
     one::One,
 
@@ -131,6 +154,53 @@ concrete_types!(
     current::Current,
     voltage::Voltage,
     resistance::Resistance,
+    capacitance::Capacitance,
+    inductance::Inductance,
+);
+
+
+//  Unit roots. These are not implemented for `One`, which is its own root, or
+//      for `Volume`, which has a third root of `Length`.
+macro_rules! impl_root {
+    ($($unit:ident),+ $(,)?) => {
+        $(impl<const D: i32> CanRoot<D> for $unit where
+            Exponent<D>: HasTypenum,
+            <Exponent<D> as HasTypenum>::Typenum: ::typenum::NonZero,
+            ::typenum::P1: ReduceExp<<Exponent<D> as HasTypenum>::Typenum>,
+            UnitRootN<Self, D>: Unit,
+        {
+            type Output = UnitRootN<Self, D>;
+
+            fn root(self) -> Self::Output {
+                UnitPow::new(self)
+            }
+        })+
+    };
+}
+
+impl_root!(
+    Length, Mass, Time, Frequency,
+    Temp, Amount, Intensity,
+    Force, Pressure,
+    Energy, Power, Charge, Current, Voltage, Resistance, Capacitance, Inductance,
+);
+
+
+//  Systems of measurement. These are implemented individually for unit types
+//      that have units specific to some systems, and every unit of the other
+//      types is treated as part of every system.
+macro_rules! impl_system {
+    ($($unit:ident),+ $(,)?) => {
+        $(impl<S: UnitSystem> InSystem<S> for $unit {
+            fn to_system(&self) -> Self { *self }
+        })+
+    };
+}
+
+impl_system!(
+    One, Time, Frequency,
+    Temp, Amount, Intensity,
+    Power, Charge, Current, Voltage, Resistance, Capacitance, Inductance,
 );
 
 
@@ -154,4 +224,6 @@ concrete_types!(
     for Current impl (Micro, Milli, Kilo, Mega, Giga, Tera) Amp;
     for Voltage impl (Micro, Milli, Kilo, Mega, Giga, Tera) Volt;
     for Resistance impl (Micro, Milli, Kilo, Mega, Giga, Tera) Ohm;
+    for Capacitance impl (Pico, Nano, Micro, Milli) Farad;
+    for Inductance impl (Nano, Micro, Milli) Henry;
 }*/
@@ -1,4 +1,4 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{scale_ratio::ScaleRatio, traits::{Unit, UnitConcrete, UnitStep}};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -28,10 +28,31 @@ impl Unit for Amount {
             Self::TeraMole => 1e+12,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::MicroMole => ScaleRatio::pow10(-6),
+            Self::MilliMole => ScaleRatio::pow10(-3),
+            Self::Mole      => ScaleRatio::pow10(0),
+            Self::KiloMole  => ScaleRatio::pow10(3),
+            Self::MegaMole  => ScaleRatio::pow10(6),
+            Self::GigaMole  => ScaleRatio::pow10(9),
+            Self::TeraMole  => ScaleRatio::pow10(12),
+        })
+    }
 }
 
 impl UnitConcrete for Amount {
     const BASE: Self = Self::Mole;
+    const VARIANTS: &'static [Self] = &[
+        Self::MicroMole,
+        Self::MilliMole,
+        Self::Mole,
+        Self::KiloMole,
+        Self::MegaMole,
+        Self::GigaMole,
+        Self::TeraMole,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -70,4 +91,18 @@ impl UnitStep for Amount {
             Self::TeraMole  => None,
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        Some(&[
+            Self::MicroMole,
+            Self::MilliMole,
+            Self::Mole,
+            Self::KiloMole,
+            Self::MegaMole,
+            Self::GigaMole,
+            Self::TeraMole,
+        ])
+    }
 }
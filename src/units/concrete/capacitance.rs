@@ -0,0 +1,92 @@
+use crate::units::{scale_ratio::ScaleRatio, traits::{Unit, UnitConcrete, UnitStep}};
+
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Capacitance {
+    PicoFarad,
+    NanoFarad,
+    MicroFarad,
+    MilliFarad,
+    Farad,
+}
+
+impl Unit for Capacitance {
+    type Dim = crate::dimension::Capacitance;
+    // type ScaleType = f64;
+
+    fn scale(&self) -> f64 {
+        match self {
+            Self::PicoFarad => 1e-12,
+            Self::NanoFarad => 1e-9,
+            Self::MicroFarad => 1e-6,
+            Self::MilliFarad => 1e-3,
+            Self::Farad => 1e0,
+        }
+    }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::PicoFarad  => ScaleRatio::pow10(-12),
+            Self::NanoFarad  => ScaleRatio::pow10(-9),
+            Self::MicroFarad => ScaleRatio::pow10(-6),
+            Self::MilliFarad => ScaleRatio::pow10(-3),
+            Self::Farad      => ScaleRatio::pow10(0),
+        })
+    }
+}
+
+impl UnitConcrete for Capacitance {
+    const BASE: Self = Self::Farad;
+    const VARIANTS: &'static [Self] = &[
+        Self::PicoFarad,
+        Self::NanoFarad,
+        Self::MicroFarad,
+        Self::MilliFarad,
+        Self::Farad,
+    ];
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::PicoFarad => "pF",
+            Self::NanoFarad => "nF",
+            Self::MicroFarad => "μF",
+            Self::MilliFarad => "mF",
+            Self::Farad => "F",
+        }
+    }
+}
+
+impl UnitStep for Capacitance {
+    fn step_down(&self) -> Option<Self> {
+        match self {
+            Self::PicoFarad  => None,
+            Self::NanoFarad  => Some(Self::PicoFarad),
+            Self::MicroFarad => Some(Self::NanoFarad),
+            Self::MilliFarad => Some(Self::MicroFarad),
+            Self::Farad      => Some(Self::MilliFarad),
+        }
+    }
+
+    fn step_up(&self) -> Option<Self> {
+        match self {
+            Self::PicoFarad  => Some(Self::NanoFarad),
+            Self::NanoFarad  => Some(Self::MicroFarad),
+            Self::MicroFarad => Some(Self::MilliFarad),
+            Self::MilliFarad => Some(Self::Farad),
+            Self::Farad      => None,
+        }
+    }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        Some(&[
+            Self::PicoFarad,
+            Self::NanoFarad,
+            Self::MicroFarad,
+            Self::MilliFarad,
+            Self::Farad,
+        ])
+    }
+}
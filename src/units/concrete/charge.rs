@@ -1,4 +1,4 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{scale_ratio::ScaleRatio, traits::{Unit, UnitConcrete, UnitStep}};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -28,10 +28,31 @@ impl Unit for Charge {
             Self::TeraCoulomb => 1e+12,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::MicroCoulomb => ScaleRatio::pow10(-6),
+            Self::MilliCoulomb => ScaleRatio::pow10(-3),
+            Self::Coulomb      => ScaleRatio::pow10(0),
+            Self::KiloCoulomb  => ScaleRatio::pow10(3),
+            Self::MegaCoulomb  => ScaleRatio::pow10(6),
+            Self::GigaCoulomb  => ScaleRatio::pow10(9),
+            Self::TeraCoulomb  => ScaleRatio::pow10(12),
+        })
+    }
 }
 
 impl UnitConcrete for Charge {
     const BASE: Self = Self::Coulomb;
+    const VARIANTS: &'static [Self] = &[
+        Self::MicroCoulomb,
+        Self::MilliCoulomb,
+        Self::Coulomb,
+        Self::KiloCoulomb,
+        Self::MegaCoulomb,
+        Self::GigaCoulomb,
+        Self::TeraCoulomb,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -70,4 +91,18 @@ impl UnitStep for Charge {
             Self::TeraCoulomb  => None,
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        Some(&[
+            Self::MicroCoulomb,
+            Self::MilliCoulomb,
+            Self::Coulomb,
+            Self::KiloCoulomb,
+            Self::MegaCoulomb,
+            Self::GigaCoulomb,
+            Self::TeraCoulomb,
+        ])
+    }
 }
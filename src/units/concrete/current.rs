@@ -1,4 +1,4 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{scale_ratio::ScaleRatio, traits::{Unit, UnitConcrete, UnitStep}};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -28,10 +28,31 @@ impl Unit for Current {
             Self::TeraAmp => 1e+12,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::MicroAmp => ScaleRatio::pow10(-6),
+            Self::MilliAmp => ScaleRatio::pow10(-3),
+            Self::Amp      => ScaleRatio::pow10(0),
+            Self::KiloAmp  => ScaleRatio::pow10(3),
+            Self::MegaAmp  => ScaleRatio::pow10(6),
+            Self::GigaAmp  => ScaleRatio::pow10(9),
+            Self::TeraAmp  => ScaleRatio::pow10(12),
+        })
+    }
 }
 
 impl UnitConcrete for Current {
     const BASE: Self = Self::Amp;
+    const VARIANTS: &'static [Self] = &[
+        Self::MicroAmp,
+        Self::MilliAmp,
+        Self::Amp,
+        Self::KiloAmp,
+        Self::MegaAmp,
+        Self::GigaAmp,
+        Self::TeraAmp,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -70,4 +91,18 @@ impl UnitStep for Current {
             Self::TeraAmp  => None,
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        Some(&[
+            Self::MicroAmp,
+            Self::MilliAmp,
+            Self::Amp,
+            Self::KiloAmp,
+            Self::MegaAmp,
+            Self::GigaAmp,
+            Self::TeraAmp,
+        ])
+    }
 }
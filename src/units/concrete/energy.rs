@@ -1,10 +1,15 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{
+    scale_ratio::ScaleRatio,
+    system::{Cgs, Imperial, Si, UsCustomary},
+    traits::{InSystem, Unit, UnitConcrete, UnitMixed, UnitStep},
+};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Energy {
     ElectronVolt,
+    Erg,
     MicroJoule,
     MilliJoule,
     Joule,
@@ -14,6 +19,31 @@ pub enum Energy {
     TeraJoule,
 }
 
+impl UnitMixed for Energy {
+    fn to_si(&self) -> Self {
+        match self {
+            Self::Erg => Self::MicroJoule,
+            _ => *self,
+        }
+    }
+}
+
+impl InSystem<Si> for Energy {
+    fn to_system(&self) -> Self { self.to_si() }
+}
+
+impl InSystem<UsCustomary> for Energy {
+    fn to_system(&self) -> Self { self.to_si() }
+}
+
+impl InSystem<Imperial> for Energy {
+    fn to_system(&self) -> Self { self.to_si() }
+}
+
+impl InSystem<Cgs> for Energy {
+    fn to_system(&self) -> Self { Self::Erg }
+}
+
 impl Unit for Energy {
     type Dim = crate::dimension::Energy;
     // type ScaleType = f64;
@@ -21,6 +51,7 @@ impl Unit for Energy {
     fn scale(&self) -> f64 {
         match self {
             Self::ElectronVolt => 1.602_176_634_e-19,
+            Self::Erg => 1e-7,
             Self::MicroJoule => 1e-6,
             Self::MilliJoule => 1e-3,
             Self::Joule => 1e0,
@@ -30,14 +61,40 @@ impl Unit for Energy {
             Self::TeraJoule => 1e+12,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::ElectronVolt => return None,
+            Self::Erg          => ScaleRatio::pow10(-7),
+            Self::MicroJoule   => ScaleRatio::pow10(-6),
+            Self::MilliJoule   => ScaleRatio::pow10(-3),
+            Self::Joule        => ScaleRatio::pow10(0),
+            Self::KiloJoule    => ScaleRatio::pow10(3),
+            Self::MegaJoule    => ScaleRatio::pow10(6),
+            Self::GigaJoule    => ScaleRatio::pow10(9),
+            Self::TeraJoule    => ScaleRatio::pow10(12),
+        })
+    }
 }
 
 impl UnitConcrete for Energy {
     const BASE: Self = Self::Joule;
+    const VARIANTS: &'static [Self] = &[
+        Self::ElectronVolt,
+        Self::Erg,
+        Self::MicroJoule,
+        Self::MilliJoule,
+        Self::Joule,
+        Self::KiloJoule,
+        Self::MegaJoule,
+        Self::GigaJoule,
+        Self::TeraJoule,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
             Self::ElectronVolt => "eV",
+            Self::Erg => "erg",
             Self::MicroJoule => "μJ",
             Self::MilliJoule => "mJ",
             Self::Joule => "J",
@@ -53,6 +110,7 @@ impl UnitStep for Energy {
     fn step_down(&self) -> Option<Self> {
         match self {
             Self::ElectronVolt => None,
+            Self::Erg        => Some(Self::ElectronVolt),
             Self::MicroJoule => Some(Self::ElectronVolt),
             Self::MilliJoule => Some(Self::MicroJoule),
             Self::Joule      => Some(Self::MilliJoule),
@@ -66,6 +124,7 @@ impl UnitStep for Energy {
     fn step_up(&self) -> Option<Self> {
         match self {
             Self::ElectronVolt => Some(Self::MicroJoule),
+            Self::Erg        => Some(Self::MicroJoule),
             Self::MicroJoule => Some(Self::MilliJoule),
             Self::MilliJoule => Some(Self::Joule),
             Self::Joule      => Some(Self::KiloJoule),
@@ -75,4 +134,24 @@ impl UnitStep for Energy {
             Self::TeraJoule  => None,
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        match self {
+            //  Side step, not part of any scale.
+            Self::Erg => None,
+
+            _ => Some(&[
+                Self::ElectronVolt,
+                Self::MicroJoule,
+                Self::MilliJoule,
+                Self::Joule,
+                Self::KiloJoule,
+                Self::MegaJoule,
+                Self::GigaJoule,
+                Self::TeraJoule,
+            ]),
+        }
+    }
 }
@@ -1,10 +1,15 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{
+    scale_ratio::ScaleRatio,
+    system::{Cgs, Imperial, Si, UsCustomary},
+    traits::{InSystem, Unit, UnitConcrete, UnitMixed, UnitStep},
+};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Force {
     MicroNewton,
+    Dyne,
     MilliNewton,
     Ounce,
     Newton,
@@ -15,6 +20,41 @@ pub enum Force {
     TeraNewton,
 }
 
+impl UnitMixed for Force {
+    fn to_si(&self) -> Self {
+        match self {
+            Self::Dyne => Self::MicroNewton,
+            Self::Ounce => Self::Newton,
+            Self::Pound => Self::Newton,
+            _ => *self,
+        }
+    }
+}
+
+impl InSystem<Si> for Force {
+    fn to_system(&self) -> Self { self.to_si() }
+}
+
+impl InSystem<UsCustomary> for Force {
+    fn to_system(&self) -> Self {
+        match self {
+            Self::MicroNewton => Self::Ounce,
+            Self::Dyne => Self::Ounce,
+            Self::MilliNewton => Self::Ounce,
+            Self::Ounce => Self::Ounce,
+            _ => Self::Pound,
+        }
+    }
+}
+
+impl InSystem<Imperial> for Force {
+    fn to_system(&self) -> Self { InSystem::<UsCustomary>::to_system(self) }
+}
+
+impl InSystem<Cgs> for Force {
+    fn to_system(&self) -> Self { Self::Dyne }
+}
+
 impl Unit for Force {
     type Dim = crate::dimension::Force;
     // type ScaleType = f64;
@@ -29,14 +69,42 @@ impl Unit for Force {
             Self::GigaNewton => 1e+9,
             Self::TeraNewton => 1e+12,
 
+            Self::Dyne => 1e-5,
             Self::Ounce => 0.278_013_9,
             Self::Pound => 4.448_222,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::MicroNewton => ScaleRatio::pow10(-6),
+            Self::MilliNewton => ScaleRatio::pow10(-3),
+            Self::Newton      => ScaleRatio::pow10(0),
+            Self::KiloNewton  => ScaleRatio::pow10(3),
+            Self::MegaNewton  => ScaleRatio::pow10(6),
+            Self::GigaNewton  => ScaleRatio::pow10(9),
+            Self::TeraNewton  => ScaleRatio::pow10(12),
+            Self::Dyne        => ScaleRatio::pow10(-5),
+            Self::Ounce       => ScaleRatio::decimal(2_780_139, -7),
+            Self::Pound       => ScaleRatio::decimal(4_448_222, -6),
+        })
+    }
 }
 
 impl UnitConcrete for Force {
     const BASE: Self = Self::Newton;
+    const VARIANTS: &'static [Self] = &[
+        Self::MicroNewton,
+        Self::Dyne,
+        Self::MilliNewton,
+        Self::Ounce,
+        Self::Newton,
+        Self::Pound,
+        Self::KiloNewton,
+        Self::MegaNewton,
+        Self::GigaNewton,
+        Self::TeraNewton,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -48,6 +116,7 @@ impl UnitConcrete for Force {
             Self::GigaNewton => "GN",
             Self::TeraNewton => "TN",
 
+            Self::Dyne => "dyn",
             Self::Ounce => "ozf",
             Self::Pound => "lbf",
         }
@@ -65,6 +134,7 @@ impl UnitStep for Force {
             Self::GigaNewton  => Some(Self::MegaNewton),
             Self::TeraNewton  => Some(Self::GigaNewton),
 
+            Self::Dyne        => Some(Self::MicroNewton),
             Self::Ounce       => Some(Self::MilliNewton),
             Self::Pound       => Some(Self::Ounce),
         }
@@ -80,8 +150,30 @@ impl UnitStep for Force {
             Self::GigaNewton  => Some(Self::TeraNewton),
             Self::TeraNewton  => None,
 
+            Self::Dyne        => Some(Self::MilliNewton),
             Self::Ounce       => Some(Self::Pound),
             Self::Pound       => Some(Self::KiloNewton),
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        match self {
+            //  Side step, not part of any scale.
+            Self::Dyne => None,
+
+            Self::Ounce | Self::Pound => Some(&[
+                Self::MicroNewton, Self::MilliNewton,
+                Self::Ounce, Self::Pound,
+                Self::KiloNewton, Self::MegaNewton, Self::GigaNewton, Self::TeraNewton,
+            ]),
+
+            _ => Some(&[
+                Self::MicroNewton, Self::MilliNewton,
+                Self::Newton,
+                Self::KiloNewton, Self::MegaNewton, Self::GigaNewton, Self::TeraNewton,
+            ]),
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{scale_ratio::ScaleRatio, traits::{Unit, UnitConcrete, UnitStep}};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -28,10 +28,31 @@ impl Unit for Frequency {
             Self::TeraHertz => 1e+12,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::MicroHertz => ScaleRatio::pow10(-6),
+            Self::MilliHertz => ScaleRatio::pow10(-3),
+            Self::Hertz      => ScaleRatio::pow10(0),
+            Self::KiloHertz  => ScaleRatio::pow10(3),
+            Self::MegaHertz  => ScaleRatio::pow10(6),
+            Self::GigaHertz  => ScaleRatio::pow10(9),
+            Self::TeraHertz  => ScaleRatio::pow10(12),
+        })
+    }
 }
 
 impl UnitConcrete for Frequency {
     const BASE: Self = Self::Hertz;
+    const VARIANTS: &'static [Self] = &[
+        Self::MicroHertz,
+        Self::MilliHertz,
+        Self::Hertz,
+        Self::KiloHertz,
+        Self::MegaHertz,
+        Self::GigaHertz,
+        Self::TeraHertz,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -70,4 +91,18 @@ impl UnitStep for Frequency {
             Self::TeraHertz  => None,
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        Some(&[
+            Self::MicroHertz,
+            Self::MilliHertz,
+            Self::Hertz,
+            Self::KiloHertz,
+            Self::MegaHertz,
+            Self::GigaHertz,
+            Self::TeraHertz,
+        ])
+    }
 }
@@ -0,0 +1,84 @@
+use crate::units::{scale_ratio::ScaleRatio, traits::{Unit, UnitConcrete, UnitStep}};
+
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Inductance {
+    NanoHenry,
+    MicroHenry,
+    MilliHenry,
+    Henry,
+}
+
+impl Unit for Inductance {
+    type Dim = crate::dimension::Inductance;
+    // type ScaleType = f64;
+
+    fn scale(&self) -> f64 {
+        match self {
+            Self::NanoHenry => 1e-9,
+            Self::MicroHenry => 1e-6,
+            Self::MilliHenry => 1e-3,
+            Self::Henry => 1e0,
+        }
+    }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::NanoHenry  => ScaleRatio::pow10(-9),
+            Self::MicroHenry => ScaleRatio::pow10(-6),
+            Self::MilliHenry => ScaleRatio::pow10(-3),
+            Self::Henry      => ScaleRatio::pow10(0),
+        })
+    }
+}
+
+impl UnitConcrete for Inductance {
+    const BASE: Self = Self::Henry;
+    const VARIANTS: &'static [Self] = &[
+        Self::NanoHenry,
+        Self::MicroHenry,
+        Self::MilliHenry,
+        Self::Henry,
+    ];
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::NanoHenry => "nH",
+            Self::MicroHenry => "μH",
+            Self::MilliHenry => "mH",
+            Self::Henry => "H",
+        }
+    }
+}
+
+impl UnitStep for Inductance {
+    fn step_down(&self) -> Option<Self> {
+        match self {
+            Self::NanoHenry  => None,
+            Self::MicroHenry => Some(Self::NanoHenry),
+            Self::MilliHenry => Some(Self::MicroHenry),
+            Self::Henry      => Some(Self::MilliHenry),
+        }
+    }
+
+    fn step_up(&self) -> Option<Self> {
+        match self {
+            Self::NanoHenry  => Some(Self::MicroHenry),
+            Self::MicroHenry => Some(Self::MilliHenry),
+            Self::MilliHenry => Some(Self::Henry),
+            Self::Henry      => None,
+        }
+    }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        Some(&[
+            Self::NanoHenry,
+            Self::MicroHenry,
+            Self::MilliHenry,
+            Self::Henry,
+        ])
+    }
+}
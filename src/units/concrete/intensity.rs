@@ -1,4 +1,4 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{scale_ratio::ScaleRatio, traits::{Unit, UnitConcrete, UnitStep}};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -28,10 +28,31 @@ impl Unit for Intensity {
             Self::TeraCandela => 1e+12,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::MicroCandela => ScaleRatio::pow10(-6),
+            Self::MilliCandela => ScaleRatio::pow10(-3),
+            Self::Candela      => ScaleRatio::pow10(0),
+            Self::KiloCandela  => ScaleRatio::pow10(3),
+            Self::MegaCandela  => ScaleRatio::pow10(6),
+            Self::GigaCandela  => ScaleRatio::pow10(9),
+            Self::TeraCandela  => ScaleRatio::pow10(12),
+        })
+    }
 }
 
 impl UnitConcrete for Intensity {
     const BASE: Self = Self::Candela;
+    const VARIANTS: &'static [Self] = &[
+        Self::MicroCandela,
+        Self::MilliCandela,
+        Self::Candela,
+        Self::KiloCandela,
+        Self::MegaCandela,
+        Self::GigaCandela,
+        Self::TeraCandela,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -70,4 +91,18 @@ impl UnitStep for Intensity {
             Self::TeraCandela  => None,
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        Some(&[
+            Self::MicroCandela,
+            Self::MilliCandela,
+            Self::Candela,
+            Self::KiloCandela,
+            Self::MegaCandela,
+            Self::GigaCandela,
+            Self::TeraCandela,
+        ])
+    }
 }
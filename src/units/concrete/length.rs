@@ -1,4 +1,8 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{
+    scale_ratio::ScaleRatio,
+    system::{Cgs, Imperial, Si, UsCustomary},
+    traits::{InSystem, Unit, UnitConcrete, UnitMixed, UnitStep},
+};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -32,6 +36,62 @@ pub enum Length {
     GigaParsec,
 }
 
+impl UnitMixed for Length {
+    fn to_si(&self) -> Self {
+        match self {
+            Self::Inch => Self::CentiMeter,
+            Self::Foot => Self::Meter,
+            Self::Yard => Self::Meter,
+            Self::Mile => Self::KiloMeter,
+
+            Self::LightSec => Self::KiloMeter,
+            Self::LightYear => Self::KiloMeter,
+            Self::Parsec => Self::KiloMeter,
+            Self::KiloParsec => Self::KiloMeter,
+            Self::MegaParsec => Self::KiloMeter,
+            Self::GigaParsec => Self::KiloMeter,
+            _ => *self,
+        }
+    }
+}
+
+impl InSystem<Si> for Length {
+    fn to_system(&self) -> Self { self.to_si() }
+}
+
+impl InSystem<UsCustomary> for Length {
+    fn to_system(&self) -> Self {
+        match self {
+            Self::PicoMeter => Self::Inch,
+            Self::NanoMeter => Self::Inch,
+            Self::MicroMeter => Self::Inch,
+            Self::MilliMeter => Self::Inch,
+            Self::CentiMeter => Self::Inch,
+            Self::Meter => Self::Foot,
+            Self::KiloMeter => Self::Mile,
+
+            Self::LightSec => Self::Mile,
+            Self::AstroUnit => Self::Mile,
+            Self::LightYear => Self::Mile,
+            Self::Parsec => Self::Mile,
+            Self::KiloParsec => Self::Mile,
+            Self::MegaParsec => Self::Mile,
+            Self::GigaParsec => Self::Mile,
+            _ => *self,
+        }
+    }
+}
+
+//  NOTE: Since 1959, the inch, foot, yard, and mile have been the same in the
+//      Imperial and US customary systems.
+impl InSystem<Imperial> for Length {
+    fn to_system(&self) -> Self { InSystem::<UsCustomary>::to_system(self) }
+}
+
+impl InSystem<Cgs> for Length {
+    fn to_system(&self) -> Self { Self::CentiMeter }
+}
+
 impl Unit for Length {
     type Dim = crate::dimension::Length;
     // type ScaleType = f64;
@@ -60,10 +120,40 @@ impl Unit for Length {
             Self::GigaParsec => 030_857_e+21,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::PicoMeter  => ScaleRatio::pow10(-12),
+            Self::NanoMeter  => ScaleRatio::pow10(-9),
+            Self::MicroMeter => ScaleRatio::pow10(-6),
+            Self::MilliMeter => ScaleRatio::pow10(-3),
+            Self::CentiMeter => ScaleRatio::pow10(-2),
+            Self::Meter      => ScaleRatio::pow10(0),
+            Self::KiloMeter  => ScaleRatio::pow10(3),
+            Self::Inch       => ScaleRatio::decimal(254, -4),
+            Self::Foot       => ScaleRatio::decimal(3_048, -4),
+            Self::Yard       => ScaleRatio::decimal(9_144, -4),
+            Self::Mile       => ScaleRatio::decimal(1_609_344, -3),
+            Self::LightSec   => ScaleRatio::int(299_792_458),
+            Self::AstroUnit  => ScaleRatio::int(149_597_870_700),
+            Self::LightYear  => ScaleRatio::int(9_460_700_000_000_000),
+            Self::Parsec     => ScaleRatio::decimal(30_857, 12),
+            Self::KiloParsec => return None,
+            Self::MegaParsec => return None,
+            Self::GigaParsec => return None,
+        })
+    }
 }
 
 impl UnitConcrete for Length {
     const BASE: Self = Self::Meter;
+    const VARIANTS: &'static [Self] = &[
+        Self::PicoMeter, Self::NanoMeter, Self::MicroMeter, Self::MilliMeter,
+        Self::CentiMeter, Self::Inch,
+        Self::Foot, Self::Yard, Self::Meter, Self::KiloMeter, Self::Mile,
+        Self::LightSec, Self::AstroUnit, Self::LightYear,
+        Self::Parsec, Self::KiloParsec, Self::MegaParsec, Self::GigaParsec,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -154,4 +244,27 @@ impl UnitStep for Length {
             //endregion
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        match self {
+            //  Side steps, not part of any scale.
+            Self::CentiMeter | Self::Yard => None,
+
+            Self::Inch | Self::Foot | Self::Mile => Some(&[
+                Self::PicoMeter, Self::NanoMeter, Self::MicroMeter, Self::MilliMeter,
+                Self::Inch, Self::Foot, Self::Mile,
+                Self::LightSec, Self::AstroUnit, Self::LightYear,
+                Self::Parsec, Self::KiloParsec, Self::MegaParsec, Self::GigaParsec,
+            ]),
+
+            _ => Some(&[
+                Self::PicoMeter, Self::NanoMeter, Self::MicroMeter, Self::MilliMeter,
+                Self::Meter, Self::KiloMeter,
+                Self::LightSec, Self::AstroUnit, Self::LightYear,
+                Self::Parsec, Self::KiloParsec, Self::MegaParsec, Self::GigaParsec,
+            ]),
+        }
+    }
 }
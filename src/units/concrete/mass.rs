@@ -1,4 +1,8 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{
+    scale_ratio::ScaleRatio,
+    system::{Cgs, Imperial, Si, UsCustomary},
+    traits::{InSystem, Unit, UnitConcrete, UnitMixed, UnitStep},
+};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -13,8 +17,13 @@ pub enum Mass {
     Ounce,
     Pound,
     KiloGram,
+    Stone,
+    ShortHundredweight,
+    LongHundredweight,
+    ShortTon,
 
     MetricTon,
+    LongTon,
     KiloTon,
     MegaTon,
     GigaTon,
@@ -24,6 +33,87 @@ pub enum Mass {
     SolarMass,
 }
 
+impl UnitMixed for Mass {
+    fn to_si(&self) -> Self {
+        match self {
+            Self::Grain => Self::MilliGram,
+            Self::Ounce => Self::Gram,
+            Self::Pound => Self::KiloGram,
+            Self::Stone => Self::KiloGram,
+            Self::ShortHundredweight => Self::KiloGram,
+            Self::LongHundredweight => Self::KiloGram,
+            Self::ShortTon => Self::MetricTon,
+            Self::LongTon => Self::MetricTon,
+
+            Self::EarthMass => Self::GigaTon,
+            Self::JupiterMass => Self::GigaTon,
+            Self::SolarMass => Self::GigaTon,
+            _ => *self,
+        }
+    }
+}
+
+impl InSystem<Si> for Mass {
+    fn to_system(&self) -> Self { self.to_si() }
+}
+
+impl InSystem<UsCustomary> for Mass {
+    fn to_system(&self) -> Self {
+        match self {
+            Self::PicoGram => Self::Grain,
+            Self::NanoGram => Self::Grain,
+            Self::MicroGram => Self::Grain,
+            Self::MilliGram => Self::Grain,
+            Self::Gram => Self::Ounce,
+            Self::KiloGram => Self::Pound,
+
+            Self::MetricTon => Self::ShortTon,
+            Self::KiloTon => Self::ShortTon,
+            Self::MegaTon => Self::ShortTon,
+            Self::GigaTon => Self::ShortTon,
+
+            Self::EarthMass => Self::ShortTon,
+            Self::JupiterMass => Self::ShortTon,
+            Self::SolarMass => Self::ShortTon,
+
+            Self::Stone => Self::Pound,
+            Self::LongHundredweight => Self::ShortHundredweight,
+            Self::LongTon => Self::ShortTon,
+            _ => *self,
+        }
+    }
+}
+
+impl InSystem<Imperial> for Mass {
+    fn to_system(&self) -> Self {
+        match self {
+            Self::PicoGram => Self::Grain,
+            Self::NanoGram => Self::Grain,
+            Self::MicroGram => Self::Grain,
+            Self::MilliGram => Self::Grain,
+            Self::Gram => Self::Ounce,
+            Self::KiloGram => Self::Pound,
+
+            Self::MetricTon => Self::LongTon,
+            Self::KiloTon => Self::LongTon,
+            Self::MegaTon => Self::LongTon,
+            Self::GigaTon => Self::LongTon,
+
+            Self::EarthMass => Self::LongTon,
+            Self::JupiterMass => Self::LongTon,
+            Self::SolarMass => Self::LongTon,
+
+            Self::ShortHundredweight => Self::LongHundredweight,
+            Self::ShortTon => Self::LongTon,
+            _ => *self,
+        }
+    }
+}
+
+impl InSystem<Cgs> for Mass {
+    fn to_system(&self) -> Self { Self::Gram }
+}
+
 impl Unit for Mass {
     type Dim = crate::dimension::Mass;
     // type ScaleType = f64;
@@ -40,6 +130,12 @@ impl Unit for Mass {
             Self::Grain     => 064.798_910_e-6,
             Self::Ounce     => 028.349_523_125_e-3,
             Self::Pound     => 453.592_370_e-3,
+            Self::Stone     => 006.350_293_18,
+
+            Self::ShortHundredweight => 045.359_237,
+            Self::LongHundredweight  => 050.802_345_44,
+            Self::ShortTon           => 907.184_74,
+            Self::LongTon            => 1_016.046_908_8,
 
             Self::MetricTon => 1e+3,
             Self::KiloTon   => 1e+6,
@@ -51,10 +147,43 @@ impl Unit for Mass {
             Self::SolarMass   => 1.988_47_e+30,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::PicoGram    => ScaleRatio::pow10(-15),
+            Self::NanoGram    => ScaleRatio::pow10(-12),
+            Self::MicroGram   => ScaleRatio::pow10(-9),
+            Self::MilliGram   => ScaleRatio::pow10(-6),
+            Self::Gram        => ScaleRatio::pow10(-3),
+            Self::KiloGram    => ScaleRatio::pow10(0),
+            Self::Grain       => ScaleRatio::decimal(64_798_910, -12),
+            Self::Ounce       => ScaleRatio::decimal(28_349_523_125, -12),
+            Self::Pound       => ScaleRatio::decimal(453_592_370, -9),
+            Self::Stone       => ScaleRatio::decimal(635_029_318, -8),
+            Self::ShortHundredweight => ScaleRatio::decimal(45_359_237, -6),
+            Self::LongHundredweight  => ScaleRatio::decimal(5_080_234_544, -8),
+            Self::ShortTon    => ScaleRatio::decimal(90_718_474, -5),
+            Self::LongTon     => ScaleRatio::decimal(10_160_469_088, -7),
+            Self::MetricTon   => ScaleRatio::pow10(3),
+            Self::KiloTon     => ScaleRatio::pow10(6),
+            Self::MegaTon     => ScaleRatio::pow10(9),
+            Self::GigaTon     => ScaleRatio::pow10(12),
+            Self::EarthMass   => return None,
+            Self::JupiterMass => return None,
+            Self::SolarMass   => return None,
+        })
+    }
 }
 
 impl UnitConcrete for Mass {
     const BASE: Self = Self::KiloGram;
+    const VARIANTS: &'static [Self] = &[
+        Self::PicoGram, Self::NanoGram, Self::MicroGram, Self::MilliGram,
+        Self::Grain, Self::Gram, Self::Ounce, Self::Pound, Self::KiloGram,
+        Self::Stone, Self::ShortHundredweight, Self::LongHundredweight,
+        Self::ShortTon, Self::MetricTon, Self::LongTon, Self::KiloTon, Self::MegaTon, Self::GigaTon,
+        Self::EarthMass, Self::JupiterMass, Self::SolarMass,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -68,6 +197,12 @@ impl UnitConcrete for Mass {
             Self::Grain     => "gr",
             Self::Ounce     => "oz",
             Self::Pound     => "lb",
+            Self::Stone     => "st",
+
+            Self::ShortHundredweight => "sh cwt",
+            Self::LongHundredweight  => "long cwt",
+            Self::ShortTon           => "sh tn",
+            Self::LongTon            => "long tn",
 
             Self::MetricTon =>  "T",
             Self::KiloTon   => "kT",
@@ -110,6 +245,12 @@ impl UnitStep for Mass {
             Self::Grain         => Some(Self::MilliGram),
             Self::Ounce         => Some(Self::Grain),
             Self::Pound         => Some(Self::Ounce),
+            Self::Stone         => Some(Self::Pound),
+
+            Self::ShortHundredweight => Some(Self::Pound),
+            Self::ShortTon           => Some(Self::ShortHundredweight),
+            Self::LongHundredweight  => Some(Self::Stone),
+            Self::LongTon            => Some(Self::LongHundredweight),
 
             Self::MetricTon     => Some(Self::KiloGram),
             Self::KiloTon       => Some(Self::MetricTon),
@@ -133,7 +274,13 @@ impl UnitStep for Mass {
 
             Self::Grain         => Some(Self::Ounce),
             Self::Ounce         => Some(Self::Pound),
-            Self::Pound         => Some(Self::MetricTon),
+            Self::Pound         => Some(Self::ShortHundredweight),
+            Self::Stone         => Some(Self::LongHundredweight),
+
+            Self::ShortHundredweight => Some(Self::ShortTon),
+            Self::ShortTon           => Some(Self::KiloTon),
+            Self::LongHundredweight  => Some(Self::LongTon),
+            Self::LongTon            => Some(Self::KiloTon),
 
             Self::MetricTon     => Some(Self::KiloTon),
             Self::KiloTon       => Some(Self::MegaTon),
@@ -145,4 +292,34 @@ impl UnitStep for Mass {
             Self::SolarMass     => None,
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        match self {
+            Self::Grain | Self::Ounce | Self::Pound
+            | Self::ShortHundredweight | Self::ShortTon => Some(&[
+                Self::PicoGram, Self::NanoGram, Self::MicroGram, Self::MilliGram,
+                Self::Grain, Self::Ounce, Self::Pound,
+                Self::ShortHundredweight, Self::ShortTon,
+                Self::KiloTon, Self::MegaTon, Self::GigaTon,
+                Self::EarthMass, Self::JupiterMass, Self::SolarMass,
+            ]),
+
+            Self::Stone | Self::LongHundredweight | Self::LongTon => Some(&[
+                Self::PicoGram, Self::NanoGram, Self::MicroGram, Self::MilliGram,
+                Self::Grain, Self::Ounce, Self::Pound,
+                Self::Stone, Self::LongHundredweight, Self::LongTon,
+                Self::KiloTon, Self::MegaTon, Self::GigaTon,
+                Self::EarthMass, Self::JupiterMass, Self::SolarMass,
+            ]),
+
+            _ => Some(&[
+                Self::PicoGram, Self::NanoGram, Self::MicroGram, Self::MilliGram,
+                Self::Gram, Self::KiloGram,
+                Self::MetricTon, Self::KiloTon, Self::MegaTon, Self::GigaTon,
+                Self::EarthMass, Self::JupiterMass, Self::SolarMass,
+            ]),
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::units::traits::{CanRoot, Unit, UnitConcrete, UnitStep};
+use crate::units::{scale_ratio::ScaleRatio, traits::{CanRoot, Unit, UnitConcrete, UnitStep}};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -10,6 +10,7 @@ impl Unit for One {
     // type ScaleType = f64;
 
     fn scale(&self) -> f64 { 1.0 }
+    fn scale_ratio(&self) -> Option<ScaleRatio> { Some(ScaleRatio::ONE) }
 }
 
 impl<const D: i32> CanRoot<D> for One {
@@ -19,10 +20,12 @@ impl<const D: i32> CanRoot<D> for One {
 
 impl UnitConcrete for One {
     const BASE: Self = Self;
+    const VARIANTS: &'static [Self] = &[Self];
     fn symbol(&self) -> &'static str { "1" }
 }
 
 impl UnitStep for One {
     fn step_down(&self) -> Option<Self> { None }
     fn step_up(&self) -> Option<Self> { None }
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
 }
@@ -1,4 +1,4 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{scale_ratio::ScaleRatio, traits::{Unit, UnitConcrete, UnitStep}};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -28,10 +28,31 @@ impl Unit for Power {
             Self::TeraWatt => 1e+12,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::MicroWatt => ScaleRatio::pow10(-6),
+            Self::MilliWatt => ScaleRatio::pow10(-3),
+            Self::Watt      => ScaleRatio::pow10(0),
+            Self::KiloWatt  => ScaleRatio::pow10(3),
+            Self::MegaWatt  => ScaleRatio::pow10(6),
+            Self::GigaWatt  => ScaleRatio::pow10(9),
+            Self::TeraWatt  => ScaleRatio::pow10(12),
+        })
+    }
 }
 
 impl UnitConcrete for Power {
     const BASE: Self = Self::Watt;
+    const VARIANTS: &'static [Self] = &[
+        Self::MicroWatt,
+        Self::MilliWatt,
+        Self::Watt,
+        Self::KiloWatt,
+        Self::MegaWatt,
+        Self::GigaWatt,
+        Self::TeraWatt,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -70,4 +91,18 @@ impl UnitStep for Power {
             Self::TeraWatt  => None,
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        Some(&[
+            Self::MicroWatt,
+            Self::MilliWatt,
+            Self::Watt,
+            Self::KiloWatt,
+            Self::MegaWatt,
+            Self::GigaWatt,
+            Self::TeraWatt,
+        ])
+    }
 }
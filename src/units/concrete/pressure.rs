@@ -1,4 +1,8 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{
+    scale_ratio::ScaleRatio,
+    system::{Cgs, Imperial, Si, UsCustomary},
+    traits::{InSystem, Unit, UnitConcrete, UnitMixed, UnitStep},
+};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -6,6 +10,7 @@ use crate::units::traits::{Unit, UnitConcrete, UnitStep};
 pub enum Pressure {
     MicroPascal,
     MilliPascal,
+    Barye,
     Pascal,
     KiloPascal,
     Psi,
@@ -16,6 +21,46 @@ pub enum Pressure {
     TeraPascal,
 }
 
+impl UnitMixed for Pressure {
+    fn to_si(&self) -> Self {
+        match self {
+            Self::Barye => Self::Pascal,
+            Self::Psi => Self::KiloPascal,
+            Self::KiloPsi => Self::MegaPascal,
+            Self::MegaPsi => Self::GigaPascal,
+            _ => *self,
+        }
+    }
+}
+
+impl InSystem<Si> for Pressure {
+    fn to_system(&self) -> Self { self.to_si() }
+}
+
+impl InSystem<UsCustomary> for Pressure {
+    fn to_system(&self) -> Self {
+        match self {
+            Self::MicroPascal => Self::Psi,
+            Self::MilliPascal => Self::Psi,
+            Self::Barye => Self::Psi,
+            Self::Pascal => Self::Psi,
+            Self::KiloPascal => Self::Psi,
+            Self::MegaPascal => Self::KiloPsi,
+            Self::GigaPascal => Self::MegaPsi,
+            Self::TeraPascal => Self::MegaPsi,
+            _ => *self,
+        }
+    }
+}
+
+impl InSystem<Imperial> for Pressure {
+    fn to_system(&self) -> Self { InSystem::<UsCustomary>::to_system(self) }
+}
+
+impl InSystem<Cgs> for Pressure {
+    fn to_system(&self) -> Self { Self::Barye }
+}
+
 impl Unit for Pressure {
     type Dim = crate::dimension::Pressure;
     // type ScaleType = f64;
@@ -30,15 +75,46 @@ impl Unit for Pressure {
             Self::GigaPascal => 1e+9,
             Self::TeraPascal => 1e+12,
 
+            Self::Barye => 1e-1,
+
             Self::Psi     => 6_894.757_889_515_779,
             Self::KiloPsi => 6_894_757.889_515_779,
             Self::MegaPsi => 6_894_757_889.515_779,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::MicroPascal => ScaleRatio::pow10(-6),
+            Self::MilliPascal => ScaleRatio::pow10(-3),
+            Self::Pascal      => ScaleRatio::pow10(0),
+            Self::KiloPascal  => ScaleRatio::pow10(3),
+            Self::MegaPascal  => ScaleRatio::pow10(6),
+            Self::GigaPascal  => ScaleRatio::pow10(9),
+            Self::TeraPascal  => ScaleRatio::pow10(12),
+            Self::Barye       => ScaleRatio::pow10(-1),
+            Self::Psi         => ScaleRatio::decimal(6_894_757_889_515_779, -12),
+            Self::KiloPsi     => ScaleRatio::decimal(6_894_757_889_515_779, -9),
+            Self::MegaPsi     => ScaleRatio::decimal(6_894_757_889_515_779, -6),
+        })
+    }
 }
 
 impl UnitConcrete for Pressure {
     const BASE: Self = Self::Pascal;
+    const VARIANTS: &'static [Self] = &[
+        Self::MicroPascal,
+        Self::MilliPascal,
+        Self::Barye,
+        Self::Pascal,
+        Self::KiloPascal,
+        Self::Psi,
+        Self::MegaPascal,
+        Self::KiloPsi,
+        Self::GigaPascal,
+        Self::MegaPsi,
+        Self::TeraPascal,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -50,6 +126,8 @@ impl UnitConcrete for Pressure {
             Self::GigaPascal => "GPa",
             Self::TeraPascal => "TPa",
 
+            Self::Barye => "Ba",
+
             Self::Psi => "psi",
             Self::KiloPsi => "kpsi",
             Self::MegaPsi => "Mpsi",
@@ -68,6 +146,8 @@ impl UnitStep for Pressure {
             Self::GigaPascal  => Some(Self::MegaPascal),
             Self::TeraPascal  => Some(Self::GigaPascal),
 
+            Self::Barye => Some(Self::MilliPascal),
+
             Self::Psi => Some(Self::KiloPascal),
             Self::KiloPsi => Some(Self::Psi),
             Self::MegaPsi => Some(Self::KiloPsi),
@@ -84,9 +164,31 @@ impl UnitStep for Pressure {
             Self::GigaPascal  => Some(Self::TeraPascal),
             Self::TeraPascal  => None,
 
+            Self::Barye => Some(Self::Pascal),
+
             Self::Psi => Some(Self::KiloPsi),
             Self::KiloPsi => Some(Self::MegaPsi),
             Self::MegaPsi => Some(Self::TeraPascal),
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        match self {
+            //  Side step, not part of any scale.
+            Self::Barye => None,
+
+            Self::Psi | Self::KiloPsi | Self::MegaPsi => Some(&[
+                Self::MicroPascal, Self::MilliPascal, Self::Pascal, Self::KiloPascal,
+                Self::Psi, Self::KiloPsi, Self::MegaPsi,
+                Self::TeraPascal,
+            ]),
+
+            _ => Some(&[
+                Self::MicroPascal, Self::MilliPascal, Self::Pascal, Self::KiloPascal,
+                Self::MegaPascal, Self::GigaPascal, Self::TeraPascal,
+            ]),
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{scale_ratio::ScaleRatio, traits::{Unit, UnitConcrete, UnitStep}};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -28,10 +28,31 @@ impl Unit for Resistance {
             Self::TeraOhm => 1e+12,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::MicroOhm => ScaleRatio::pow10(-6),
+            Self::MilliOhm => ScaleRatio::pow10(-3),
+            Self::Ohm      => ScaleRatio::pow10(0),
+            Self::KiloOhm  => ScaleRatio::pow10(3),
+            Self::MegaOhm  => ScaleRatio::pow10(6),
+            Self::GigaOhm  => ScaleRatio::pow10(9),
+            Self::TeraOhm  => ScaleRatio::pow10(12),
+        })
+    }
 }
 
 impl UnitConcrete for Resistance {
     const BASE: Self = Self::Ohm;
+    const VARIANTS: &'static [Self] = &[
+        Self::MicroOhm,
+        Self::MilliOhm,
+        Self::Ohm,
+        Self::KiloOhm,
+        Self::MegaOhm,
+        Self::GigaOhm,
+        Self::TeraOhm,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -70,4 +91,18 @@ impl UnitStep for Resistance {
             Self::TeraOhm  => None,
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        Some(&[
+            Self::MicroOhm,
+            Self::MilliOhm,
+            Self::Ohm,
+            Self::KiloOhm,
+            Self::MegaOhm,
+            Self::GigaOhm,
+            Self::TeraOhm,
+        ])
+    }
 }
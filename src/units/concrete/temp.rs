@@ -1,4 +1,4 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{scale_ratio::ScaleRatio, traits::{Unit, UnitConcrete, UnitStep}};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -28,10 +28,31 @@ impl Unit for Temp {
             Self::TeraKelvin => 1e+12,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::MicroKelvin => ScaleRatio::pow10(-6),
+            Self::MilliKelvin => ScaleRatio::pow10(-3),
+            Self::Kelvin      => ScaleRatio::pow10(0),
+            Self::KiloKelvin  => ScaleRatio::pow10(3),
+            Self::MegaKelvin  => ScaleRatio::pow10(6),
+            Self::GigaKelvin  => ScaleRatio::pow10(9),
+            Self::TeraKelvin  => ScaleRatio::pow10(12),
+        })
+    }
 }
 
 impl UnitConcrete for Temp {
     const BASE: Self = Self::Kelvin;
+    const VARIANTS: &'static [Self] = &[
+        Self::MicroKelvin,
+        Self::MilliKelvin,
+        Self::Kelvin,
+        Self::KiloKelvin,
+        Self::MegaKelvin,
+        Self::GigaKelvin,
+        Self::TeraKelvin,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -70,4 +91,18 @@ impl UnitStep for Temp {
             Self::TeraKelvin  => None,
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        Some(&[
+            Self::MicroKelvin,
+            Self::MilliKelvin,
+            Self::Kelvin,
+            Self::KiloKelvin,
+            Self::MegaKelvin,
+            Self::GigaKelvin,
+            Self::TeraKelvin,
+        ])
+    }
 }
@@ -1,4 +1,4 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{scale_ratio::ScaleRatio, traits::{Unit, UnitConcrete, UnitMixed, UnitStep}};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -14,6 +14,12 @@ pub enum Time {
     Day,
 }
 
+//  NOTE: Minutes, hours, and days are accepted for use with the SI, in the same
+//      way as liters are for volume, so every variant is treated as SI.
+impl UnitMixed for Time {
+    fn to_si(&self) -> Self { *self }
+}
+
 impl Unit for Time {
     type Dim = crate::dimension::Time;
     // type ScaleType = f64;
@@ -30,10 +36,33 @@ impl Unit for Time {
             Self::Day => 86_400.0,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::PicoSecond  => ScaleRatio::pow10(-12),
+            Self::NanoSecond  => ScaleRatio::pow10(-9),
+            Self::MicroSecond => ScaleRatio::pow10(-6),
+            Self::MilliSecond => ScaleRatio::pow10(-3),
+            Self::Second      => ScaleRatio::ONE,
+            Self::Minute      => ScaleRatio::int(60),
+            Self::Hour        => ScaleRatio::int(3_600),
+            Self::Day         => ScaleRatio::int(86_400),
+        })
+    }
 }
 
 impl UnitConcrete for Time {
     const BASE: Self = Self::Second;
+    const VARIANTS: &'static [Self] = &[
+        Self::PicoSecond,
+        Self::NanoSecond,
+        Self::MicroSecond,
+        Self::MilliSecond,
+        Self::Second,
+        Self::Minute,
+        Self::Hour,
+        Self::Day,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -47,6 +76,14 @@ impl UnitConcrete for Time {
             Self::Day => "d",
         }
     }
+
+    fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            //  Accept the common shorthand of `m` for minutes, as in "1h30m".
+            "m" => Some(Self::Minute),
+            _ => Self::VARIANTS.iter().find(|unit| unit.symbol() == symbol).copied(),
+        }
+    }
 }
 
 
@@ -76,4 +113,19 @@ impl UnitStep for Time {
             Self::Day => None,
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        Some(&[
+            Self::PicoSecond,
+            Self::NanoSecond,
+            Self::MicroSecond,
+            Self::MilliSecond,
+            Self::Second,
+            Self::Minute,
+            Self::Hour,
+            Self::Day,
+        ])
+    }
 }
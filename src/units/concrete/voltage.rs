@@ -1,4 +1,4 @@
-use crate::units::traits::{Unit, UnitConcrete, UnitStep};
+use crate::units::{scale_ratio::ScaleRatio, traits::{Unit, UnitConcrete, UnitStep}};
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -28,10 +28,31 @@ impl Unit for Voltage {
             Self::TeraVolt => 1e+12,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::MicroVolt => ScaleRatio::pow10(-6),
+            Self::MilliVolt => ScaleRatio::pow10(-3),
+            Self::Volt      => ScaleRatio::pow10(0),
+            Self::KiloVolt  => ScaleRatio::pow10(3),
+            Self::MegaVolt  => ScaleRatio::pow10(6),
+            Self::GigaVolt  => ScaleRatio::pow10(9),
+            Self::TeraVolt  => ScaleRatio::pow10(12),
+        })
+    }
 }
 
 impl UnitConcrete for Voltage {
     const BASE: Self = Self::Volt;
+    const VARIANTS: &'static [Self] = &[
+        Self::MicroVolt,
+        Self::MilliVolt,
+        Self::Volt,
+        Self::KiloVolt,
+        Self::MegaVolt,
+        Self::GigaVolt,
+        Self::TeraVolt,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -70,4 +91,18 @@ impl UnitStep for Voltage {
             Self::TeraVolt  => None,
         }
     }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        Some(&[
+            Self::MicroVolt,
+            Self::MilliVolt,
+            Self::Volt,
+            Self::KiloVolt,
+            Self::MegaVolt,
+            Self::GigaVolt,
+            Self::TeraVolt,
+        ])
+    }
 }
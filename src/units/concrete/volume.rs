@@ -1,14 +1,31 @@
 use crate::units::{
     Length,
-    traits::{CanRoot, Unit, UnitConcrete, UnitMixed, UnitStep},
+    ScaleRatio,
+    system::{Cgs, Imperial, Si, UsCustomary},
+    traits::{CanRoot, InSystem, Unit, UnitConcrete, UnitMixed, UnitStep},
     UnitRescale,
 };
 
 
-const _GALLON_IMP: f64 = 4.546_090_e-3;
-const _GALLON_USA: f64 = 3.785_411_784_e-3;
+const GALLON_IMP: f64 = 4.546_090_e-3;
+const GALLON_USA: f64 = 3.785_411_784_e-3;
+const BUSHEL_USA: f64 = 35.239_070_166_88_e-3;
 
-const GAL: f64 = _GALLON_USA;
+/// Return an exact fraction of a [US gallon](GALLON_USA), in cubic meters.
+const fn gal(div: u64) -> ScaleRatio {
+    ScaleRatio::new(3_785_411_784, div * 1_000_000_000_000)
+}
+
+/// Return an exact fraction of an [imperial gallon](GALLON_IMP), in cubic
+///     meters.
+const fn gal_imp(div: u64) -> ScaleRatio {
+    ScaleRatio::new(454_609, div * 100_000_000)
+}
+
+/// Return an exact fraction of a [US bushel](BUSHEL_USA), in cubic meters.
+const fn bu(div: u64) -> ScaleRatio {
+    ScaleRatio::new(3_523_907_016_688, div * 100_000_000_000_000)
+}
 
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
@@ -18,12 +35,19 @@ pub enum Volume {
     MilliLiter,
 
     Dram,
+    ImpFlOunce,
     FlOunce,
     Cup,
     Pint,
+    DryPint,
+    ImpPint,
     Quart,
     Liter,
+    DryQuart,
     Gallon,
+    ImpGallon,
+    Peck,
+    Bushel,
 
     KiloLiter,
     MegaLiter,
@@ -40,11 +64,74 @@ impl UnitMixed for Volume {
             Self::Pint => Self::Liter,
             Self::Quart => Self::Liter,
             Self::Gallon => Self::Liter,
+
+            Self::ImpFlOunce => Self::MilliLiter,
+            Self::ImpPint => Self::Liter,
+            Self::ImpGallon => Self::Liter,
+
+            Self::DryPint => Self::Liter,
+            Self::DryQuart => Self::Liter,
+            Self::Peck => Self::Liter,
+            Self::Bushel => Self::Liter,
+            _ => *self,
+        }
+    }
+}
+
+impl InSystem<Si> for Volume {
+    fn to_system(&self) -> Self { self.to_si() }
+}
+
+impl InSystem<UsCustomary> for Volume {
+    fn to_system(&self) -> Self {
+        match self {
+            Self::MicroLiter => Self::Dram,
+            Self::MilliLiter => Self::FlOunce,
+            Self::Liter => Self::Quart,
+            Self::KiloLiter => Self::Gallon,
+            Self::MegaLiter => Self::Gallon,
+            Self::GigaLiter => Self::Gallon,
+            Self::TeraLiter => Self::Gallon,
+
+            Self::ImpFlOunce => Self::FlOunce,
+            Self::ImpPint => Self::Pint,
+            Self::ImpGallon => Self::Gallon,
+            _ => *self,
+        }
+    }
+}
+
+impl InSystem<Imperial> for Volume {
+    fn to_system(&self) -> Self {
+        match self {
+            Self::MicroLiter => Self::ImpFlOunce,
+            Self::MilliLiter => Self::ImpFlOunce,
+            Self::Liter => Self::ImpPint,
+            Self::KiloLiter => Self::ImpGallon,
+            Self::MegaLiter => Self::ImpGallon,
+            Self::GigaLiter => Self::ImpGallon,
+            Self::TeraLiter => Self::ImpGallon,
+
+            Self::Dram => Self::ImpFlOunce,
+            Self::FlOunce => Self::ImpFlOunce,
+            Self::Cup => Self::ImpPint,
+            Self::Pint => Self::ImpPint,
+            Self::Quart => Self::ImpPint,
+            Self::Gallon => Self::ImpGallon,
+
+            Self::DryPint => Self::ImpPint,
+            Self::DryQuart => Self::ImpPint,
+            Self::Peck => Self::ImpGallon,
+            Self::Bushel => Self::ImpGallon,
             _ => *self,
         }
     }
 }
 
+impl InSystem<Cgs> for Volume {
+    fn to_system(&self) -> Self { Self::MilliLiter }
+}
+
 impl Unit for Volume {
     type Dim = crate::dimension::Volume;
     // type ScaleType = f64;
@@ -59,14 +146,48 @@ impl Unit for Volume {
             Self::GigaLiter  => 1e+6,
             Self::TeraLiter  => 1e+9,
 
-            Self::Dram       => GAL / 1_280.0,
-            Self::FlOunce    => GAL / 160.0,
-            Self::Cup        => GAL / 16.0,
-            Self::Pint       => GAL / 8.0,
-            Self::Quart      => GAL / 4.0,
-            Self::Gallon     => GAL,
+            Self::Dram       => GALLON_USA / 1_024.0,
+            Self::FlOunce    => GALLON_USA / 128.0,
+            Self::Cup        => GALLON_USA / 16.0,
+            Self::Pint       => GALLON_USA / 8.0,
+            Self::Quart      => GALLON_USA / 4.0,
+            Self::Gallon     => GALLON_USA,
+
+            Self::ImpFlOunce => GALLON_IMP / 160.0,
+            Self::ImpPint    => GALLON_IMP / 8.0,
+            Self::ImpGallon  => GALLON_IMP,
+
+            Self::DryPint    => BUSHEL_USA / 64.0,
+            Self::DryQuart   => BUSHEL_USA / 32.0,
+            Self::Peck       => BUSHEL_USA / 4.0,
+            Self::Bushel     => BUSHEL_USA,
         }
     }
+
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        Some(match self {
+            Self::MicroLiter => ScaleRatio::pow10(-9),
+            Self::MilliLiter => ScaleRatio::pow10(-6),
+            Self::Liter      => ScaleRatio::pow10(-3),
+            Self::KiloLiter  => ScaleRatio::ONE,
+            Self::MegaLiter  => ScaleRatio::pow10(3),
+            Self::GigaLiter  => ScaleRatio::pow10(6),
+            Self::TeraLiter  => ScaleRatio::pow10(9),
+            Self::Dram       => gal(1_024),
+            Self::FlOunce    => gal(128),
+            Self::Cup        => gal(16),
+            Self::Pint       => gal(8),
+            Self::Quart      => gal(4),
+            Self::Gallon     => gal(1),
+            Self::ImpFlOunce => gal_imp(160),
+            Self::ImpPint    => gal_imp(8),
+            Self::ImpGallon  => gal_imp(1),
+            Self::DryPint    => bu(64),
+            Self::DryQuart   => bu(32),
+            Self::Peck       => bu(4),
+            Self::Bushel     => bu(1),
+        })
+    }
 }
 
 impl CanRoot<3> for Volume {
@@ -91,6 +212,14 @@ impl CanRoot<3> for Volume {
 
 impl UnitConcrete for Volume {
     const BASE: Self = Self::Liter;
+    const VARIANTS: &'static [Self] = &[
+        Self::MicroLiter, Self::MilliLiter,
+        Self::Dram, Self::ImpFlOunce, Self::FlOunce, Self::Cup,
+        Self::Pint, Self::DryPint, Self::ImpPint, Self::Quart,
+        Self::Liter, Self::DryQuart, Self::Gallon, Self::ImpGallon,
+        Self::Peck, Self::Bushel,
+        Self::KiloLiter, Self::MegaLiter, Self::GigaLiter, Self::TeraLiter,
+    ];
 
     fn symbol(&self) -> &'static str {
         match self {
@@ -108,6 +237,15 @@ impl UnitConcrete for Volume {
             Self::Pint       => "pt",
             Self::Quart      => "qt",
             Self::Gallon     => "gal",
+
+            Self::ImpFlOunce => "imp fl oz",
+            Self::ImpPint    => "imp pt",
+            Self::ImpGallon  => "imp gal",
+
+            Self::DryPint    => "dry pt",
+            Self::DryQuart   => "dry qt",
+            Self::Peck       => "pk",
+            Self::Bushel     => "bu",
         }
     }
 }
@@ -129,6 +267,15 @@ impl UnitStep for Volume {
             Self::Pint       => Some(Self::Cup),
             Self::Quart      => Some(Self::Pint),
             Self::Gallon     => Some(Self::Quart),
+
+            Self::ImpFlOunce => Some(Self::MilliLiter),
+            Self::ImpPint    => Some(Self::ImpFlOunce),
+            Self::ImpGallon  => Some(Self::ImpPint),
+
+            Self::DryPint    => Some(Self::MilliLiter),
+            Self::DryQuart   => Some(Self::DryPint),
+            Self::Peck       => Some(Self::DryQuart),
+            Self::Bushel     => Some(Self::Peck),
         }
     }
 
@@ -148,6 +295,46 @@ impl UnitStep for Volume {
             Self::Pint       => Some(Self::Quart),
             Self::Quart      => Some(Self::Gallon),
             Self::Gallon     => Some(Self::KiloLiter),
+
+            Self::ImpFlOunce => Some(Self::ImpPint),
+            Self::ImpPint    => Some(Self::ImpGallon),
+            Self::ImpGallon  => Some(Self::KiloLiter),
+
+            Self::DryPint    => Some(Self::DryQuart),
+            Self::DryQuart   => Some(Self::Peck),
+            Self::Peck       => Some(Self::Bushel),
+            Self::Bushel     => Some(Self::KiloLiter),
+        }
+    }
+
+    fn variants() -> Option<&'static [Self]> { Some(Self::VARIANTS) }
+
+    fn step_table(&self) -> Option<&'static [Self]> {
+        match self {
+            Self::Dram | Self::FlOunce | Self::Cup
+            | Self::Pint | Self::Quart | Self::Gallon => Some(&[
+                Self::MicroLiter, Self::MilliLiter,
+                Self::Dram, Self::FlOunce, Self::Cup, Self::Pint, Self::Quart, Self::Gallon,
+                Self::KiloLiter, Self::MegaLiter, Self::GigaLiter, Self::TeraLiter,
+            ]),
+
+            Self::ImpFlOunce | Self::ImpPint | Self::ImpGallon => Some(&[
+                Self::MicroLiter, Self::MilliLiter,
+                Self::ImpFlOunce, Self::ImpPint, Self::ImpGallon,
+                Self::KiloLiter, Self::MegaLiter, Self::GigaLiter, Self::TeraLiter,
+            ]),
+
+            Self::DryPint | Self::DryQuart | Self::Peck | Self::Bushel => Some(&[
+                Self::MicroLiter, Self::MilliLiter,
+                Self::DryPint, Self::DryQuart, Self::Peck, Self::Bushel,
+                Self::KiloLiter, Self::MegaLiter, Self::GigaLiter, Self::TeraLiter,
+            ]),
+
+            _ => Some(&[
+                Self::MicroLiter, Self::MilliLiter,
+                Self::Liter,
+                Self::KiloLiter, Self::MegaLiter, Self::GigaLiter, Self::TeraLiter,
+            ]),
         }
     }
 }
@@ -0,0 +1,159 @@
+//! Module for exact rational unit scales.
+
+use num_traits::{CheckedDiv, CheckedMul};
+use crate::Value;
+
+
+/// An exact rational scale factor, `num/den`, always stored in lowest terms.
+///
+/// Units whose scales can be expressed exactly as ratios of integers provide
+///     them through [`Unit::scale_ratio`](super::Unit::scale_ratio). Converting
+///     quantities with these ratios avoids floating point arithmetic entirely,
+///     which is important on targets without hardware floating point support.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ScaleRatio {
+    /// Numerator.
+    pub num: u64,
+    /// Denominator.
+    pub den: u64,
+}
+
+impl ScaleRatio {
+    /// A ratio of exactly one.
+    pub const ONE: Self = Self { num: 1, den: 1 };
+
+    /// Construct a new ratio, reduced to lowest terms.
+    ///
+    /// # Panics
+    /// Panics if the denominator is zero.
+    pub const fn new(num: u64, den: u64) -> Self {
+        assert!(den != 0, "denominator must not be zero");
+
+        let gcd = gcd(num, den);
+        Self { num: num / gcd, den: den / gcd }
+    }
+
+    /// Construct a new ratio from an integer.
+    pub const fn int(num: u64) -> Self { Self { num, den: 1 } }
+
+    /// Construct a new ratio equal to a power of ten.
+    ///
+    /// # Panics
+    /// Panics if the power of ten does not fit in a `u64`.
+    pub const fn pow10(exp: i32) -> Self {
+        let pow = 10u64.pow(exp.unsigned_abs());
+
+        if exp < 0 {
+            Self { num: 1, den: pow }
+        } else {
+            Self { num: pow, den: 1 }
+        }
+    }
+
+    /// Construct a new ratio equal to a decimal number, `mantissa * 10^exp`.
+    ///
+    /// # Panics
+    /// Panics if the result does not fit in a `u64` ratio.
+    pub const fn decimal(mantissa: u64, exp: i32) -> Self {
+        let pow = Self::pow10(exp);
+        Self::new(mantissa * pow.num, pow.den)
+    }
+
+    /// Return the reciprocal of this ratio.
+    pub const fn recip(self) -> Self { Self { num: self.den, den: self.num } }
+
+    /// Multiply this ratio by another, returning `None` on overflow.
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        //  Cross-reduce first, to keep the products small.
+        let a = gcd(self.num, rhs.den);
+        let b = gcd(rhs.num, self.den);
+
+        match (
+            (self.num / a).checked_mul(rhs.num / b),
+            (self.den / b).checked_mul(rhs.den / a),
+        ) {
+            (Some(num), Some(den)) => Some(Self { num, den }),
+            _ => None,
+        }
+    }
+
+    /// Divide this ratio by another, returning `None` on overflow.
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.recip())
+    }
+
+    /// Raise this ratio to an integer power, returning `None` on overflow.
+    pub const fn checked_pow(self, exp: i32) -> Option<Self> {
+        let base = if exp < 0 { self.recip() } else { self };
+
+        match (
+            base.num.checked_pow(exp.unsigned_abs()),
+            base.den.checked_pow(exp.unsigned_abs()),
+        ) {
+            (Some(num), Some(den)) => Some(Self { num, den }),
+            _ => None,
+        }
+    }
+
+    /// Return this ratio as an `f64`.
+    pub fn to_f64(self) -> f64 { self.num as f64 / self.den as f64 }
+
+    /// Multiply a value by this ratio, without converting through floating
+    ///     point. Returns `None` if the numerator or denominator cannot be
+    ///     represented by the value type.
+    ///
+    /// The value is multiplied by the numerator before it is divided by the
+    ///     denominator, which preserves precision for integer and fixed-point
+    ///     types, but may overflow where the intermediate product is large.
+    pub fn apply<V: Value>(self, value: V) -> Option<V> {
+        let out = match (self.num, self.den) {
+            (1, 1) => value,
+            (num, 1) => value * V::from_u64(num)?,
+            (1, den) => value / V::from_u64(den)?,
+            (num, den) => value * V::from_u64(num)? / V::from_u64(den)?,
+        };
+
+        Some(out)
+    }
+
+    /// Multiply a value by this ratio, as with [`Self::apply`], but return
+    ///     `None` if the intermediate product overflows the value type.
+    ///
+    /// If the denominator is too large to be represented by the value type,
+    ///     the product must be smaller in magnitude than the denominator, so
+    ///     the result is truncated to zero rather than reported as overflow.
+    pub fn checked_apply<V: Value + CheckedMul + CheckedDiv>(self, value: V) -> Option<V> {
+        let product = match self.num {
+            1 => value,
+            num => value.checked_mul(&V::from_u64(num)?)?,
+        };
+
+        match self.den {
+            1 => Some(product),
+            den => match V::from_u64(den) {
+                Some(den) => product.checked_div(&den),
+                None => Some(V::zero()),
+            },
+        }
+    }
+}
+
+impl core::fmt::Display for ScaleRatio {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+
+/// Return the greatest common divisor of two integers.
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    if a == 0 { 1 } else { a }
+}
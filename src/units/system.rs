@@ -0,0 +1,96 @@
+//! Module for systems of measurement.
+//!
+//! Each system is represented by a marker type implementing [`UnitSystem`].
+//!     A unit type implementing [`InSystem`] for a system can be converted to
+//!     its nearest unit in that system, with [`Quantity::into_system`]:
+//! ```
+//! use dana::{prelude::*, units::system::*};
+//!
+//! let height = Length::CentiMeter.quantity(30.48);
+//! let height = height.into_system::<UsCustomary>();
+//! assert_eq!(height.unit, Length::Inch);
+//! assert!((height.value - 12.0f64).abs() < 1e-12);
+//!
+//! //  The system may also be chosen at runtime, such as from a setting.
+//! let system = MeasurementSystem::Si;
+//! assert_eq!(height.into_measurement_system(system).unit, Length::CentiMeter);
+//! ```
+//!
+//! [`Quantity::into_system`]: crate::Quantity::into_system
+
+pub use crate::units::traits::{InAnySystem, InSystem, UnitSystem};
+
+
+/// The [International System of Units](https://en.wikipedia.org/wiki/International_System_of_Units).
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Si;
+
+impl UnitSystem for Si {
+    const NAME: &'static str = "SI";
+}
+
+
+/// The [United States customary](https://en.wikipedia.org/wiki/United_States_customary_units)
+///     system.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct UsCustomary;
+
+impl UnitSystem for UsCustomary {
+    const NAME: &'static str = "US customary";
+}
+
+
+/// The British [Imperial](https://en.wikipedia.org/wiki/Imperial_units) system.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Imperial;
+
+impl UnitSystem for Imperial {
+    const NAME: &'static str = "Imperial";
+}
+
+
+/// The [centimetre-gram-second](https://en.wikipedia.org/wiki/Centimetre%E2%80%93gram%E2%80%93second_system_of_units)
+///     system.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Cgs;
+
+impl UnitSystem for Cgs {
+    const NAME: &'static str = "CGS";
+}
+
+
+/// A built-in system of measurement, chosen at runtime.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum MeasurementSystem {
+    /// The [SI](Si).
+    #[default]
+    Si,
+    /// The [US customary](UsCustomary) system.
+    UsCustomary,
+    /// The [Imperial](Imperial) system.
+    Imperial,
+    /// The [CGS](Cgs) system.
+    Cgs,
+}
+
+impl MeasurementSystem {
+    /// Every built-in system.
+    pub const ALL: [Self; 4] = [Self::Si, Self::UsCustomary, Self::Imperial, Self::Cgs];
+
+    /// Return the name of this system.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Si => Si::NAME,
+            Self::UsCustomary => UsCustomary::NAME,
+            Self::Imperial => Imperial::NAME,
+            Self::Cgs => Cgs::NAME,
+        }
+    }
+}
+
+impl core::fmt::Display for MeasurementSystem {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -5,6 +5,8 @@ use crate::{
     Quantity,
     units::{
         compound::*,
+        scale_ratio::ScaleRatio,
+        system::{Cgs, Imperial, MeasurementSystem, Si, UsCustomary},
         unit_anon::UnitAnon,
         unit_rescale::{Rescale, UnitRescale},
     },
@@ -17,6 +19,9 @@ pub use exponential::*;
 mod step;
 pub use step::*;
 
+mod system;
+pub use system::*;
+
 pub mod transform;
 pub use transform::*;
 
@@ -53,6 +58,16 @@ pub trait Unit: Copy + Default + core::fmt::Debug + core::fmt::Display + Partial
     fn scale_factor_v<U: Unit<Dim=Self::Dim>, V: Value>(self, target: U) -> Option<V> {
         V::from_f64(self.scale_factor(target))
     }
+
+    /// Return the scale of this unit as an exact ratio, relative to the base
+    ///     unit of this type, if it can be represented as one.
+    fn scale_ratio(&self) -> Option<ScaleRatio> { None }
+
+    /// Given a unit of the same dimension, return the exact ratio needed to
+    ///     scale from this unit to the other unit, if there is one.
+    fn scale_factor_ratio<U: Unit<Dim=Self::Dim>>(self, target: U) -> Option<ScaleRatio> {
+        self.scale_ratio()?.checked_div(target.scale_ratio()?)
+    }
     //endregion
 
     /// Return the base unit of this type, with a scale of 1.
@@ -159,6 +174,20 @@ pub trait Unit: Copy + Default + core::fmt::Debug + core::fmt::Display + Partial
 }
 
 
+/// Object-safe view of a [`Unit`] with the dimension `D`. This allows units of
+///     different types, such as [`Length`](crate::units::Length) and
+///     [`UnitRescale<Length>`](crate::units::UnitRescale), to be used together
+///     as `&dyn UnitAny<D>`.
+pub trait UnitAny<D: DimType>: core::fmt::Debug + core::fmt::Display {
+    /// Return the [scale](Unit::scale) of this unit.
+    fn scale_any(&self) -> f64;
+}
+
+impl<U: Unit> UnitAny<U::Dim> for U {
+    fn scale_any(&self) -> f64 { self.scale() }
+}
+
+
 /// A mixed unit type has variants that are SI units and variants that are not.
 pub trait UnitMixed: Unit {
     /// Return the SI unit nearest to this one.
@@ -183,12 +212,74 @@ pub trait UnitConcrete: Unit + UnitStep {
     /// The SI base unit has a scale of 1.
     const BASE: Self;
 
+    /// Every variant of this unit type, in ascending order of scale.
+    const VARIANTS: &'static [Self];
+
     /// Return a textual representation of this unit. Usually a base symbol with
     ///     an optional SI scaling prefix.
     fn symbol(&self) -> &'static str;
+
+    /// Find the unit with the given [symbol](Self::symbol), if any.
+    fn from_symbol(symbol: &str) -> Option<Self> {
+        Self::VARIANTS.iter().find(|unit| unit.symbol() == symbol).copied()
+    }
 }
 
 
+/// Trait for a [`Unit`] whose quantities can be added to quantities of another
+///     [`Unit`] type. This requires that both have the same dimension.
+#[diagnostic::on_unimplemented(
+    message = "cannot add `{Rhs}` to `{Self}`",
+    label = "`{Rhs}` does not have the same dimension as `{Self}`",
+    note = "quantities can only be added if their units have the same dimension",
+)]
+pub trait CanUnitAdd<Rhs: Unit>: Unit {}
+
+impl<A: Unit, B: Unit> CanUnitAdd<B> for A where
+    B::Dim: SameDimAdd<A::Dim, A, B>,
+{}
+
+
+/// Trait for a [`Unit`] whose quantities can be subtracted by quantities of
+///     another [`Unit`] type. This requires that both have the same dimension.
+#[diagnostic::on_unimplemented(
+    message = "cannot subtract `{Rhs}` from `{Self}`",
+    label = "`{Rhs}` does not have the same dimension as `{Self}`",
+    note = "quantities can only be subtracted if their units have the same dimension",
+)]
+pub trait CanUnitSub<Rhs: Unit>: Unit {}
+
+impl<A: Unit, B: Unit> CanUnitSub<B> for A where
+    B::Dim: SameDimSub<A::Dim, A, B>,
+{}
+
+
+/// Helper trait for [`CanUnitAdd`], implemented only for a dimension that is
+///     the same as `D`. The unit types `A` and `B` are not used, except to name
+///     them in compiler errors.
+#[diagnostic::on_unimplemented(
+    message = "cannot add `{B}` to `{A}`",
+    label = "`{B}` does not have the same dimension as `{A}`",
+    note = "quantities can only be added if their units have the same dimension",
+)]
+pub trait SameDimAdd<D: DimType, A, B>: DimType {}
+
+impl<D: DimType, A, B> SameDimAdd<D, A, B> for D {}
+
+
+/// Helper trait for [`CanUnitSub`], implemented only for a dimension that is
+///     the same as `D`. The unit types `A` and `B` are not used, except to name
+///     them in compiler errors.
+#[diagnostic::on_unimplemented(
+    message = "cannot subtract `{B}` from `{A}`",
+    label = "`{B}` does not have the same dimension as `{A}`",
+    note = "quantities can only be subtracted if their units have the same dimension",
+)]
+pub trait SameDimSub<D: DimType, A, B>: DimType {}
+
+impl<D: DimType, A, B> SameDimSub<D, A, B> for D {}
+
+
 /// Trait for a type that, when inverted, yields a [`Unit`] type.
 pub trait CanUnitInv: Unit {
     /// The [`Dimension`](crate::dimension::Dimension) of the output type.
@@ -206,6 +297,10 @@ impl<U: Unit> CanUnitInv for U where
 
 
 /// Trait for a type that, when divided, yields a [`Unit`] type.
+#[diagnostic::on_unimplemented(
+    message = "cannot divide `{Self}` by `{U}`",
+    label = "no dimension for `{Self} / {U}`",
+)]
 pub trait CanUnitDiv<U>: Unit {
     /// The [`Dimension`](crate::dimension::Dimension) of the output type.
     type DimOut: DimType;
@@ -222,6 +317,10 @@ impl<A: Unit, B: Unit> CanUnitDiv<B> for A where
 
 
 /// Trait for a type that, when multiplied, yields a [`Unit`] type.
+#[diagnostic::on_unimplemented(
+    message = "cannot multiply `{Self}` by `{U}`",
+    label = "no dimension for `{Self} * {U}`",
+)]
 pub trait CanUnitMul<U>: Unit {
     /// The [`Dimension`](crate::dimension::Dimension) of the output type.
     type DimOut: DimType;
@@ -4,7 +4,7 @@ use super::*;
 /// This trait defines the ability to "step" a [`Unit`] type up and down. This
 ///     allows a [`Quantity`] to automatically [normalize](Quantity::normalize)
 ///     its value.
-pub trait UnitStep: Unit {
+pub trait UnitStep: Unit + 'static {
     /// Return the next unit down in the scale, or `None` if this is already the
     ///     smallest variant.
     //  NOTE: It is an error for this method to return the same unit as `self`,
@@ -30,4 +30,62 @@ pub trait UnitStep: Unit {
         while let Some(next) = unit.step_up() { unit = next; }
         unit
     }
+
+    /// Return every unit in the same scale as this one, sorted by ascending
+    ///     scale factor, if such a table is available.
+    ///
+    /// The table must contain `self`, and must list exactly the units that can
+    ///     be reached from `self` by [stepping down](Self::step_down) and
+    ///     [stepping up](Self::step_up). Units that are not part of any table
+    ///     may return `None`, in which case stepping will be used instead.
+    fn step_table(&self) -> Option<&'static [Self]> { None }
+
+    /// Return every unit of this type, if there is a fixed list of them, such
+    ///     as the [variants](super::UnitConcrete::VARIANTS) of a concrete unit.
+    fn variants() -> Option<&'static [Self]> { None }
+
+    /// Find the largest unit in the scale with a scale factor not greater than
+    ///     the one given. If there is no such unit, the smallest unit in the
+    ///     scale is returned instead.
+    ///
+    /// If a [table](Self::step_table) is available, this is a binary search.
+    ///     Otherwise, the unit is stepped up or down one variant at a time.
+    fn step_to_floor(&self, scale: f64) -> Self {
+        if let Some(table) = self.step_table() {
+            let i = table.partition_point(|unit| unit.scale() <= scale);
+            return table[i.saturating_sub(1)];
+        }
+
+        let mut unit = *self;
+
+        while unit.scale() > scale {
+            match unit.step_down() {
+                Some(next) => unit = next,
+                None => return unit,
+            }
+        }
+
+        while let Some(next) = unit.step_up() {
+            if next.scale() <= scale {
+                unit = next;
+            } else {
+                break;
+            }
+        }
+
+        unit
+    }
+
+    /// Find the smallest unit in the scale with a scale factor not less than
+    ///     the one given. If there is no such unit, the largest unit in the
+    ///     scale is returned instead.
+    fn step_to_ceil(&self, scale: f64) -> Self {
+        let unit = self.step_to_floor(scale);
+
+        if unit.scale() < scale {
+            unit.step_up().unwrap_or(unit)
+        } else {
+            unit
+        }
+    }
 }
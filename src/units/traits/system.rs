@@ -0,0 +1,46 @@
+use super::*;
+
+
+/// Trait for a marker type representing a system of measurement, such as the
+///     [SI](crate::units::system::Si) or the
+///     [US customary](crate::units::system::UsCustomary) system.
+pub trait UnitSystem: Copy + Default + core::fmt::Debug + 'static {
+    /// Name of this system.
+    const NAME: &'static str;
+}
+
+
+/// Trait for a [`Unit`] type that can be expressed in a [system](UnitSystem)
+///     of measurement.
+///
+/// Unit types with no units specific to a system use their SI units in it.
+pub trait InSystem<S: UnitSystem>: Unit {
+    /// Return the unit in the system nearest to this one.
+    fn to_system(&self) -> Self;
+
+    /// Return `true` if this unit is part of the system.
+    fn is_in_system(&self) -> bool {
+        self.to_system().eq(self)
+    }
+}
+
+
+/// Trait for a [`Unit`] type that can be expressed in every built-in system of
+///     measurement, so that the system can be chosen at runtime.
+pub trait InAnySystem:
+    InSystem<Si> + InSystem<UsCustomary> + InSystem<Imperial> + InSystem<Cgs>
+{
+    /// Return the unit in a system, chosen at runtime, nearest to this one.
+    fn to_measurement_system(&self, system: MeasurementSystem) -> Self {
+        match system {
+            MeasurementSystem::Si => InSystem::<Si>::to_system(self),
+            MeasurementSystem::UsCustomary => InSystem::<UsCustomary>::to_system(self),
+            MeasurementSystem::Imperial => InSystem::<Imperial>::to_system(self),
+            MeasurementSystem::Cgs => InSystem::<Cgs>::to_system(self),
+        }
+    }
+}
+
+impl<U> InAnySystem for U where
+    U: InSystem<Si> + InSystem<UsCustomary> + InSystem<Imperial> + InSystem<Cgs>,
+{}
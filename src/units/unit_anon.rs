@@ -1,8 +1,12 @@
 //! Module for the anonymous unit type.
 
-use core::{marker::PhantomData, ops::{Div, Mul}};
+use core::{hash::{Hash, Hasher}, marker::PhantomData, ops::{Div, Mul}};
 use num_traits::{AsPrimitive, Inv, real::Real};
-use crate::{dimension::*, units::traits::*, Value};
+use crate::{
+    dimension::*,
+    units::{concrete::{visit_concrete, ConcreteVisitor}, traits::*},
+    Value,
+};
 
 
 dummy!(
@@ -19,7 +23,7 @@ dummy!(
 ///     an arbitrary scaling factor. This may be useful in situations where an
 ///     extremely complex unit tree needs to be used in a large number of
 ///     operations that all calculate its scale factor.
-#[derive(Clone, Copy, Hash, Eq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Eq, Ord, PartialOrd)]
 #[repr(transparent)]
 pub struct UnitAnon<D: DimType, S: AnonScale = f64>(pub S, PhantomData<D>);
 
@@ -43,6 +47,12 @@ impl<D: DimType, S: AnonScale> PartialEq for UnitAnon<D, S> {
     fn eq(&self, other: &Self) -> bool { self.0.eq(&other.0) }
 }
 
+impl<D: DimType, S: AnonScale + Hash> Hash for UnitAnon<D, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
 impl<D: DimType, S: AnonScale> Unit for UnitAnon<D, S> {
     type Dim = D;
     // type ScaleType = S;
@@ -54,14 +64,52 @@ impl<D: DimType, S: AnonScale> Unit for UnitAnon<D, S> {
 
 impl<D: DimType, S: AnonScale> core::fmt::Debug for UnitAnon<D, S> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "UnitAnon({:?}, \"{}\")", self.0, self.dimension())
+        write!(f, "UnitAnon({:?}, \"{}\")", self.0, NamedDim::of::<D>())
     }
 }
 
+/// If the scale of the unit matches a variant of a [concrete unit](UnitConcrete)
+///     with the same dimension, it is displayed with the symbol of that variant.
+///     Otherwise, it is displayed as its scaling factor.
 impl<D: DimType, S: AnonScale> core::fmt::Display for UnitAnon<D, S> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "*{}", self.0)
+        match concrete_symbol(DynDimension::of::<D>(), self.scale()) {
+            Some(symbol) => f.write_str(symbol),
+            None => write!(f, "*{}", self.0),
+        }
+    }
+}
+
+
+/// Relative tolerance within which two scale factors are considered equal.
+pub(crate) const SCALE_TOLERANCE: f64 = 1e-12;
+
+
+/// Return `true` if two scale factors are equal, to within [`SCALE_TOLERANCE`].
+pub(crate) fn same_scale(a: f64, b: f64) -> bool {
+    Real::abs(a - b) <= Real::abs(a).max(Real::abs(b)) * SCALE_TOLERANCE
+}
+
+
+/// Find the symbol of a concrete unit variant with the given dimension and
+///     scale, if there is one. The unit types searched are generated by
+///     `concrete_types!`.
+fn concrete_symbol(dim: DynDimension, scale: f64) -> Option<&'static str> {
+    struct FindSymbol(DynDimension, f64);
+
+    impl ConcreteVisitor for FindSymbol {
+        type Output = &'static str;
+
+        fn visit<U: UnitConcrete>(&mut self) -> Option<&'static str> {
+            if self.0 != DynDimension::of::<U::Dim>() { return None; }
+
+            U::VARIANTS.iter()
+                .find(|unit| same_scale(unit.scale(), self.1))
+                .map(|unit| unit.symbol())
+        }
     }
+
+    visit_concrete(&mut FindSymbol(dim, scale))
 }
 
 
@@ -105,7 +153,7 @@ impl<D: DimType, S: AnonScale> Inv for UnitAnon<D, S> where
 
 impl<D: DimType, S: AnonScale, const E: i32> CanPow<E> for UnitAnon<D, S> where
     Exponent<E>: HasTypenum,
-    D: CanDimPowType<<Exponent<E> as HasTypenum>::Typenum>,
+    D: CanDimPowRatio<<Exponent<E> as HasTypenum>::Typenum, typenum::P1>,
 {
     type Output = crate::units::UnitPow<Self, <Exponent<E> as HasTypenum>::Typenum>;
 
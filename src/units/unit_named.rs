@@ -0,0 +1,134 @@
+//! Module for the named unit type.
+
+use core::ops::{Div, Mul};
+use num_traits::Inv;
+use typenum::{NonZero, P1};
+use crate::{dimension::*, units::{compound::*, traits::*, ScaleRatio, UnitRescale}};
+use super::unit_rescale::Rescale;
+
+mod serde;
+
+#[cfg(feature = "serde")]
+pub use self::serde::{NamedQtySeed, NamedSeed};
+
+
+/// A unit with a custom symbol and name, defined in terms of another unit.
+///
+/// This allows units specific to a single application, such as a "pallet" or
+///     a "shot", to be defined at runtime without writing a new unit type. The
+///     underlying unit is usually a [`UnitRescale`]:
+/// ```
+/// use dana::{prelude::*, units::UnitNamed};
+///
+/// const FURLONG: UnitNamed<UnitRescale<Length>> =
+///     UnitRescale::new(Length::Meter, 201.168).named("fur", "furlong");
+///
+/// let race = FURLONG.quantity(8.0);
+/// assert_eq!(race.to_string(), "8 fur");
+/// assert_eq!(race.value_as(Length::KiloMeter), 1.609_344);
+/// assert_eq!(Length::Mile.quantity(1.0).convert_to(FURLONG).value, 8.0);
+/// ```
+///
+/// With the `serde` feature, the symbol and name are serialized alongside the
+///     underlying unit. Because they are `&'static str`, a named unit is
+///     deserialized by looking up its symbol in a list of units, with a
+///     [`NamedSeed`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+pub struct UnitNamed<U: Unit> {
+    /// The unit this one is defined as.
+    pub unit: U,
+    /// Symbol of this unit, used to display and parse it.
+    pub symbol: &'static str,
+    /// Full name of this unit.
+    pub name: &'static str,
+}
+
+impl<U: Unit> UnitNamed<U> {
+    /// Construct a new [`UnitNamed`] from another unit, with a symbol and name.
+    pub const fn new(unit: U, symbol: &'static str, name: &'static str) -> Self {
+        Self { unit, symbol, name }
+    }
+
+    /// Find the unit with the given symbol or name in a list of units, if any.
+    ///     Symbols are checked before names.
+    pub fn find(units: &[Self], symbol: &str) -> Option<Self> {
+        units.iter().find(|unit| unit.symbol == symbol)
+            .or_else(|| units.iter().find(|unit| unit.name == symbol))
+            .copied()
+    }
+}
+
+impl<U: Unit, S: Rescale> UnitRescale<U, S> {
+    /// Give this unit a symbol and name.
+    pub const fn named(self, symbol: &'static str, name: &'static str) -> UnitNamed<Self> {
+        UnitNamed::new(self, symbol, name)
+    }
+}
+
+/// The default named unit is the base of the underlying unit type, with an
+///     empty symbol and name.
+impl<U: Unit> Default for UnitNamed<U> {
+    fn default() -> Self { Self::new(U::base(), "", "") }
+}
+
+impl<U: Unit> Unit for UnitNamed<U> {
+    type Dim = U::Dim;
+
+    fn scale(&self) -> f64 { self.unit.scale() }
+    fn scale_ratio(&self) -> Option<ScaleRatio> { self.unit.scale_ratio() }
+}
+
+impl<U: Unit> core::fmt::Display for UnitNamed<U> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.symbol)
+    }
+}
+
+
+//region Unit operations.
+impl<U: Unit, W: Unit> Div<W> for UnitNamed<U> where
+    U::Dim: Div<W::Dim>,
+    <U::Dim as Div<W::Dim>>::Output: DimType,
+{
+    type Output = UnitDiv<Self, W>;
+    fn div(self, rhs: W) -> Self::Output { UnitDiv::new(self, rhs) }
+}
+
+
+impl<U: Unit, W: Unit> Mul<W> for UnitNamed<U> where
+    U::Dim: Mul<W::Dim>,
+    <U::Dim as Mul<W::Dim>>::Output: DimType,
+{
+    type Output = UnitMul<Self, W>;
+    fn mul(self, rhs: W) -> Self::Output { UnitMul::new(self, rhs) }
+}
+
+
+impl<U: Unit> Inv for UnitNamed<U> where
+    U::Dim: Inv, <U::Dim as Inv>::Output: DimType,
+{
+    type Output = PerUnit<Self>;
+    fn inv(self) -> Self::Output { PerUnit::new(self) }
+}
+
+
+impl<U: Unit, const E: i32> CanPow<E> for UnitNamed<U> where
+    Exponent<E>: HasTypenum,
+    U::Dim: CanDimPowRatio<<Exponent<E> as HasTypenum>::Typenum, P1>,
+{
+    type Output = UnitPow<Self, <Exponent<E> as HasTypenum>::Typenum>;
+    fn pow(self) -> Self::Output { UnitPow::new(self) }
+}
+
+
+impl<U: Unit, const D: i32> CanRoot<D> for UnitNamed<U> where
+    Exponent<D>: HasTypenum,
+    <Exponent<D> as HasTypenum>::Typenum: NonZero,
+    P1: ReduceExp<<Exponent<D> as HasTypenum>::Typenum>,
+    UnitRootN<Self, D>: Unit,
+{
+    type Output = UnitRootN<Self, D>;
+    fn root(self) -> Self::Output { Self::Output::new(self) }
+}
+//endregion
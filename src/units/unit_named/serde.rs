@@ -0,0 +1,164 @@
+#![cfg(feature = "serde")]
+
+use core::marker::PhantomData;
+use serde::{
+    de::{DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Unexpected, Visitor},
+    Deserialize, Deserializer,
+};
+use crate::{units::UnitNamed, Quantity, Unit, Value};
+
+
+/// Seed for deserializing a [`UnitNamed`] by looking up its symbol in a list of
+///     units, with [`UnitNamed::find`].
+///
+/// Only the symbol is read, so that units can be deserialized from data that
+///     does not live for `'static`, such as a `String`. The underlying unit and
+///     name are taken from the list, rather than from the serialized data.
+/// ```
+/// use dana::{prelude::*, units::unit_named::NamedSeed};
+/// use serde::de::DeserializeSeed;
+///
+/// let units = [
+///     UnitRescale::new(Length::Meter, 201.168).named("fur", "furlong"),
+///     UnitRescale::new(Length::Meter, 20.1168).named("ch", "chain"),
+/// ];
+///
+/// let json = String::from(r#"{"value":8.0,"unit":{"symbol":"fur"}}"#);
+/// let mut de = serde_json::Deserializer::from_str(&json);
+/// let race = NamedSeed(&units).quantity::<f64>().deserialize(&mut de).unwrap();
+/// assert_eq!(race, units[0].quantity(8.0));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NamedSeed<'a, U: Unit>(pub &'a [UnitNamed<U>]);
+
+impl<'a, U: Unit> NamedSeed<'a, U> {
+    /// Return a seed for deserializing a [`Quantity`] of one of these units.
+    pub const fn quantity<V: Value>(self) -> NamedQtySeed<'a, U, V> {
+        NamedQtySeed(self, PhantomData)
+    }
+}
+
+impl<'de, U: Unit> DeserializeSeed<'de> for NamedSeed<'_, U> {
+    type Value = UnitNamed<U>;
+
+    fn deserialize<D: Deserializer<'de>>(self, d: D) -> Result<UnitNamed<U>, D::Error> {
+        d.deserialize_struct("UnitNamed", &["unit", "symbol", "name"], self)
+    }
+}
+
+impl<'de, U: Unit> Visitor<'de> for NamedSeed<'_, U> {
+    type Value = UnitNamed<U>;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("a named unit")
+    }
+
+    fn visit_str<E: Error>(self, symbol: &str) -> Result<Self::Value, E> {
+        UnitNamed::find(self.0, symbol).ok_or_else(|| E::invalid_value(
+            Unexpected::Str(symbol),
+            &"the symbol of a known unit",
+        ))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut unit = None;
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                FieldUnit::Symbol => unit = Some(map.next_value_seed(Symbol(self))?),
+                FieldUnit::Other => { map.next_value::<IgnoredAny>()?; }
+            }
+        }
+
+        unit.ok_or_else(|| A::Error::missing_field("symbol"))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        seq.next_element::<IgnoredAny>()?;
+        let unit = seq.next_element_seed(Symbol(self))?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        seq.next_element::<IgnoredAny>()?;
+
+        Ok(unit)
+    }
+}
+
+
+/// Seed for deserializing the symbol field of a [`UnitNamed`].
+struct Symbol<'a, U: Unit>(NamedSeed<'a, U>);
+
+impl<'de, U: Unit> DeserializeSeed<'de> for Symbol<'_, U> {
+    type Value = UnitNamed<U>;
+
+    fn deserialize<D: Deserializer<'de>>(self, d: D) -> Result<UnitNamed<U>, D::Error> {
+        d.deserialize_str(self.0)
+    }
+}
+
+
+/// Seed for deserializing a [`Quantity`] of a [`UnitNamed`], by looking up the
+///     symbol of its unit. This is returned by [`NamedSeed::quantity`].
+#[derive(Clone, Copy, Debug)]
+pub struct NamedQtySeed<'a, U: Unit, V: Value>(NamedSeed<'a, U>, PhantomData<V>);
+
+impl<'de, U: Unit, V: Value + Deserialize<'de>> DeserializeSeed<'de> for NamedQtySeed<'_, U, V> {
+    type Value = Quantity<UnitNamed<U>, V>;
+
+    fn deserialize<D: Deserializer<'de>>(self, d: D) -> Result<Quantity<UnitNamed<U>, V>, D::Error> {
+        d.deserialize_struct("Quantity", &["value", "unit"], self)
+    }
+}
+
+impl<'de, U: Unit, V: Value + Deserialize<'de>> Visitor<'de> for NamedQtySeed<'_, U, V> {
+    type Value = Quantity<UnitNamed<U>, V>;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("a quantity of a named unit")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut value = None;
+        let mut unit = None;
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                FieldQty::Value => value = Some(map.next_value()?),
+                FieldQty::Unit => unit = Some(map.next_value_seed(self.0)?),
+                FieldQty::Other => { map.next_value::<IgnoredAny>()?; }
+            }
+        }
+
+        let value = value.ok_or_else(|| A::Error::missing_field("value"))?;
+        let unit = unit.ok_or_else(|| A::Error::missing_field("unit"))?;
+        Ok(Quantity::new(unit, value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let value = seq.next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let unit = seq.next_element_seed(self.0)?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+
+        Ok(Quantity::new(unit, value))
+    }
+}
+
+
+/// Helper enum for the fields of a serialized [`UnitNamed`].
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum FieldUnit {
+    Symbol,
+    #[serde(other)]
+    Other,
+}
+
+/// Helper enum for the fields of a serialized [`Quantity`].
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum FieldQty {
+    Value,
+    Unit,
+    #[serde(other)]
+    Other,
+}
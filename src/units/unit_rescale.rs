@@ -2,7 +2,7 @@
 
 use core::ops::{Div, Mul};
 use num_traits::{AsPrimitive, Inv, real::Real};
-use crate::{dimension::*, units::{compound::*, traits::*}, Value};
+use crate::{dimension::*, units::{compound::*, traits::*, ScaleRatio}, Value};
 
 
 dummy!(
@@ -14,6 +14,7 @@ dummy!(
 
 /// A rescaled unit.
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct UnitRescale<U: Unit, S: Rescale = f64>(pub U, pub S);
 
 impl<U: Unit, S: Rescale> UnitRescale<U, S> {
@@ -30,6 +31,18 @@ impl<U: Unit, S: Rescale> Unit for UnitRescale<U, S> {
     // type ScaleType = S;
 
     fn scale(&self) -> f64 { self.0.scale() * self.1.as_() }
+
+    /// An exact ratio is only available when the scaling factor is a whole
+    ///     number.
+    fn scale_ratio(&self) -> Option<ScaleRatio> {
+        let factor = self.1.to_u64()?;
+
+        if S::from_u64(factor)? == self.1 {
+            self.0.scale_ratio()?.checked_mul(ScaleRatio::int(factor))
+        } else {
+            None
+        }
+    }
 }
 
 impl<U: Unit, S: Rescale> core::fmt::Display for UnitRescale<U, S> {
@@ -72,7 +85,7 @@ impl<U: Unit, S: Rescale> Inv for UnitRescale<U, S> where
 
 impl<U: Unit, S: Rescale, const E: i32> CanPow<E> for UnitRescale<U, S> where
     Exponent<E>: HasTypenum,
-    U::Dim: CanDimPowType<<Exponent<E> as HasTypenum>::Typenum>,
+    U::Dim: CanDimPowRatio<<Exponent<E> as HasTypenum>::Typenum, typenum::P1>,
 {
     type Output = UnitPow<Self, <Exponent<E> as HasTypenum>::Typenum>;
     fn pow(self) -> Self::Output { UnitPow::new(self) }
@@ -100,4 +113,8 @@ impl<U: UnitStep, S: Rescale> UnitStep for UnitRescale<U, S> {
     fn step_up(&self) -> Option<Self> {
         Some(Self::new(self.0.step_up()?, self.1))
     }
+
+    fn step_to_floor(&self, scale: f64) -> Self {
+        Self::new(self.0.step_to_floor(scale / self.1.as_()), self.1)
+    }
 }
@@ -1,7 +1,16 @@
 //! Module for the [`Value`] marker trait.
 
-use core::fmt::{Debug, Display};
-use num_traits::{FromPrimitive, Num, NumCast};
+use core::{cmp::Ordering, fmt::{Debug, Display}, hash::{Hash, Hasher}};
+use num_traits::{float::TotalOrder, FromPrimitive, Num, NumCast};
+
+pub mod complex;
+pub mod dual;
+pub mod fixed;
+pub mod interval;
+pub use complex::Complex;
+pub use dual::Dual;
+pub use fixed::{Fixed32, Fixed64};
+pub use interval::Interval;
 
 
 dummy! {
@@ -25,6 +34,78 @@ dummy! {
 }
 
 
+/// Trait for a [`Value`] type with a total order, and a hash consistent with
+///     that order. This allows [quantities](crate::Quantity) to be compared
+///     and hashed with [`TotalQty`](crate::quantity::TotalQty).
+pub trait TotalValue: Value {
+    /// Return the ordering between this value and another. Every pair of
+    ///     values must be ordered, including values such as NaN.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Feed this value into a hasher. Values that are equal according to
+    ///     [`Self::total_cmp`] must produce the same hash.
+    fn hash_canonical<H: Hasher>(&self, state: &mut H);
+}
+
+/// Trait for a [`Value`] type that can measure the distance between two values
+///     in units in the last place (ULPs).
+pub trait UlpDistance: Value {
+    /// Return the number of representable values between this value and
+    ///     another, or `None` if either is NaN. Positive and negative zero are
+    ///     zero ULPs apart.
+    fn ulp_distance(&self, other: &Self) -> Option<u64>;
+}
+
+macro_rules! impl_float {
+    ($($t:ty => $i:ty),+$(,)?) => {$(
+    /// Negative zero is treated as equal to positive zero, and every NaN is
+    ///     treated as the same positive NaN. Otherwise, values are ordered by
+    ///     the IEEE 754 `totalOrder` predicate.
+    impl TotalValue for $t {
+        fn total_cmp(&self, other: &Self) -> Ordering {
+            TotalOrder::total_cmp(&canonical(*self), &canonical(*other))
+        }
+
+        fn hash_canonical<H: Hasher>(&self, state: &mut H) {
+            canonical(*self).to_bits().hash(state);
+        }
+    }
+
+    impl UlpDistance for $t {
+        fn ulp_distance(&self, other: &Self) -> Option<u64> {
+            if self.is_nan() || other.is_nan() {
+                return None;
+            }
+
+            //  Map the bits onto a scale where adjacent floats are adjacent
+            //      integers, with both zeros at zero.
+            let key = |v: $t| {
+                let bits = v.to_bits() as $i;
+                if bits < 0 { <$i>::MIN - bits } else { bits }
+            };
+
+            Some((key(*self) as i128 - key(*other) as i128).unsigned_abs() as u64)
+        }
+    }
+    )+};
+}
+
+impl_float!(f32 => i32, f64 => i64);
+
+
+/// Replace negative zero with positive zero, and any NaN with the standard
+///     positive NaN.
+fn canonical<F: num_traits::float::FloatCore>(value: F) -> F {
+    if value.is_nan() {
+        F::nan()
+    } else if value.is_zero() {
+        F::zero()
+    } else {
+        value
+    }
+}
+
+
 /// Convert an `f64` to any [`Value`] type.
 ///
 /// TODO: This function is a stand-in to concentrate every conversion in one
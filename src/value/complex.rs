@@ -0,0 +1,353 @@
+//! Module for the [`Complex`] value type.
+
+use core::{
+    fmt::{Display, Formatter},
+    ops::{
+        Add, AddAssign,
+        Div, DivAssign,
+        Mul, MulAssign,
+        Neg,
+        Rem, RemAssign,
+        Sub, SubAssign,
+    },
+};
+use num_traits::{
+    FromPrimitive,
+    Inv,
+    Num,
+    NumCast,
+    One,
+    real::Real,
+    ToPrimitive,
+    Zero,
+};
+use crate::error::ParseComplexError;
+
+
+/// A complex number in rectangular form, `re + im*i`.
+///
+/// This type implements [`Value`](crate::Value) when its component type is
+///     [`Real`], so it can be used as the value of a [`Quantity`]. This allows
+///     the representation of phasors, such as AC voltages, and of impedances.
+///
+/// [`Quantity`]: crate::Quantity
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Complex<T> {
+    /// Real part.
+    pub re: T,
+    /// Imaginary part.
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    /// Construct a new complex number from real and imaginary parts.
+    pub const fn new(re: T, im: T) -> Self { Self { re, im } }
+}
+
+impl<T: Clone + Num> Complex<T> {
+    /// Return the imaginary unit, `i`.
+    pub fn i() -> Self { Self::new(T::zero(), T::one()) }
+
+    /// Construct a new complex number with no imaginary part.
+    pub fn real(re: T) -> Self { Self::new(re, T::zero()) }
+
+    /// Return the square of the magnitude of this number.
+    pub fn norm_sqr(&self) -> T {
+        self.re.clone() * self.re.clone() + self.im.clone() * self.im.clone()
+    }
+
+    /// Multiply both parts of this number by a real value.
+    pub fn scale(&self, t: T) -> Self {
+        Self::new(self.re.clone() * t.clone(), self.im.clone() * t)
+    }
+
+    /// Divide both parts of this number by a real value.
+    pub fn unscale(&self, t: T) -> Self {
+        Self::new(self.re.clone() / t.clone(), self.im.clone() / t)
+    }
+}
+
+impl<T: Clone + Num + Neg<Output=T>> Complex<T> {
+    /// Return the complex conjugate of this number.
+    pub fn conj(&self) -> Self { Self::new(self.re.clone(), -self.im.clone()) }
+}
+
+impl<T: Real> Complex<T> {
+    /// Construct a new complex number from a magnitude and a phase angle in
+    ///     radians.
+    pub fn from_polar(r: T, theta: T) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Self::new(r * cos, r * sin)
+    }
+
+    /// Return the magnitude of this number.
+    pub fn norm(&self) -> T { self.re.hypot(self.im) }
+
+    /// Return the phase angle of this number, in radians, in the range
+    ///     `(-π, π]`.
+    pub fn arg(&self) -> T { self.im.atan2(self.re) }
+
+    /// Return the magnitude and phase angle of this number.
+    pub fn to_polar(&self) -> (T, T) { (self.norm(), self.arg()) }
+}
+
+impl<T: Clone + Num + Neg<Output=T>> Inv for Complex<T> {
+    type Output = Self;
+
+    fn inv(self) -> Self::Output {
+        let norm_sqr = self.norm_sqr();
+        self.conj().unscale(norm_sqr)
+    }
+}
+
+impl<T: Display + Num + Neg<Output=T> + PartialOrd + Clone> Display for Complex<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        <T as Display>::fmt(&self.re, f)?;
+
+        if self.im < T::zero() {
+            f.write_str("-")?;
+            <T as Display>::fmt(&-self.im.clone(), f)?;
+        } else {
+            f.write_str("+")?;
+            <T as Display>::fmt(&self.im, f)?;
+        }
+
+        f.write_str("i")
+    }
+}
+
+
+//region Arithmetic between complex numbers.
+impl<T: Clone + Num> Add for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Clone + Num> Sub for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: Clone + Num> Mul for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let re = self.re.clone() * rhs.re.clone() - self.im.clone() * rhs.im.clone();
+        let im = self.re * rhs.im + self.im * rhs.re;
+        Self::new(re, im)
+    }
+}
+
+impl<T: Clone + Num> Div for Complex<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let norm_sqr = rhs.norm_sqr();
+        let re = self.re.clone() * rhs.re.clone() + self.im.clone() * rhs.im.clone();
+        let im = self.im * rhs.re - self.re * rhs.im;
+        Self::new(re / norm_sqr.clone(), im / norm_sqr)
+    }
+}
+
+/// The remainder is taken after truncating both parts of the quotient toward
+///     zero, giving a Gaussian integer multiple of the divisor.
+impl<T: Real> Rem for Complex<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        let Complex { re, im } = self / rhs;
+        self - rhs * Self::new(re.trunc(), im.trunc())
+    }
+}
+
+impl<T: Clone + Num + Neg<Output=T>> Neg for Complex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output { Self::new(-self.re, -self.im) }
+}
+//endregion
+
+
+//region Arithmetic between complex and real numbers.
+impl<T: Clone + Num> Add<T> for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: T) -> Self::Output { Self::new(self.re + rhs, self.im) }
+}
+
+impl<T: Clone + Num> Sub<T> for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: T) -> Self::Output { Self::new(self.re - rhs, self.im) }
+}
+
+impl<T: Clone + Num> Mul<T> for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output { self.scale(rhs) }
+}
+
+impl<T: Clone + Num> Div<T> for Complex<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output { self.unscale(rhs) }
+}
+
+impl<T: Real> Rem<T> for Complex<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output { self % Self::real(rhs) }
+}
+
+macro_rules! impl_real_lhs {
+    ($($t:ty),+$(,)?) => {$(
+    impl Add<Complex<$t>> for $t {
+        type Output = Complex<$t>;
+
+        fn add(self, rhs: Complex<$t>) -> Self::Output { rhs + self }
+    }
+
+    impl Sub<Complex<$t>> for $t {
+        type Output = Complex<$t>;
+
+        fn sub(self, rhs: Complex<$t>) -> Self::Output { Complex::real(self) - rhs }
+    }
+
+    impl Mul<Complex<$t>> for $t {
+        type Output = Complex<$t>;
+
+        fn mul(self, rhs: Complex<$t>) -> Self::Output { rhs * self }
+    }
+
+    impl Div<Complex<$t>> for $t {
+        type Output = Complex<$t>;
+
+        fn div(self, rhs: Complex<$t>) -> Self::Output { Complex::real(self) / rhs }
+    }
+    )+};
+}
+
+impl_real_lhs!(f32, f64);
+//endregion
+
+
+//region Assignment operators.
+macro_rules! impl_assign {
+    ($($op:ident::$method:ident => $base:ident::$base_method:ident),+$(,)?) => {$(
+    impl<T: Clone> $op for Complex<T> where Self: $base<Output=Self> {
+        fn $method(&mut self, rhs: Self) {
+            *self = self.clone().$base_method(rhs);
+        }
+    }
+
+    impl<T: Clone> $op<T> for Complex<T> where Self: $base<T, Output=Self> {
+        fn $method(&mut self, rhs: T) {
+            *self = self.clone().$base_method(rhs);
+        }
+    }
+    )+};
+}
+
+impl_assign!(
+    AddAssign::add_assign => Add::add,
+    SubAssign::sub_assign => Sub::sub,
+    MulAssign::mul_assign => Mul::mul,
+    DivAssign::div_assign => Div::div,
+    RemAssign::rem_assign => Rem::rem,
+);
+//endregion
+
+
+//region Numeric traits.
+impl<T: Clone + Num> Zero for Complex<T> {
+    fn zero() -> Self { Self::new(T::zero(), T::zero()) }
+
+    fn is_zero(&self) -> bool { self.re.is_zero() && self.im.is_zero() }
+}
+
+impl<T: Clone + Num> One for Complex<T> {
+    fn one() -> Self { Self::new(T::one(), T::zero()) }
+}
+
+impl<T: Real> Num for Complex<T> where
+    T::FromStrRadixErr: core::fmt::Debug,
+{
+    type FromStrRadixErr = ParseComplexError<T::FromStrRadixErr>;
+
+    /// Parse a complex number in one of the forms `a`, `bi`, `a+bi`, or
+    ///     `a-bi`. The `i` may also be written as `j`.
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let str = str.trim();
+
+        let parse = |s: &str| T::from_str_radix(s, radix).map_err(ParseComplexError::Part);
+        let parse_im = |s: &str| match s {
+            "" | "+" => Ok(T::one()),
+            "-" => Ok(-T::one()),
+            s => parse(s),
+        };
+
+        let Some(body) = str.strip_suffix(['i', 'j']) else {
+            return Ok(Self::real(parse(str)?));
+        };
+
+        //  Find the sign separating the parts, skipping a leading sign and any
+        //      sign that belongs to an exponent.
+        let split = body.char_indices().rev().find(|&(n, c)| {
+            n > 0
+                && (c == '+' || c == '-')
+                && !(radix <= 10 && body[..n].ends_with(['e', 'E']))
+        });
+
+        match split {
+            Some((n, _)) => Ok(Self::new(parse(&body[..n])?, parse_im(&body[n..])?)),
+            None => Ok(Self::new(T::zero(), parse_im(body)?)),
+        }
+    }
+}
+
+impl<T: ToPrimitive + Zero> ToPrimitive for Complex<T> {
+    fn to_i64(&self) -> Option<i64> {
+        if self.im.is_zero() { self.re.to_i64() } else { None }
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        if self.im.is_zero() { self.re.to_u64() } else { None }
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        if self.im.is_zero() { self.re.to_f64() } else { None }
+    }
+}
+
+impl<T: NumCast + Zero> NumCast for Complex<T> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        Some(Self::new(T::from(n)?, T::zero()))
+    }
+}
+
+impl<T: FromPrimitive + Zero> FromPrimitive for Complex<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::new(T::from_i64(n)?, T::zero()))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::new(T::from_u64(n)?, T::zero()))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(Self::new(T::from_f64(n)?, T::zero()))
+    }
+}
+
+impl<T: Clone + Num> From<T> for Complex<T> {
+    fn from(re: T) -> Self { Self::real(re) }
+}
+//endregion
+
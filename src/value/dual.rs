@@ -0,0 +1,438 @@
+//! Module for the [`Dual`] value type, used for automatic differentiation.
+
+use core::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    ops::{
+        Add, AddAssign,
+        Div, DivAssign,
+        Mul, MulAssign,
+        Neg,
+        Rem, RemAssign,
+        Sub, SubAssign,
+    },
+};
+use num_traits::{
+    FromPrimitive,
+    Inv,
+    Num,
+    NumCast,
+    One,
+    real::Real,
+    ToPrimitive,
+    Zero,
+};
+
+
+/// A dual number, `re + eps*ε`, where `ε² = 0`.
+///
+/// Evaluating a function with a dual number whose `eps` part is one produces
+///     the result of the function in the `re` part, and its derivative in the
+///     `eps` part. This is known as forward-mode automatic differentiation.
+///
+/// This type implements [`Value`](crate::Value) when its component type is
+///     [`Real`], so it can be used as the value of a [`Quantity`]. See
+///     [`derivative`] for a way to find the dimensioned derivative of a
+///     function between quantities.
+///
+/// Comparisons between dual numbers only consider their real parts, so that
+///     branches in a function, such as `if x < 0.0`, follow the value being
+///     evaluated and not its derivative. As a result, [`PartialOrd`] is not
+///     consistent with [`PartialEq`], which compares both parts: two dual
+///     numbers with the same real part are ordered as equal, even if they are
+///     not equal.
+///
+/// [`Quantity`]: crate::Quantity
+/// [`derivative`]: crate::quantity::derivative
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Dual<T> {
+    /// Real part.
+    pub re: T,
+    /// Infinitesimal part, holding the derivative.
+    pub eps: T,
+}
+
+impl<T> Dual<T> {
+    /// Construct a new dual number from real and infinitesimal parts.
+    pub const fn new(re: T, eps: T) -> Self { Self { re, eps } }
+}
+
+impl<T: Num> Dual<T> {
+    /// Construct a dual number representing a constant, with a derivative of
+    ///     zero.
+    pub fn constant(re: T) -> Self { Self::new(re, T::zero()) }
+
+    /// Construct a dual number representing the variable of differentiation,
+    ///     with a derivative of one.
+    pub fn variable(re: T) -> Self { Self::new(re, T::one()) }
+}
+
+impl<T: Clone + Num> Dual<T> {
+    /// Apply a function with the given value and derivative at this point,
+    ///     according to the chain rule.
+    fn chain(&self, re: T, deriv: T) -> Self {
+        Self::new(re, deriv * self.eps.clone())
+    }
+}
+
+impl<T: Display> Display for Dual<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        <T as Display>::fmt(&self.re, f)?;
+        f.write_str("+")?;
+        <T as Display>::fmt(&self.eps, f)?;
+        f.write_str("ε")
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for Dual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.re.partial_cmp(&other.re)
+    }
+}
+
+
+//region Arithmetic between dual numbers.
+impl<T: Clone + Num> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl<T: Clone + Num> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl<T: Clone + Num> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let eps = self.re.clone() * rhs.eps + self.eps * rhs.re.clone();
+        Self::new(self.re * rhs.re, eps)
+    }
+}
+
+impl<T: Clone + Num> Div for Dual<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let eps = (self.eps * rhs.re.clone() - self.re.clone() * rhs.eps)
+            / (rhs.re.clone() * rhs.re.clone());
+        Self::new(self.re / rhs.re, eps)
+    }
+}
+
+impl<T: Real> Rem for Dual<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        //  a % b = a - b*trunc(a/b), where the truncation is locally constant.
+        let quot = (self.re / rhs.re).trunc();
+        Self::new(self.re % rhs.re, self.eps - rhs.eps * quot)
+    }
+}
+
+impl<T: Neg<Output=T>> Neg for Dual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output { Self::new(-self.re, -self.eps) }
+}
+
+impl<T: Clone + Num + Neg<Output=T>> Inv for Dual<T> {
+    type Output = Self;
+
+    fn inv(self) -> Self::Output { Self::one() / self }
+}
+//endregion
+
+
+//region Arithmetic between dual and real numbers.
+impl<T: Clone + Num> Add<T> for Dual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: T) -> Self::Output { Self::new(self.re + rhs, self.eps) }
+}
+
+impl<T: Clone + Num> Sub<T> for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: T) -> Self::Output { Self::new(self.re - rhs, self.eps) }
+}
+
+impl<T: Clone + Num> Mul<T> for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.re * rhs.clone(), self.eps * rhs)
+    }
+}
+
+impl<T: Clone + Num> Div<T> for Dual<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.re / rhs.clone(), self.eps / rhs)
+    }
+}
+
+impl<T: Real> Rem<T> for Dual<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output { self % Self::constant(rhs) }
+}
+
+macro_rules! impl_real_lhs {
+    ($($t:ty),+$(,)?) => {$(
+    impl Add<Dual<$t>> for $t {
+        type Output = Dual<$t>;
+
+        fn add(self, rhs: Dual<$t>) -> Self::Output { rhs + self }
+    }
+
+    impl Sub<Dual<$t>> for $t {
+        type Output = Dual<$t>;
+
+        fn sub(self, rhs: Dual<$t>) -> Self::Output { Dual::constant(self) - rhs }
+    }
+
+    impl Mul<Dual<$t>> for $t {
+        type Output = Dual<$t>;
+
+        fn mul(self, rhs: Dual<$t>) -> Self::Output { rhs * self }
+    }
+
+    impl Div<Dual<$t>> for $t {
+        type Output = Dual<$t>;
+
+        fn div(self, rhs: Dual<$t>) -> Self::Output { Dual::constant(self) / rhs }
+    }
+    )+};
+}
+
+impl_real_lhs!(f32, f64);
+//endregion
+
+
+//region Assignment operators.
+macro_rules! impl_assign {
+    ($($op:ident::$method:ident => $base:ident::$base_method:ident),+$(,)?) => {$(
+    impl<T: Clone> $op for Dual<T> where Self: $base<Output=Self> {
+        fn $method(&mut self, rhs: Self) {
+            *self = self.clone().$base_method(rhs);
+        }
+    }
+
+    impl<T: Clone> $op<T> for Dual<T> where Self: $base<T, Output=Self> {
+        fn $method(&mut self, rhs: T) {
+            *self = self.clone().$base_method(rhs);
+        }
+    }
+    )+};
+}
+
+impl_assign!(
+    AddAssign::add_assign => Add::add,
+    SubAssign::sub_assign => Sub::sub,
+    MulAssign::mul_assign => Mul::mul,
+    DivAssign::div_assign => Div::div,
+    RemAssign::rem_assign => Rem::rem,
+);
+//endregion
+
+
+//region Numeric traits.
+impl<T: Clone + Num> Zero for Dual<T> {
+    fn zero() -> Self { Self::new(T::zero(), T::zero()) }
+
+    fn is_zero(&self) -> bool { self.re.is_zero() && self.eps.is_zero() }
+}
+
+impl<T: Clone + Num> One for Dual<T> {
+    fn one() -> Self { Self::new(T::one(), T::zero()) }
+}
+
+impl<T: Real> Num for Dual<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    /// Parse a constant dual number, with a derivative of zero.
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(str, radix).map(Self::constant)
+    }
+}
+
+impl<T: ToPrimitive> ToPrimitive for Dual<T> {
+    fn to_i64(&self) -> Option<i64> { self.re.to_i64() }
+    fn to_u64(&self) -> Option<u64> { self.re.to_u64() }
+    fn to_f64(&self) -> Option<f64> { self.re.to_f64() }
+}
+
+impl<T: NumCast + Zero> NumCast for Dual<T> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        Some(Self::new(T::from(n)?, T::zero()))
+    }
+}
+
+impl<T: FromPrimitive + Zero> FromPrimitive for Dual<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::new(T::from_i64(n)?, T::zero()))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::new(T::from_u64(n)?, T::zero()))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(Self::new(T::from_f64(n)?, T::zero()))
+    }
+}
+
+impl<T: Num> From<T> for Dual<T> {
+    fn from(re: T) -> Self { Self::constant(re) }
+}
+
+impl<T: Real> Real for Dual<T> {
+    fn min_value() -> Self { Self::constant(T::min_value()) }
+    fn min_positive_value() -> Self { Self::constant(T::min_positive_value()) }
+    fn epsilon() -> Self { Self::constant(T::epsilon()) }
+    fn max_value() -> Self { Self::constant(T::max_value()) }
+
+    fn floor(self) -> Self { Self::constant(self.re.floor()) }
+    fn ceil(self) -> Self { Self::constant(self.re.ceil()) }
+    fn round(self) -> Self { Self::constant(self.re.round()) }
+    fn trunc(self) -> Self { Self::constant(self.re.trunc()) }
+    fn fract(self) -> Self { Self::new(self.re.fract(), self.eps) }
+    fn abs(self) -> Self { self.chain(self.re.abs(), self.re.signum()) }
+    fn signum(self) -> Self { Self::constant(self.re.signum()) }
+    fn is_sign_positive(self) -> bool { self.re.is_sign_positive() }
+    fn is_sign_negative(self) -> bool { self.re.is_sign_negative() }
+    fn mul_add(self, a: Self, b: Self) -> Self { self * a + b }
+    fn recip(self) -> Self { self.chain(self.re.recip(), -self.re.powi(2).recip()) }
+
+    fn powi(self, n: i32) -> Self {
+        let n_t = T::from(n).unwrap();
+        self.chain(self.re.powi(n), n_t * self.re.powi(n - 1))
+    }
+
+    fn powf(self, n: Self) -> Self {
+        let re = self.re.powf(n.re);
+        let eps = n.re * self.re.powf(n.re - T::one()) * self.eps;
+
+        if n.eps.is_zero() {
+            //  Avoid the logarithm when the exponent is constant, so that the
+            //      derivative is defined for negative bases.
+            Self::new(re, eps)
+        } else {
+            Self::new(re, eps + re * self.re.ln() * n.eps)
+        }
+    }
+
+    fn sqrt(self) -> Self {
+        let re = self.re.sqrt();
+        self.chain(re, (re + re).recip())
+    }
+
+    fn exp(self) -> Self {
+        let re = self.re.exp();
+        self.chain(re, re)
+    }
+
+    fn exp2(self) -> Self {
+        let re = self.re.exp2();
+        self.chain(re, re * T::from(core::f64::consts::LN_2).unwrap())
+    }
+
+    fn ln(self) -> Self { self.chain(self.re.ln(), self.re.recip()) }
+    fn log(self, base: Self) -> Self { self.ln() / base.ln() }
+
+    fn log2(self) -> Self {
+        let ln_2 = T::from(core::f64::consts::LN_2).unwrap();
+        self.chain(self.re.log2(), (self.re * ln_2).recip())
+    }
+
+    fn log10(self) -> Self {
+        let ln_10 = T::from(core::f64::consts::LN_10).unwrap();
+        self.chain(self.re.log10(), (self.re * ln_10).recip())
+    }
+
+    fn to_degrees(self) -> Self { Self::new(self.re.to_degrees(), self.eps.to_degrees()) }
+    fn to_radians(self) -> Self { Self::new(self.re.to_radians(), self.eps.to_radians()) }
+
+    fn max(self, other: Self) -> Self { if other.re > self.re { other } else { self } }
+    fn min(self, other: Self) -> Self { if other.re < self.re { other } else { self } }
+
+    fn abs_sub(self, other: Self) -> Self {
+        if self.re > other.re { self - other } else { Self::zero() }
+    }
+
+    fn cbrt(self) -> Self {
+        let re = self.re.cbrt();
+        self.chain(re, (re * re * T::from(3).unwrap()).recip())
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        let re = self.re.hypot(other.re);
+        Self::new(re, (self.re * self.eps + other.re * other.eps) / re)
+    }
+
+    fn sin(self) -> Self { self.chain(self.re.sin(), self.re.cos()) }
+    fn cos(self) -> Self { self.chain(self.re.cos(), -self.re.sin()) }
+
+    fn tan(self) -> Self {
+        let re = self.re.tan();
+        self.chain(re, T::one() + re * re)
+    }
+
+    fn asin(self) -> Self {
+        self.chain(self.re.asin(), (T::one() - self.re * self.re).sqrt().recip())
+    }
+
+    fn acos(self) -> Self {
+        self.chain(self.re.acos(), -(T::one() - self.re * self.re).sqrt().recip())
+    }
+
+    fn atan(self) -> Self {
+        self.chain(self.re.atan(), (T::one() + self.re * self.re).recip())
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let re = self.re.atan2(other.re);
+        let den = self.re * self.re + other.re * other.re;
+        Self::new(re, (other.re * self.eps - self.re * other.eps) / den)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.re.sin_cos();
+        (self.chain(sin, cos), self.chain(cos, -sin))
+    }
+
+    fn exp_m1(self) -> Self { self.chain(self.re.exp_m1(), self.re.exp()) }
+    fn ln_1p(self) -> Self { self.chain(self.re.ln_1p(), (T::one() + self.re).recip()) }
+
+    fn sinh(self) -> Self { self.chain(self.re.sinh(), self.re.cosh()) }
+    fn cosh(self) -> Self { self.chain(self.re.cosh(), self.re.sinh()) }
+
+    fn tanh(self) -> Self {
+        let re = self.re.tanh();
+        self.chain(re, T::one() - re * re)
+    }
+
+    fn asinh(self) -> Self {
+        self.chain(self.re.asinh(), (self.re * self.re + T::one()).sqrt().recip())
+    }
+
+    fn acosh(self) -> Self {
+        self.chain(self.re.acosh(), (self.re * self.re - T::one()).sqrt().recip())
+    }
+
+    fn atanh(self) -> Self {
+        self.chain(self.re.atanh(), (T::one() - self.re * self.re).recip())
+    }
+}
+//endregion
+
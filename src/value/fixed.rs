@@ -0,0 +1,454 @@
+//! Module for fixed-point value types.
+//!
+//! These types store a number as an integer count of fractional steps, and
+//!     perform all of their arithmetic, parsing, and formatting with integers.
+//!     Together with [`ScaleRatio`](crate::units::ScaleRatio) conversions, they
+//!     allow quantities to be used on targets with no floating point hardware.
+
+use core::{
+    fmt::{Debug, Display, Formatter},
+    ops::{
+        Add, AddAssign,
+        Div, DivAssign,
+        Mul, MulAssign,
+        Neg,
+        Rem, RemAssign,
+        Sub, SubAssign,
+    },
+};
+use num_traits::{
+    Bounded,
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub,
+    float::FloatCore,
+    FromPrimitive,
+    Num,
+    NumCast,
+    One,
+    ToPrimitive,
+    Zero,
+};
+use crate::error::ParseFixedError;
+
+
+/// Round a fraction of `2^bits` to a number of decimal digits, no more than
+///     `bits`, returning the digits as an integer. The result may be equal to
+///     `10^digits` if it rounds up.
+const fn round_decimal(frac: u128, bits: u32, digits: u32) -> u128 {
+    if digits >= bits {
+        //  Every binary fraction has an exact decimal representation with as
+        //      many digits as it has bits.
+        frac * 5u128.pow(bits)
+    } else {
+        let shift = bits - digits;
+        (frac * 5u128.pow(digits) + (1 << (shift - 1))) >> shift
+    }
+}
+
+
+/// Convert a decimal fraction of `10^digits` to the nearest fraction of
+///     `2^bits`. This is the inverse of [`round_decimal`].
+const fn nearest_binary(dec: u128, bits: u32, digits: u32) -> u128 {
+    let den = 10u128.pow(digits);
+    ((dec << bits) + den / 2) / den
+}
+
+
+macro_rules! impl_fixed {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident($raw:ty, $wide:ty, $half:ty, $frac:literal)
+    );+$(;)?) => {$(
+    $(#[$attr])*
+    #[derive(Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+    #[repr(transparent)]
+    pub struct $name($raw);
+
+    impl $name {
+        /// The number of bits after the binary point.
+        pub const FRAC_BITS: u32 = $frac;
+
+        /// Zero.
+        pub const ZERO: Self = Self(0);
+        /// One.
+        pub const ONE: Self = Self(1 << $frac);
+        /// The smallest positive value.
+        pub const DELTA: Self = Self(1);
+        /// The smallest value.
+        pub const MIN: Self = Self(<$raw>::MIN);
+        /// The largest value.
+        pub const MAX: Self = Self(<$raw>::MAX);
+
+        const FRAC_MASK: $raw = (1 << $frac) - 1;
+
+        /// Construct a value from its underlying integer representation.
+        pub const fn from_bits(bits: $raw) -> Self { Self(bits) }
+
+        /// Return the underlying integer representation of this value.
+        pub const fn to_bits(self) -> $raw { self.0 }
+
+        /// Construct a value from an integer. Every integer of this size can
+        ///     be represented exactly.
+        pub const fn from_int(int: $half) -> Self { Self((int as $raw) << $frac) }
+
+        /// Return the largest integer less than or equal to this value.
+        pub const fn floor(self) -> Self { Self(self.0 & !Self::FRAC_MASK) }
+
+        /// Return the fractional part of this value, which is never negative.
+        pub const fn fract(self) -> Self { Self(self.0 & Self::FRAC_MASK) }
+
+        /// Return the absolute value of this value.
+        ///
+        /// # Panics
+        /// Panics if this value is [`Self::MIN`], whose absolute value cannot
+        ///     be represented. See [`Self::checked_abs`] and
+        ///     [`Self::wrapping_abs`].
+        pub const fn abs(self) -> Self {
+            match self.checked_abs() {
+                Some(abs) => abs,
+                None => panic!("fixed-point absolute value overflowed"),
+            }
+        }
+
+        /// Return the absolute value of this value, or `None` if this value is
+        ///     [`Self::MIN`].
+        pub const fn checked_abs(self) -> Option<Self> {
+            match self.0.checked_abs() {
+                Some(raw) => Some(Self(raw)),
+                None => None,
+            }
+        }
+
+        /// Return the absolute value of this value, wrapping [`Self::MIN`] to
+        ///     itself.
+        pub const fn wrapping_abs(self) -> Self { Self(self.0.wrapping_abs()) }
+
+        /// Add two values, returning `None` on overflow.
+        pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+            match self.0.checked_add(rhs.0) {
+                Some(raw) => Some(Self(raw)),
+                None => None,
+            }
+        }
+
+        /// Subtract two values, returning `None` on overflow.
+        pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+            match self.0.checked_sub(rhs.0) {
+                Some(raw) => Some(Self(raw)),
+                None => None,
+            }
+        }
+
+        /// Multiply two values, rounding to the nearest step. Returns `None`
+        ///     on overflow.
+        pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+            let product = (self.0 as $wide) * (rhs.0 as $wide);
+            let rounded = (product + (1 << ($frac - 1))) >> $frac;
+
+            <$raw>::try_from(rounded).ok().map(Self)
+        }
+
+        /// Divide two values, rounding to the nearest step. Returns `None` if
+        ///     the divisor is zero, or on overflow.
+        pub fn checked_div(self, rhs: Self) -> Option<Self> {
+            if rhs.0 == 0 { return None; }
+
+            let num = (self.0 as $wide) << $frac;
+            let den = rhs.0 as $wide;
+            let mut quot = num / den;
+
+            if 2 * (num % den).abs() >= den.abs() {
+                quot += if (num < 0) == (den < 0) { 1 } else { -1 };
+            }
+
+            <$raw>::try_from(quot).ok().map(Self)
+        }
+
+        /// Return the remainder of dividing two values. Returns `None` if the
+        ///     divisor is zero, or on overflow.
+        pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+            match self.0.checked_rem(rhs.0) {
+                Some(raw) => Some(Self(raw)),
+                None => None,
+            }
+        }
+    }
+
+    impl Debug for $name {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            Display::fmt(self, f)
+        }
+    }
+
+    /// Without a precision, this prints the fewest decimal digits that will
+    ///     parse back to the same value.
+    impl Display for $name {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            let abs = self.0.unsigned_abs() as u128;
+            let mut int = abs >> $frac;
+            let frac = abs & Self::FRAC_MASK as u128;
+
+            //  Digits past the number of fractional bits are always zero, and
+            //      are written as padding.
+            let (digits, mut dec) = match f.precision() {
+                Some(p) => {
+                    let d = (p as u32).min($frac);
+                    (d, round_decimal(frac, $frac, d))
+                }
+                None if frac == 0 => (0, 0),
+                None => (1..$frac)
+                    .map(|d| (d, round_decimal(frac, $frac, d)))
+                    .find(|&(d, dec)| nearest_binary(dec, $frac, d) == frac)
+                    .unwrap_or_else(|| ($frac, round_decimal(frac, $frac, $frac))),
+            };
+
+            if dec == 10u128.pow(digits) {
+                int += 1;
+                dec = 0;
+            }
+
+            if self.0 < 0 {
+                f.write_str("-")?;
+            } else if f.sign_plus() {
+                f.write_str("+")?;
+            }
+
+            write!(f, "{int}")?;
+
+            if digits > 0 {
+                write!(f, ".{dec:0width$}", width = digits as usize)?;
+            }
+
+            for _ in digits as usize..f.precision().unwrap_or(0) {
+                f.write_str("0")?;
+            }
+
+            Ok(())
+        }
+    }
+
+
+    //region Arithmetic.
+    impl Add for $name {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            self.checked_add(rhs).expect("fixed-point addition overflowed")
+        }
+    }
+
+    impl Sub for $name {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            self.checked_sub(rhs).expect("fixed-point subtraction overflowed")
+        }
+    }
+
+    impl Mul for $name {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            self.checked_mul(rhs).expect("fixed-point multiplication overflowed")
+        }
+    }
+
+    impl Div for $name {
+        type Output = Self;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            self.checked_div(rhs).expect("fixed-point division failed")
+        }
+    }
+
+    impl Rem for $name {
+        type Output = Self;
+
+        fn rem(self, rhs: Self) -> Self::Output {
+            self.checked_rem(rhs).expect("fixed-point remainder failed")
+        }
+    }
+
+    impl Neg for $name {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            Self(self.0.checked_neg().expect("fixed-point negation overflowed"))
+        }
+    }
+
+    impl AddAssign for $name {
+        fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+    }
+
+    impl SubAssign for $name {
+        fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+    }
+
+    impl MulAssign for $name {
+        fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+    }
+
+    impl DivAssign for $name {
+        fn div_assign(&mut self, rhs: Self) { *self = *self / rhs; }
+    }
+
+    impl RemAssign for $name {
+        fn rem_assign(&mut self, rhs: Self) { *self = *self % rhs; }
+    }
+    //endregion
+
+
+    //region Numeric traits.
+    impl Zero for $name {
+        fn zero() -> Self { Self::ZERO }
+        fn is_zero(&self) -> bool { self.0 == 0 }
+    }
+
+    impl One for $name {
+        fn one() -> Self { Self::ONE }
+    }
+
+    impl Num for $name {
+        type FromStrRadixErr = ParseFixedError;
+
+        /// Parse a number with an optional sign and an optional fractional
+        ///     part, such as `-12.375`. The result is rounded to the nearest
+        ///     step, without any floating point arithmetic.
+        fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+            let str = str.trim();
+            let (neg, body) = match str.strip_prefix('-') {
+                Some(body) => (true, body),
+                None => (false, str.strip_prefix('+').unwrap_or(str)),
+            };
+
+            let (int_str, frac_str) = body.split_once('.').unwrap_or((body, ""));
+
+            if int_str.is_empty() && frac_str.is_empty() {
+                return Err(ParseFixedError::Empty);
+            }
+
+            let limit = <$raw>::MAX.unsigned_abs() as u128 + 1;
+            let mut int = 0u128;
+
+            for c in int_str.chars() {
+                let digit = c.to_digit(radix).ok_or(ParseFixedError::InvalidDigit)?;
+                int = int * radix as u128 + digit as u128;
+
+                if int > limit { return Err(ParseFixedError::Overflow); }
+            }
+
+            //  Digits beyond the precision of the denominator are checked, but
+            //      do not contribute to the value.
+            let (mut num, mut den) = (0u128, 1u128);
+
+            for c in frac_str.chars() {
+                let digit = c.to_digit(radix).ok_or(ParseFixedError::InvalidDigit)?;
+
+                if den <= u64::MAX as u128 / radix as u128 {
+                    num = num * radix as u128 + digit as u128;
+                    den *= radix as u128;
+                }
+            }
+
+            let frac = ((num << $frac) + den / 2) / den;
+            let mag = (int << $frac) + frac;
+            let raw = if neg { -(mag as i128) } else { mag as i128 };
+
+            <$raw>::try_from(raw).map(Self).map_err(|_| ParseFixedError::Overflow)
+        }
+    }
+
+    /// Conversions to integers truncate toward zero.
+    impl ToPrimitive for $name {
+        fn to_i64(&self) -> Option<i64> {
+            (self.0 / Self::ONE.0).to_i64()
+        }
+
+        fn to_u64(&self) -> Option<u64> {
+            (self.0 / Self::ONE.0).to_u64()
+        }
+
+        fn to_f64(&self) -> Option<f64> {
+            Some(self.0 as f64 / Self::ONE.0 as f64)
+        }
+    }
+
+    /// Conversions from integers do not use floating point arithmetic.
+    impl FromPrimitive for $name {
+        fn from_i64(n: i64) -> Option<Self> {
+            <$raw>::try_from((n as i128) << $frac).ok().map(Self)
+        }
+
+        fn from_u64(n: u64) -> Option<Self> {
+            <$raw>::try_from((n as i128) << $frac).ok().map(Self)
+        }
+
+        fn from_f64(n: f64) -> Option<Self> {
+            let scaled = n * Self::ONE.0 as f64;
+            let limit = -(<$raw>::MIN as f64);
+
+            if -limit <= scaled && scaled < limit {
+                Some(Self(FloatCore::round(scaled) as $raw))
+            } else {
+                None
+            }
+        }
+    }
+
+    impl NumCast for $name {
+        fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+            Self::from_f64(n.to_f64()?)
+        }
+    }
+
+    impl Bounded for $name {
+        fn min_value() -> Self { Self::MIN }
+        fn max_value() -> Self { Self::MAX }
+    }
+
+    impl CheckedAdd for $name {
+        fn checked_add(&self, v: &Self) -> Option<Self> { $name::checked_add(*self, *v) }
+    }
+
+    impl CheckedSub for $name {
+        fn checked_sub(&self, v: &Self) -> Option<Self> { $name::checked_sub(*self, *v) }
+    }
+
+    impl CheckedMul for $name {
+        fn checked_mul(&self, v: &Self) -> Option<Self> { $name::checked_mul(*self, *v) }
+    }
+
+    impl CheckedDiv for $name {
+        fn checked_div(&self, v: &Self) -> Option<Self> { $name::checked_div(*self, *v) }
+    }
+
+    impl CheckedRem for $name {
+        fn checked_rem(&self, v: &Self) -> Option<Self> { $name::checked_rem(*self, *v) }
+    }
+
+    impl CheckedNeg for $name {
+        fn checked_neg(&self) -> Option<Self> { self.0.checked_neg().map(Self) }
+    }
+
+    impl From<$half> for $name {
+        fn from(int: $half) -> Self { Self::from_int(int) }
+    }
+    //endregion
+    )+};
+}
+
+
+impl_fixed! {
+    /// A signed fixed-point number with 16 integer bits and 16 fractional
+    ///     bits (Q16.16), stored in an `i32`.
+    ///
+    /// This type has a range of about ±32768, with a resolution of `2^-16`.
+    Fixed32(i32, i64, i16, 16);
+
+    /// A signed fixed-point number with 32 integer bits and 32 fractional
+    ///     bits (Q32.32), stored in an `i64`.
+    ///
+    /// This type has a range of about ±2.1e9, with a resolution of `2^-32`.
+    Fixed64(i64, i128, i32, 32);
+}
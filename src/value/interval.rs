@@ -0,0 +1,536 @@
+//! Module for the [`Interval`] value type, used for interval arithmetic.
+
+use core::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    ops::{
+        Add, AddAssign,
+        Div, DivAssign,
+        Mul, MulAssign,
+        Neg,
+        Rem, RemAssign,
+        Sub, SubAssign,
+    },
+};
+use num_traits::{
+    FromPrimitive,
+    Inv,
+    Num,
+    NumCast,
+    One,
+    Pow,
+    real::Real,
+    ToPrimitive,
+    Zero,
+};
+
+
+//region Directed rounding.
+/// Return a value strictly less than `x`, by at least one unit in the last
+///     place. Non-finite values are returned unchanged.
+fn next_down<T: Real>(x: T) -> T {
+    match x.abs().partial_cmp(&T::max_value()) {
+        Some(Ordering::Greater) | None => x,
+        _ => x - (x.abs() * T::epsilon()).max(T::min_positive_value()),
+    }
+}
+
+/// Return a value strictly greater than `x`, by at least one unit in the last
+///     place. Non-finite values are returned unchanged.
+fn next_up<T: Real>(x: T) -> T {
+    -next_down(-x)
+}
+
+/// Given a rounded result and the sign of its rounding error, where a positive
+///     error means the exact result is greater, return a lower bound.
+fn round_down<T: Real>(x: T, err: T) -> T {
+    if err < T::zero() { next_down(x) } else { x }
+}
+
+/// Given a rounded result and the sign of its rounding error, where a positive
+///     error means the exact result is greater, return an upper bound.
+fn round_up<T: Real>(x: T, err: T) -> T {
+    if err > T::zero() { next_up(x) } else { x }
+}
+
+/// Return the sum of two values, and its exact rounding error.
+fn two_sum<T: Real>(a: T, b: T) -> (T, T) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Return the product of two values, and the sign of its rounding error.
+fn two_prod<T: Real>(a: T, b: T) -> (T, T) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+/// Return the quotient of two values, and the sign of its rounding error.
+fn two_div<T: Real>(a: T, b: T) -> (T, T) {
+    let q = a / b;
+    let r = -q.mul_add(b, -a);
+    (q, if b < T::zero() { -r } else { r })
+}
+
+fn add_down<T: Real>(a: T, b: T) -> T { let (s, e) = two_sum(a, b); round_down(s, e) }
+fn add_up<T: Real>(a: T, b: T) -> T { let (s, e) = two_sum(a, b); round_up(s, e) }
+fn mul_down<T: Real>(a: T, b: T) -> T { let (p, e) = two_prod(a, b); round_down(p, e) }
+fn mul_up<T: Real>(a: T, b: T) -> T { let (p, e) = two_prod(a, b); round_up(p, e) }
+fn div_down<T: Real>(a: T, b: T) -> T { let (q, e) = two_div(a, b); round_down(q, e) }
+fn div_up<T: Real>(a: T, b: T) -> T { let (q, e) = two_div(a, b); round_up(q, e) }
+
+/// Return the minimum and maximum of four values.
+fn min_max<T: Real>(values: [T; 4]) -> (T, T) {
+    let [a, b, c, d] = values;
+    (a.min(b).min(c).min(d), a.max(b).max(c).max(d))
+}
+//endregion
+
+
+/// A closed interval of real numbers, `[lo, hi]`, representing a value that is
+///     known only to lie somewhere within it.
+///
+/// Arithmetic on intervals is rounded outward, so that the result of every
+///     operation is guaranteed to contain the exact result of the operation
+///     for every combination of values within its operands. When an operation
+///     is exact, no rounding is applied.
+///
+/// This type implements [`Value`](crate::Value) when its component type is
+///     [`Real`], so it can be used as the value of a [`Quantity`]. Bounds are
+///     carried through unit conversions, because conversion factors that are
+///     not integers are themselves widened to intervals. See
+///     [`FromPrimitive`](#impl-FromPrimitive-for-Interval<T>).
+///
+/// Intervals are ordered only when they do not overlap. Squaring a quantity
+///     with [`Quantity::squared`] treats the two factors independently, so
+///     for a tighter result on an interval that contains zero, use
+///     [`Quantity::pow`] instead.
+///
+/// [`Quantity`]: crate::Quantity
+/// [`Quantity::pow`]: crate::Quantity::pow
+/// [`Quantity::squared`]: crate::Quantity::squared
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Interval<T> {
+    /// Lower bound.
+    pub lo: T,
+    /// Upper bound.
+    pub hi: T,
+}
+
+impl<T: Real> Interval<T> {
+    /// Construct a new interval between two bounds, in either order.
+    pub fn new(a: T, b: T) -> Self {
+        Self { lo: a.min(b), hi: a.max(b) }
+    }
+
+    /// Construct a degenerate interval containing exactly one value.
+    pub fn point(x: T) -> Self { Self { lo: x, hi: x } }
+
+    /// Construct an interval from a value that may have been rounded, widening
+    ///     it unless it is an integer.
+    fn from_value(x: T) -> Self {
+        if x.fract().is_zero() {
+            Self::point(x)
+        } else {
+            Self::new(next_down(x), next_up(x))
+        }
+    }
+
+    /// Construct an interval containing every value within `tolerance` of
+    ///     `x`.
+    pub fn with_tolerance(x: T, tolerance: T) -> Self {
+        let tolerance = tolerance.abs();
+        Self::new(add_down(x, -tolerance), add_up(x, tolerance))
+    }
+
+    /// Construct an interval containing every real number.
+    pub fn entire() -> Self {
+        let inf = T::one() / T::zero();
+        Self { lo: -inf, hi: inf }
+    }
+
+    /// Return the width of this interval, rounded upward.
+    pub fn width(&self) -> T { add_up(self.hi, -self.lo) }
+
+    /// Return the midpoint of this interval.
+    pub fn midpoint(&self) -> T {
+        let two = T::one() + T::one();
+        self.lo / two + self.hi / two
+    }
+
+    /// Return `true` if this interval contains the given value.
+    pub fn contains(&self, x: T) -> bool { self.lo <= x && x <= self.hi }
+
+    /// Return `true` if this interval contains all of another interval.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.lo <= other.lo && other.hi <= self.hi
+    }
+
+    /// Return `true` if this interval contains exactly one value.
+    pub fn is_point(&self) -> bool { self.lo == self.hi }
+
+    /// Return the smallest interval containing both this interval and another.
+    pub fn hull(&self, other: &Self) -> Self {
+        Self { lo: self.lo.min(other.lo), hi: self.hi.max(other.hi) }
+    }
+
+    /// Return the intersection of this interval and another, if they overlap.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let lo = self.lo.max(other.lo);
+        let hi = self.hi.min(other.hi);
+
+        if lo <= hi { Some(Self { lo, hi }) } else { None }
+    }
+
+    /// Return the interval of absolute values of this interval.
+    pub fn abs(&self) -> Self {
+        if self.lo >= T::zero() {
+            *self
+        } else if self.hi <= T::zero() {
+            -*self
+        } else {
+            Self { lo: T::zero(), hi: self.hi.max(-self.lo) }
+        }
+    }
+
+    /// Return the square root of this interval. Any negative part of the
+    ///     interval is ignored.
+    pub fn sqrt(&self) -> Self {
+        let sqrt_down = |x: T| {
+            let r = x.sqrt();
+            round_down(r, -r.mul_add(r, -x))
+        };
+        let sqrt_up = |x: T| {
+            let r = x.sqrt();
+            round_up(r, -r.mul_add(r, -x))
+        };
+
+        Self {
+            lo: sqrt_down(self.lo.max(T::zero())),
+            hi: sqrt_up(self.hi.max(T::zero())),
+        }
+    }
+
+    /// Raise this interval to an integer power.
+    pub fn powi(&self, n: i32) -> Self {
+        match n {
+            0 => Self::one(),
+            1 => *self,
+            n if n < 0 => self.powi(-n).inv(),
+            n if n % 2 == 0 => {
+                let abs = self.abs();
+                let half = abs.powi(n / 2);
+                let lo = mul_down(half.lo, half.lo);
+                let hi = mul_up(half.hi, half.hi);
+                Self { lo, hi }
+            }
+            n => {
+                //  Odd powers are monotonic.
+                let rest = self.powi(n - 1);
+                *self * rest
+            }
+        }
+    }
+
+    /// Raise this interval to a real power. Any negative part of the interval
+    ///     is ignored.
+    pub fn powf(&self, exp: Self) -> Self {
+        if exp.is_point() && exp.lo.fract().is_zero() {
+            if let Some(n) = exp.lo.to_i32() {
+                return self.powi(n);
+            }
+        }
+
+        let lo = self.lo.max(T::zero());
+        let hi = self.hi.max(T::zero());
+
+        //  The power is monotonic in each argument for positive bases, so the
+        //      bounds are found at the corners. Results are widened, since the
+        //      power function is not guaranteed to be correctly rounded.
+        let (min, max) = min_max([
+            lo.powf(exp.lo), lo.powf(exp.hi),
+            hi.powf(exp.lo), hi.powf(exp.hi),
+        ]);
+
+        Self {
+            lo: next_down(min).max(T::zero()),
+            hi: next_up(max),
+        }
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[")?;
+        <T as Display>::fmt(&self.lo, f)?;
+        f.write_str(", ")?;
+        <T as Display>::fmt(&self.hi, f)?;
+        f.write_str("]")
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for Interval<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.hi < other.lo {
+            Some(Ordering::Less)
+        } else if self.lo > other.hi {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+
+//region Arithmetic between intervals.
+impl<T: Real> Add for Interval<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            lo: add_down(self.lo, rhs.lo),
+            hi: add_up(self.hi, rhs.hi),
+        }
+    }
+}
+
+impl<T: Real> Sub for Interval<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<T: Real> Mul for Interval<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (lo, _) = min_max([
+            mul_down(self.lo, rhs.lo), mul_down(self.lo, rhs.hi),
+            mul_down(self.hi, rhs.lo), mul_down(self.hi, rhs.hi),
+        ]);
+        let (_, hi) = min_max([
+            mul_up(self.lo, rhs.lo), mul_up(self.lo, rhs.hi),
+            mul_up(self.hi, rhs.lo), mul_up(self.hi, rhs.hi),
+        ]);
+
+        Self { lo, hi }
+    }
+}
+
+/// Division by an interval that contains zero results in an interval that
+///     contains every real number.
+impl<T: Real> Div for Interval<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.contains(T::zero()) {
+            return Self::entire();
+        }
+
+        let (lo, _) = min_max([
+            div_down(self.lo, rhs.lo), div_down(self.lo, rhs.hi),
+            div_down(self.hi, rhs.lo), div_down(self.hi, rhs.hi),
+        ]);
+        let (_, hi) = min_max([
+            div_up(self.lo, rhs.lo), div_up(self.lo, rhs.hi),
+            div_up(self.hi, rhs.lo), div_up(self.hi, rhs.hi),
+        ]);
+
+        Self { lo, hi }
+    }
+}
+
+/// The remainder is computed exactly when the quotient truncates to the same
+///     integer across both operands. Otherwise, the result is bounded by the
+///     magnitude of the divisor, with the sign of the dividend.
+impl<T: Real> Rem for Interval<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        let quot = self / rhs;
+        let trunc = quot.lo.trunc();
+
+        if trunc == quot.hi.trunc() && trunc.abs() <= T::max_value() {
+            self - rhs * Self::point(trunc)
+        } else {
+            let max = rhs.abs().hi;
+
+            Self {
+                lo: if self.lo < T::zero() { -max } else { T::zero() },
+                hi: if self.hi > T::zero() { max } else { T::zero() },
+            }
+        }
+    }
+}
+
+impl<T: Real> Neg for Interval<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output { Self { lo: -self.hi, hi: -self.lo } }
+}
+
+impl<T: Real> Inv for Interval<T> {
+    type Output = Self;
+
+    fn inv(self) -> Self::Output { Self::one() / self }
+}
+
+impl<T: Real> Pow<Interval<T>> for Interval<T> {
+    type Output = Self;
+
+    fn pow(self, rhs: Interval<T>) -> Self::Output { self.powf(rhs) }
+}
+//endregion
+
+
+//region Arithmetic between intervals and real numbers.
+macro_rules! impl_real_rhs {
+    ($($op:ident::$method:ident),+$(,)?) => {$(
+    impl<T: Real> $op<T> for Interval<T> {
+        type Output = Self;
+
+        fn $method(self, rhs: T) -> Self::Output { self.$method(Self::point(rhs)) }
+    }
+    )+};
+}
+
+impl_real_rhs!(Add::add, Sub::sub, Mul::mul, Div::div, Rem::rem);
+
+macro_rules! impl_real_lhs {
+    ($($t:ty),+$(,)?) => {$(
+    impl Add<Interval<$t>> for $t {
+        type Output = Interval<$t>;
+
+        fn add(self, rhs: Interval<$t>) -> Self::Output { Interval::point(self) + rhs }
+    }
+
+    impl Sub<Interval<$t>> for $t {
+        type Output = Interval<$t>;
+
+        fn sub(self, rhs: Interval<$t>) -> Self::Output { Interval::point(self) - rhs }
+    }
+
+    impl Mul<Interval<$t>> for $t {
+        type Output = Interval<$t>;
+
+        fn mul(self, rhs: Interval<$t>) -> Self::Output { Interval::point(self) * rhs }
+    }
+
+    impl Div<Interval<$t>> for $t {
+        type Output = Interval<$t>;
+
+        fn div(self, rhs: Interval<$t>) -> Self::Output { Interval::point(self) / rhs }
+    }
+    )+};
+}
+
+impl_real_lhs!(f32, f64);
+//endregion
+
+
+//region Assignment operators.
+macro_rules! impl_assign {
+    ($($op:ident::$method:ident => $base:ident::$base_method:ident),+$(,)?) => {$(
+    impl<T: Clone> $op for Interval<T> where Self: $base<Output=Self> {
+        fn $method(&mut self, rhs: Self) {
+            *self = self.clone().$base_method(rhs);
+        }
+    }
+
+    impl<T: Clone> $op<T> for Interval<T> where Self: $base<T, Output=Self> {
+        fn $method(&mut self, rhs: T) {
+            *self = self.clone().$base_method(rhs);
+        }
+    }
+    )+};
+}
+
+impl_assign!(
+    AddAssign::add_assign => Add::add,
+    SubAssign::sub_assign => Sub::sub,
+    MulAssign::mul_assign => Mul::mul,
+    DivAssign::div_assign => Div::div,
+    RemAssign::rem_assign => Rem::rem,
+);
+//endregion
+
+
+//region Numeric traits.
+impl<T: Real> Zero for Interval<T> {
+    fn zero() -> Self { Self::point(T::zero()) }
+
+    fn is_zero(&self) -> bool { self.lo.is_zero() && self.hi.is_zero() }
+}
+
+impl<T: Real> One for Interval<T> {
+    fn one() -> Self { Self::point(T::one()) }
+}
+
+impl<T: Real> Num for Interval<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    /// Parse an interval, either as a pair of bounds in the form `[lo, hi]`,
+    ///     or as a single number. A single number is widened to include the
+    ///     values on either side of it, since it may have been rounded while
+    ///     parsing.
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let str = str.trim();
+
+        match str.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            Some(inner) => {
+                let (lo, hi) = inner.split_once(',').unwrap_or((inner, inner));
+                let lo = T::from_str_radix(lo.trim(), radix)?;
+                let hi = T::from_str_radix(hi.trim(), radix)?;
+                Ok(Self::new(next_down(lo), next_up(hi)))
+            }
+            None => {
+                let x = T::from_str_radix(str, radix)?;
+                Ok(Self::new(next_down(x), next_up(x)))
+            }
+        }
+    }
+}
+
+/// Conversion to a primitive returns the midpoint of the interval.
+impl<T: Real> ToPrimitive for Interval<T> {
+    fn to_i64(&self) -> Option<i64> { self.midpoint().to_i64() }
+    fn to_u64(&self) -> Option<u64> { self.midpoint().to_u64() }
+    fn to_f64(&self) -> Option<f64> { self.midpoint().to_f64() }
+}
+
+/// Conversion from a primitive is exact for integers. Other values are assumed
+///     to have been rounded, and are widened to include the values on either
+///     side of them.
+impl<T: Real> NumCast for Interval<T> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        Some(Self::from_value(T::from(n)?))
+    }
+}
+
+/// Conversion from a primitive is exact for integers. Other values are assumed
+///     to have been rounded, and are widened to include the values on either
+///     side of them.
+impl<T: Real> FromPrimitive for Interval<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::from_value(<T as NumCast>::from(n)?))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from_value(<T as NumCast>::from(n)?))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(Self::from_value(<T as NumCast>::from(n)?))
+    }
+}
+
+impl<T: Real> From<T> for Interval<T> {
+    fn from(x: T) -> Self { Self::point(x) }
+}
+//endregion
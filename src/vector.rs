@@ -0,0 +1,276 @@
+//! Module for vectors of [`Quantity`] components.
+//!
+//! A vector stores all of its components as values in a single shared unit,
+//!     in the same way that a [`Quantity`] stores one value with one unit.
+
+use core::{fmt::Display, ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign}};
+use num_traits::real::Real;
+use crate::{Quantity, units::{traits::*, One, UnitMul}, Value};
+
+
+macro_rules! qty_vec {
+    ($(
+    $(#[$attr:meta])*
+    $name:ident[$n:literal] { $($field:ident),+ }
+    ),+$(,)?) => {$(
+    $(#[$attr])*
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+    pub struct $name<U: Unit, V: Value = f64> {
+        $(
+        #[doc = concat!("Value of the ", stringify!($field), " component.")]
+        pub $field: V,
+        )+
+        /// Unit shared by all components.
+        pub unit: U,
+    }
+
+    impl<U: Unit, V: Value> $name<U, V> {
+        /// Construct a new vector from a unit and component values.
+        pub const fn new(unit: U, $($field: V),+) -> Self {
+            Self { $($field,)+ unit }
+        }
+
+        /// Construct a new vector from an array of quantities. All components
+        ///     will be converted to the unit of the first.
+        pub fn from_qty(array: [Quantity<U, V>; $n]) -> Self {
+            let unit = array[0].unit;
+            let [$($field),+] = array.map(|qty| qty.value_as(unit));
+
+            Self::new(unit, $($field),+)
+        }
+
+        /// Return the components of this vector as an array of quantities.
+        pub fn to_qty(self) -> [Quantity<U, V>; $n] {
+            [$(Quantity::new(self.unit, self.$field)),+]
+        }
+
+        /// Return the components of this vector as an array of values.
+        pub fn values(self) -> [V; $n] {
+            [$(self.$field),+]
+        }
+
+        /// Return an equivalent vector, with all components scaled to another
+        ///     unit of the same type.
+        pub fn with_unit(self, unit: U) -> Self {
+            self.convert_to(unit)
+        }
+
+        /// Perform trait-based unit conversion to a specific unit. This kind of
+        ///     conversion can cross between [`Unit`] types.
+        pub fn convert_to<W: Unit>(self, unit: W) -> $name<W, V> where
+            U: ConvertInto<W>,
+        {
+            let factor: V = crate::_conv_f64(self.unit.conversion_factor_into(unit));
+            $name::new(unit, $(self.$field * factor.clone()),+)
+        }
+
+        /// Return the dot product of this vector and another, with a unit that
+        ///     is the product of their units.
+        pub fn dot<W: Unit>(self, rhs: $name<W, V>) -> Quantity<UnitMul<U, W>, V> where
+            UnitMul<U, W>: Unit,
+        {
+            let value = V::zero() $(+ self.$field * rhs.$field)+;
+            Quantity::new(UnitMul::new(self.unit, rhs.unit), value)
+        }
+
+        /// Return the magnitude of this vector.
+        pub fn norm(self) -> Quantity<U, V> where
+            V: Real,
+        {
+            let value = (V::zero() $(+ self.$field * self.$field)+).sqrt();
+            Quantity::new(self.unit, value)
+        }
+
+        /// Return a dimensionless vector with a magnitude of one, in the same
+        ///     direction as this vector. Returns `None` if this vector has a
+        ///     magnitude of zero.
+        pub fn direction(self) -> Option<$name<One, V>> where
+            V: Real,
+        {
+            let norm = self.norm().value;
+
+            if norm.is_zero() {
+                None
+            } else {
+                Some($name::new(One, $(self.$field / norm),+))
+            }
+        }
+    }
+
+    impl<U: Unit, V: Value> Default for $name<U, V> {
+        fn default() -> Self {
+            Self { $($field: V::zero(),)+ unit: U::default() }
+        }
+    }
+
+    impl<U: Unit, V: Value> Display for $name<U, V> where V: Display {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let [first, rest @ ..] = [$(&self.$field),+];
+
+            f.write_str("(")?;
+            <V as Display>::fmt(first, f)?;
+
+            for value in rest {
+                f.write_str(", ")?;
+                <V as Display>::fmt(value, f)?;
+            }
+
+            write!(f, ") {}", self.unit)
+        }
+    }
+
+
+    //region Negation.
+    impl<U: Unit, V: Value> Neg for $name<U, V> where
+        V: Neg, <V as Neg>::Output: Value,
+    {
+        type Output = $name<U, <V as Neg>::Output>;
+
+        fn neg(self) -> Self::Output {
+            $name::new(self.unit, $(-self.$field),+)
+        }
+    }
+    //endregion
+
+    //region Addition/subtraction between same-unit vectors.
+    impl<U: Unit, V: Value, W: Unit, X: Value> Add<$name<W, X>> for $name<U, V> where
+        W: ConvertInto<U>,
+        V: Add<X>, <V as Add<X>>::Output: Value,
+    {
+        type Output = $name<U, <V as Add<X>>::Output>;
+
+        fn add(self, rhs: $name<W, X>) -> Self::Output {
+            let rhs = rhs.convert_to(self.unit);
+            $name::new(self.unit, $(self.$field + rhs.$field),+)
+        }
+    }
+
+    impl<U: Unit, V: Value, W: Unit, X: Value> Sub<$name<W, X>> for $name<U, V> where
+        W: ConvertInto<U>,
+        V: Sub<X>, <V as Sub<X>>::Output: Value,
+    {
+        type Output = $name<U, <V as Sub<X>>::Output>;
+
+        fn sub(self, rhs: $name<W, X>) -> Self::Output {
+            let rhs = rhs.convert_to(self.unit);
+            $name::new(self.unit, $(self.$field - rhs.$field),+)
+        }
+    }
+
+    impl<U: Unit, V: Value, W: Unit, X: Value> AddAssign<$name<W, X>> for $name<U, V> where
+        W: ConvertInto<U>,
+        V: AddAssign<X>,
+    {
+        fn add_assign(&mut self, rhs: $name<W, X>) {
+            let rhs = rhs.convert_to(self.unit);
+            $(self.$field += rhs.$field;)+
+        }
+    }
+
+    impl<U: Unit, V: Value, W: Unit, X: Value> SubAssign<$name<W, X>> for $name<U, V> where
+        W: ConvertInto<U>,
+        V: SubAssign<X>,
+    {
+        fn sub_assign(&mut self, rhs: $name<W, X>) {
+            let rhs = rhs.convert_to(self.unit);
+            $(self.$field -= rhs.$field;)+
+        }
+    }
+    //endregion
+
+    //region Division/multiplication between vectors and quantities.
+    impl<U: Unit, V: Value, W: Unit, X: Value> Div<Quantity<W, X>> for $name<U, V> where
+        U: Div<W>, <U as Div<W>>::Output: Unit,
+        V: Div<X>, <V as Div<X>>::Output: Value,
+    {
+        type Output = $name<U::Output, <V as Div<X>>::Output>;
+
+        fn div(self, rhs: Quantity<W, X>) -> Self::Output {
+            $name::new(self.unit / rhs.unit, $(self.$field / rhs.value.clone()),+)
+        }
+    }
+
+    impl<U: Unit, V: Value, W: Unit, X: Value> Mul<Quantity<W, X>> for $name<U, V> where
+        U: Mul<W>, <U as Mul<W>>::Output: Unit,
+        V: Mul<X>, <V as Mul<X>>::Output: Value,
+    {
+        type Output = $name<U::Output, <V as Mul<X>>::Output>;
+
+        fn mul(self, rhs: Quantity<W, X>) -> Self::Output {
+            $name::new(self.unit * rhs.unit, $(self.$field * rhs.value.clone()),+)
+        }
+    }
+    //endregion
+
+    //region Division/multiplication between vectors and scalars.
+    impl<U: Unit, V: Value, X: Value> Div<X> for $name<U, V> where
+        V: Div<X>, <V as Div<X>>::Output: Value,
+    {
+        type Output = $name<U, <V as Div<X>>::Output>;
+
+        fn div(self, rhs: X) -> Self::Output {
+            $name::new(self.unit, $(self.$field / rhs.clone()),+)
+        }
+    }
+
+    impl<U: Unit, V: Value, X: Value> Mul<X> for $name<U, V> where
+        V: Mul<X>, <V as Mul<X>>::Output: Value,
+    {
+        type Output = $name<U, <V as Mul<X>>::Output>;
+
+        fn mul(self, rhs: X) -> Self::Output {
+            $name::new(self.unit, $(self.$field * rhs.clone()),+)
+        }
+    }
+    //endregion
+    )+};
+}
+
+qty_vec!(
+    /// A two-dimensional vector of quantities sharing a single unit.
+    QtyVec2[2] { x, y },
+    /// A three-dimensional vector of quantities sharing a single unit.
+    QtyVec3[3] { x, y, z },
+);
+
+
+impl<U: Unit, V: Value> QtyVec2<U, V> {
+    /// Return the scalar cross product of this vector and another, which is the
+    ///     `z` component of their three-dimensional cross product.
+    pub fn cross<W: Unit>(self, rhs: QtyVec2<W, V>) -> Quantity<UnitMul<U, W>, V> where
+        UnitMul<U, W>: Unit,
+    {
+        let value = self.x * rhs.y - self.y * rhs.x;
+        Quantity::new(UnitMul::new(self.unit, rhs.unit), value)
+    }
+
+    /// Extend this vector into three dimensions, with the given `z` value.
+    pub fn extend(self, z: V) -> QtyVec3<U, V> {
+        QtyVec3::new(self.unit, self.x, self.y, z)
+    }
+}
+
+
+impl<U: Unit, V: Value> QtyVec3<U, V> {
+    /// Return the cross product of this vector and another, with a unit that is
+    ///     the product of their units.
+    pub fn cross<W: Unit>(self, rhs: QtyVec3<W, V>) -> QtyVec3<UnitMul<U, W>, V> where
+        UnitMul<U, W>: Unit,
+    {
+        let Self { x: ax, y: ay, z: az, .. } = self;
+        let QtyVec3 { x: bx, y: by, z: bz, .. } = rhs;
+
+        QtyVec3::new(
+            UnitMul::new(self.unit, rhs.unit),
+            ay.clone() * bz.clone() - az.clone() * by.clone(),
+            az * bx.clone() - ax.clone() * bz,
+            ax * by - ay * bx,
+        )
+    }
+
+    /// Discard the `z` component of this vector.
+    pub fn truncate(self) -> QtyVec2<U, V> {
+        QtyVec2::new(self.unit, self.x, self.y)
+    }
+}
@@ -0,0 +1,97 @@
+//! Tests for dimensional analysis of sets of variables.
+
+use dana::{
+    analysis::*,
+    dimension::{self as dim, DynDimension},
+    error::DimensionError,
+    matrix::*,
+    prelude::*,
+    symbols::basic::*,
+};
+
+
+#[test]
+fn pi_groups_froude() {
+    //  Speed, length, and gravity yield a single group, the inverse square of
+    //      the Froude number.
+    let dims = dims::<(dim::Velocity, dim::Length, dim::Accel)>();
+    let pi = PiGroups::new(dims);
+    assert_eq!(pi.len(), 1);
+    assert_eq!(pi.groups()[0].exponents, [-2, 1, 1]);
+    assert_eq!(pi.groups()[0].dimension(&dims), DynDimension::ONE);
+
+    let mut vars = DimVector::<f64, 3>::new(dims);
+    vars.set(0, qty![3.0 m/s]).unwrap();
+    vars.set(1, qty![90.0 cm]).unwrap();
+    vars.set(2, qty![10.0 m/s/s]).unwrap();
+
+    let [fr_inv_sq, ..] = pi.evaluate(&vars).unwrap();
+    assert!((fr_inv_sq - 1.0).abs() < 1e-12);
+
+    //  Variables of the wrong dimension should be rejected.
+    let wrong = DimVector::<f64, 3>::new([dims[1], dims[0], dims[2]]);
+    assert_eq!(
+        pi.evaluate(&wrong),
+        Err(DimensionError::Mismatch { expected: dims[0], found: dims[1] }),
+    );
+    assert!(pi.groups()[0].evaluate(&wrong).is_err());
+}
+
+
+#[test]
+fn pi_groups_rank() {
+    //  A pendulum: Period, length, mass, and gravity. Mass cannot be cancelled
+    //      by any other variable, so it does not appear in the only group.
+    let pi = PiGroups::new(dims::<(dim::Time, dim::Length, dim::Mass, dim::Accel)>());
+    assert_eq!(pi.len(), 1);
+    assert_eq!(pi.groups()[0].exponents, [2, -1, 0, 1]);
+
+    //  Two lengths and an area yield two groups.
+    let pi = PiGroups::new(dims::<(dim::Length, dim::Area, dim::Length)>());
+    assert_eq!(pi.len(), 2);
+    assert_eq!(pi.groups()[0].exponents, [-2, 1, 0]);
+    assert_eq!(pi.groups()[1].exponents, [-1, 0, 1]);
+
+    //  Independent variables yield no groups.
+    let pi = PiGroups::new(dims::<(dim::Length, dim::Time)>());
+    assert!(pi.is_empty());
+    assert!(pi.groups().is_empty());
+}
+
+
+#[test]
+fn pi_groups_rational() {
+    use dana::dimension::CanDimRoot;
+
+    //  Fractional exponents are handled by scaling them to integers.
+    type RootLength = <dim::Length as CanDimRoot<2>>::Output;
+    let pi = PiGroups::new(dims::<(RootLength, dim::Length)>());
+    assert_eq!(pi.groups()[0].exponents, [-2, 1]);
+}
+
+
+#[test]
+fn pi_groups_extension() {
+    use dana::dimension::{AxisDim, DimensionExt, ExtAxis};
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Parts;
+
+    impl ExtAxis for Parts {
+        type Id = typenum::U1;
+        const SYMBOL: &'static str = "parts";
+    }
+
+    type PartRate = DimensionExt<dim::Frequency, Parts>;
+
+    //  A rate of parts is not a frequency, so they form no group.
+    let pi = PiGroups::new(dims::<(dim::Frequency, PartRate)>());
+    assert!(pi.is_empty());
+
+    //  A count of parts, produced at a rate over a time.
+    let dims = dims::<(AxisDim<Parts>, PartRate, dim::Time)>();
+    let pi = PiGroups::new(dims);
+    assert_eq!(pi.len(), 1);
+    assert_eq!(pi.groups()[0].exponents, [-1, 1, 1]);
+    assert_eq!(pi.groups()[0].dimension(&dims), DynDimension::ONE);
+}
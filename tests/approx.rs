@@ -0,0 +1,74 @@
+//! Tests for approximate comparison of quantities.
+
+use dana::{assert_qty_approx, prelude::*, symbols::{basic::*, length_huge::*}};
+
+
+#[test]
+fn approx_methods() {
+    let a = qty![1.0 Mpc];
+    let b = qty![1.000_000_000_1 Mpc];
+
+    //  An absolute limit in megaparsecs is meaningless, but a relative one
+    //      is not.
+    assert!(a.almost_eq_rel(b, 1e-9));
+    assert!(!a.almost_eq_rel(b, 1e-11));
+    assert!(a.almost_eq_rel(b.convert_to(pc), 1e-9));
+    assert!(qty![0.0 nm].almost_eq_rel(qty![0.0 km], 0.0));
+    assert!(!nm.quantity(f64::NAN).almost_eq_rel(nm.quantity(f64::NAN), 1.0));
+
+    //  A limit given as a quantity can be in any unit of the dimension.
+    assert!(qty![1.0 m].almost_eq_within(qty![1.000_000_5 m], qty![1.0 μm]));
+    assert!(!qty![1.0 m].almost_eq_within(qty![1.000_002 m], qty![1.0 μm]));
+    assert!(qty![1.0 km].almost_eq_within(qty![1_000.000_5 m], qty![1.0 mm]));
+
+    //  Adjacent floats should be one ULP apart.
+    let one = qty![1.0 m];
+    let next = m.quantity(f64::from_bits(1.0f64.to_bits() + 1));
+    assert_eq!(one.ulp_distance(next), Some(1));
+    assert_eq!(one.ulp_distance(one), Some(0));
+    assert_eq!(qty![0.0 m].ulp_distance(qty![-0.0 m]), Some(0));
+    assert_eq!(
+        m.quantity(f64::from_bits(1)).ulp_distance(m.quantity(-f64::from_bits(1))),
+        Some(2),
+    );
+    assert_eq!(one.ulp_distance(m.quantity(f64::NAN)), None);
+    assert!(one.almost_eq_ulps(next, 1));
+    assert!(!one.almost_eq_ulps(next, 0));
+}
+
+
+#[test]
+fn approx_macro() {
+    assert_qty_approx!(<= qty![1.0 μm], qty![1.0 m], qty![1.000_000_5 m]);
+    assert_qty_approx!(<= qty![1.0 mm], qty![1.0 km], qty![1_000.000_5 m], "within {}", "a mm");
+    assert_qty_approx!(rel <= 1e-9, qty![1.0 Mpc], qty![1.000_000_000_1 Mpc]);
+    assert_qty_approx!(rel <= 1e-9, qty![1.0 nm], qty![1.000_000_000_1 nm],);
+    assert_qty_approx!(ulps <= 2, qty![0.1 m] + qty![0.2 m], qty![0.3 m]);
+
+    //  Variables named like the keywords should still be usable as operands.
+    let (rel, ulps) = (qty![2.0 m], qty![2.0 m]);
+    assert_qty_approx!(rel, ulps);
+    assert_qty_approx!(<= 1e-9, rel, ulps);
+
+    //  Each argument should be evaluated exactly once.
+    let mut count = 0;
+    let mut next = |qty| { count += 1; qty };
+    assert_qty_approx!(<= 1e-9, next(qty![1.0 m]), next(qty![1.0 m]));
+    assert_qty_approx!(rel <= 1e-9, next(qty![1.0 m]), next(qty![1.0 m]));
+    assert_qty_approx!(ulps <= 2, next(qty![1.0 m]), next(qty![1.0 m]));
+    assert_eq!(count, 6);
+}
+
+
+#[test]
+#[should_panic = "relative delta"]
+fn approx_macro_rel_fail() {
+    assert_qty_approx!(rel <= 1e-12, qty![1.0 Mpc], qty![1.000_000_000_1 Mpc]);
+}
+
+
+#[test]
+#[should_panic = "limit: 0.001 mm"]
+fn approx_macro_qty_fail() {
+    assert_qty_approx!(<= qty![0.001 mm], qty![1.0 m], qty![1.000_002 m], "too far");
+}
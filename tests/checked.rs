@@ -0,0 +1,86 @@
+//! Tests for overflow-aware arithmetic on integer quantities.
+
+use dana::{prelude::*, symbols::basic::*};
+
+
+#[test]
+fn checked_convert() {
+    //  Exact conversion should not lose precision, unlike conversion through
+    //      `f64`, which cannot represent every `u64`.
+    let ticks = ns.quantity(u64::MAX - 1);
+    assert_eq!(ticks.try_convert(ns).unwrap().value, u64::MAX - 1);
+    assert_eq!(ticks.try_convert(s).unwrap().value, 18_446_744_073);
+    assert_eq!(ticks.try_convert(h).unwrap().value, 5_124_095);
+
+    assert_eq!(qty![600 s].try_convert(min), Some(qty![10 min]));
+    assert_eq!(qty![599 s].try_convert(min), Some(qty![9 min]));
+    assert_eq!(qty![-1 h].try_convert(ms), Some(qty![-3_600_000 ms]));
+
+    //  Overflow should be reported, or saturated.
+    assert_eq!(qty![600_u64 s].try_convert(ns), Some(qty![600_000_000_000_u64 ns]));
+    assert_eq!(qty![6_000_000_u64 h].try_convert(ns), None);
+    assert_eq!(qty![6_000_000_u64 h].saturating_convert(ns).value, u64::MAX);
+    assert_eq!(qty![-3_i32 s].saturating_convert(ns).value, i32::MIN);
+    assert_eq!(qty![0_u8 s].try_convert(ms), Some(qty![0_u8 ms]));
+
+    //  A denominator too large for the value type should truncate, and not be
+    //      reported as overflow.
+    assert_eq!(qty![3_u8 ms].try_convert(s), Some(qty![0_u8 s]));
+    assert_eq!(qty![3_u8 ms].saturating_convert(s), qty![0_u8 s]);
+    assert_eq!(ns.quantity(5_i32).try_convert(h), Some(h.quantity(0)));
+    assert_eq!(ns.quantity(-5_i32).saturating_convert(h), h.quantity(0));
+
+    //  Integer factors should wrap around.
+    assert_eq!(qty![65_u16 s].wrapping_convert(ms).value, 65_000);
+    assert_eq!(qty![70_u16 s].wrapping_convert(ms).value, 4_464);
+}
+
+
+#[test]
+fn checked_arithmetic() {
+    let a = ns.quantity(u64::MAX - 5);
+
+    assert_eq!(a.checked_add(qty![5 ns]), Some(ns.quantity(u64::MAX)));
+    assert_eq!(a.checked_add(qty![6 ns]), None);
+    assert_eq!(a.checked_add(qty![1 s]), None);
+    assert_eq!(qty![1 s].checked_add(qty![1500 ms]), Some(qty![2 s]));
+    assert_eq!(qty![1_u64 s].checked_sub(qty![2 s]), None);
+    assert_eq!(qty![2 ms].checked_sub(qty![1_000 us]), Some(qty![1 ms]));
+    assert_eq!(qty![7 s].checked_rem(qty![2_000 ms]), Some(qty![1 s]));
+    assert_eq!(qty![7 s].checked_rem(qty![0 ms]), None);
+    assert_eq!(s.quantity(i32::MIN).checked_neg(), None);
+    assert_eq!(qty![250_u8 ms].checked_add(qty![1_u8 us]), Some(qty![250_u8 ms]));
+
+    let area = qty![70_000_i32 m].checked_mul(qty![40_000 m]);
+    assert_eq!(area, None);
+
+    let area = qty![60_000_i64 m].checked_mul(qty![30_000 m]).unwrap();
+    assert_eq!(area.value, 1_800_000_000);
+    assert_eq!(area.unit, m * m);
+
+    let speed = qty![100 m].checked_div(qty![20 s]).unwrap();
+    assert_eq!(speed.value, 5);
+    assert_eq!(speed.unit, m / s);
+    assert_eq!(qty![100 m].checked_div(qty![0 s]), None);
+}
+
+
+#[test]
+fn saturating_wrapping_arithmetic() {
+    let a = qty![250_u8 ms];
+
+    assert_eq!(a.saturating_add(qty![10 ms]), qty![255 ms]);
+    assert_eq!(a.saturating_add(qty![1 s]), qty![255 ms]);
+    assert_eq!(a.saturating_sub(qty![1 s]), qty![0 ms]);
+    assert_eq!(a.saturating_sub(qty![2 ms]), qty![248 ms]);
+    assert_eq!(a.saturating_mul(qty![2 ms]).value, 255);
+    assert_eq!(a.saturating_add(qty![1 us]), qty![250 ms]);
+
+    assert_eq!(a.wrapping_add(qty![10 ms]), qty![4 ms]);
+    assert_eq!(a.wrapping_sub(qty![251 ms]), qty![255 ms]);
+    assert_eq!(a.wrapping_mul(qty![2 ms]).value, 244);
+
+    let ticks = ns.quantity(u64::MAX);
+    assert_eq!(ticks.wrapping_add(qty![1 us]).value, 999);
+    assert_eq!(ticks.wrapping_add(qty![1 s]).value, 999_999_999);
+}
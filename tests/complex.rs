@@ -0,0 +1,92 @@
+//! Tests for quantities with complex values.
+
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+use dana::{
+    assert_qty_approx,
+    prelude::*,
+    symbols::{electrical::*, frequency::*},
+    value::Complex,
+};
+
+
+#[test]
+fn complex_value() {
+    let a = Complex::new(3.0, 4.0);
+    let b = Complex::new(1.0, -2.0);
+
+    assert_eq!(a + b, Complex::new(4.0, 2.0));
+    assert_eq!(a - b, Complex::new(2.0, 6.0));
+    assert_eq!(a * b, Complex::new(11.0, -2.0));
+    assert_eq!((a * b) / b, a);
+    assert_eq!(a * 2.0, Complex::new(6.0, 8.0));
+    assert_eq!(2.0 * a, Complex::new(6.0, 8.0));
+    assert_eq!(Complex::<f64>::i() * Complex::i(), Complex::real(-1.0));
+    assert_eq!(Complex::new(7.0, 5.0) % Complex::new(2.0, 2.0), Complex::new(1.0, -1.0));
+
+    assert_eq!(a.norm(), 5.0);
+    assert_eq!(a.conj(), Complex::new(3.0, -4.0));
+    assert_eq!(Complex::new(0.0, 1.0).arg(), FRAC_PI_2);
+
+    assert_eq!(a.to_string(), "3+4i");
+    assert_eq!(format!("{:.1}", b), "1.0-2.0i");
+
+    for (str, expected) in [
+        ("3+4i", a),
+        ("1-2j", b),
+        ("-2.5", Complex::real(-2.5)),
+        ("-i", Complex::new(0.0, -1.0)),
+        ("1e-3-1e+2i", Complex::new(1e-3, -1e2)),
+    ] {
+        assert_eq!(<Complex<f64> as num_traits::Num>::from_str_radix(str, 10).unwrap(), expected);
+    }
+}
+
+
+#[test]
+fn complex_quantity() {
+    let v = Quantity::from_polar(qty![10.0 V], FRAC_PI_4);
+    assert_qty_approx!(v.magnitude(), qty![10.0 V]);
+    assert!((v.phase() - FRAC_PI_4).abs() < 1e-12);
+    assert_eq!(v.conj().phase(), -v.phase());
+    assert_qty_approx!(v.re(), v.im());
+
+    let z = Ω.quantity(Complex::new(3.0, 4.0));
+    assert_eq!(z.to_string(), "3+4i Ω");
+    assert_eq!(z.with_unit(kΩ).value, Complex::new(0.003, 0.004));
+
+    //  Ohm's law and apparent power with phasors.
+    let i: Quantity<Current, Complex<f64>> = (v / z).convert();
+    assert_qty_approx!(i.magnitude(), qty![2.0 A]);
+
+    let s: Quantity<Power, Complex<f64>> = (v * i.conj()).convert();
+    assert_qty_approx!(s.magnitude(), qty![20.0 W]);
+    assert_qty_approx!(s.re(), qty![12.0 W]);
+    assert_qty_approx!(s.im(), qty![16.0 W]);
+}
+
+
+#[test]
+fn complex_impedance() {
+    let freq = qty![1.0 kHz];
+    let r = qty![100.0 Ω].impedance();
+    let l: Quantity<Resistance, Complex<f64>> = qty![10.0 mH].impedance(freq);
+    let c: Quantity<Resistance, Complex<f64>> = qty![2.0 μF].impedance(freq);
+
+    assert_eq!(r.value, Complex::real(100.0));
+    assert!((l.im().value - 62.83185307179586).abs() < 1e-9);
+    assert!((c.im().value + 79.57747154594767).abs() < 1e-9);
+    assert_eq!(l.phase(), FRAC_PI_2);
+    assert_eq!(c.phase(), -FRAC_PI_2);
+
+    //  At resonance, the reactances of a series LC circuit cancel out.
+    let f0 = Hz.quantity(1.0 / (core::f64::consts::TAU * (10e-3 * 2e-6f64).sqrt()));
+    let series = r + qty![10.0 mH].impedance(f0) + qty![2.0 μF].impedance(f0);
+    assert_qty_approx!(series.magnitude(), qty![100.0 Ω]);
+
+    //  Parallel combination of two equal resistors.
+    let parallel = (r.value * r.value) / (r.value + r.value);
+    assert_eq!(parallel, Complex::real(50.0));
+
+    assert_qty_approx!(qty![470.0 nF], qty![0.47 μF]);
+    assert_eq!(qty![1.0 H].with_unit(Inductance::MilliHenry).value, 1000.0);
+}
@@ -0,0 +1,65 @@
+//! Tests for the const-generic dimension representation.
+#![cfg(feature = "const_dim")]
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use num_traits::Inv;
+use typenum::{N1, N2, P1, P100, Z0};
+use dana::{dimension::{self as dim, *}, prelude::*, units::UnitAnon};
+
+
+type Length   = ConstDim<{ pack([1, 0,  0, 0, 0, 0, 0]) }>;
+type Time     = ConstDim<{ pack([0, 0,  1, 0, 0, 0, 0]) }>;
+type Velocity = ConstDim<{ pack([1, 0, -1, 0, 0, 0, 0]) }>;
+type Accel    = ConstDim<{ pack([1, 0, -2, 0, 0, 0, 0]) }>;
+type Area     = ConstDim<{ pack([2, 0,  0, 0, 0, 0, 0]) }>;
+
+
+#[test]
+fn test_const_dim() {
+    assert_eq!(Length::new().to_string(), "L");
+    assert_eq!(Velocity::new().to_string(), "L*T^-1");
+    assert_eq!(format!("{:#}", Accel::new()), "LT⁻²");
+
+    let _: Accel = Velocity::new() / Time::new();
+    let _: Accel = Velocity::new() * Time::new().inv();
+    let _: Length = Velocity::new() * Time::new();
+    let _: Area = <Length as CanDimPow<2>>::Output::new();
+    let _: Length = <Area as CanDimRoot<2>>::Output::new();
+    let _: Velocity = <Velocity as CanDimMul<ConstDim<{ pack([0; 7]) }>>>::Output::new();
+
+    //  Exponents should match the equivalent `Dimension`.
+    assert_eq!(DynDimension::of::<Accel>(), DynDimension::of::<dim::Accel>());
+    assert_eq!(Accel::ARRAY, dim::Accel::ARRAY);
+}
+
+
+#[test]
+fn test_const_dim_typenum() {
+    //  The typenum associated types should be the same as for a `Dimension`.
+    let _: <Accel as DimType>::ExpLen = P1::new();
+    let _: <Accel as DimType>::ExpMass = Z0::new();
+    let _: <Accel as DimType>::ExpTime = <dim::Accel as DimType>::ExpTime::new();
+    let _: <Velocity as DimType>::ExpTime = N1::new();
+    let _: <Accel as DimType>::ExpDen = P1::new();
+
+    //  Exponents should not be limited to the range of `HasTypenum`.
+    type Huge = ConstDim<{ pack([100, 0, -2, 0, 0, 0, 0]) }>;
+    let _: <Huge as DimType>::ExpLen = P100::new();
+    let _: <Huge as DimType>::ExpTime = N2::new();
+    assert_eq!(Huge::EXP_LEN, 100);
+
+    type Limit = ConstDim<{ pack([i16::MAX as i32, i16::MIN as i32, 0, 0, 0, 0, 0]) }>;
+    assert_eq!(<<Limit as DimType>::ExpLen as typenum::Integer>::I32, 32_767);
+    assert_eq!(<<Limit as DimType>::ExpMass as typenum::Integer>::I32, -32_768);
+}
+
+
+#[test]
+fn test_const_dim_quantity() {
+    let dist = Quantity::new(UnitAnon::<Length>::new(1e3), 3.0);
+    let time = Quantity::new(UnitAnon::<Time>::new(1.0), 60.0);
+
+    let speed: Quantity<UnitAnon<Velocity>> = (dist / time).convert_to(UnitAnon::new(1.0));
+    assert_eq!(speed.value, 50.0);
+}
@@ -1,3 +1,5 @@
+//! Tests for conversion between units and quantities.
+
 use num_traits::Inv;
 use dana::{prelude::*, symbols::{electrical::*, physics::*}};
 
@@ -30,6 +32,38 @@ fn test_anonymous() {
 }
 
 
+#[test]
+fn test_anonymous_to_unit() {
+    //  Exact matches should keep their scale.
+    assert_eq!(qty![2.0 km as ?].to_unit::<Length>().unit, Length::KiloMeter);
+    assert_eq!(qty![1.5 h as ?].to_unit::<Time>().unit, Time::Hour);
+    assert_eq!(qty![(qty![2.0 W] * qty![3.0 s]) as ?].to_unit::<Energy>(), qty![6.0 J]);
+
+    //  Including units outside the scale of the base unit.
+    let dist = Length::Mile.quantity(3.0).with_anonymous().to_unit::<Length>();
+    assert_eq!(dist, Length::Mile.quantity(3.0));
+    assert_eq!(dist.unit, Length::Mile);
+    let vol = Volume::Gallon.quantity(2.0).with_anonymous().to_unit::<Volume>();
+    assert_eq!(vol.unit, Volume::Gallon);
+    assert_eq!(vol.value, 2.0);
+
+    //  Otherwise, the quantity should be normalized.
+    let dist = qty![(qty![5.0 m] * 400.0) as ?];
+    assert_eq!(dist.to_unit::<Length>(), qty![2.0 km]);
+    let two_mi = UnitAnon::<<Length as Unit>::Dim>::new(Length::Mile.scale() * 2.0);
+    let dist = Quantity::new(two_mi, 1_250.0).to_unit::<Length>();
+    assert_eq!(dist.unit, Length::KiloMeter);
+    assert!(dist.almost_eq(qty![4_023.36 km], 1e-9));
+
+    //  Anonymous units should display as concrete units where possible.
+    assert_eq!(qty![2.0 km as ?].to_string(), "2 km");
+    assert_eq!(qty![2.0 W*s as ?].to_string(), "2 J");
+    assert_eq!(qty![2.0 km/h as ?].to_string(), "2 *0.2777777777777778");
+    assert_eq!(qty![(qty![2.0 m] / qty![1.0 m]) as ?].to_string(), "2 1");
+    assert_eq!(qty![2.0 nH as ?].to_string(), "2 nH");
+}
+
+
 #[test]
 fn test_f_ma() {
     let mass: Quantity<Mass> = qty![2.0 kg];
@@ -152,3 +186,74 @@ fn test_volume() {
     let density_water: Quantity<Density> = qty![0.997_048 kg/L];
     assert_eq!(qty![*[1.0 gal] * density_water in mg].round(), 3_774_237.0);
 }
+
+
+#[test]
+fn test_imperial() {
+    use dana::{
+        symbols::{
+            imperial::*, mass_us::{sh_cwt, sh_tn},
+            volume_si::L, volume_us::*, volume_us_dry::*,
+        },
+        units::system::*,
+    };
+
+    //  An imperial gallon is about 20% larger than a US gallon, so fuel economy
+    //      in miles per gallon must not be mixed between the two.
+    assert!((qty![*[1.0 imp_gal] in L] - 4.546_09f64).abs() < 1e-12);
+    let mpg_uk = qty![50.0 mi/imp_gal];
+    let mpg_us = qty![*(mpg_uk) in mi/gal];
+    assert!((mpg_us - 41.633_7f64).abs() < 1e-4);
+    assert!((qty![*[1.0 imp_pt] in imp_fl_oz] - 20.0f64).abs() < 1e-12);
+    assert!((qty![*[1.0 pt] in fl_oz] - 16.0f64).abs() < 1e-12);
+
+    //  US dry measures are distinct from liquid measures.
+    assert!((qty![*[1.0 bu] in pk] - 4.0f64).abs() < 1e-12);
+    assert!((qty![*[1.0 dry_pt] in pt] - 1.163_647f64).abs() < 1e-6);
+
+    //  Short tons are used in the US, and long tons in the UK.
+    assert!((qty![*[1.0 sh_tn] in lb] - 2_000.0f64).abs() < 1e-9);
+    assert!((qty![*[1.0 long_tn] in lb] - 2_240.0f64).abs() < 1e-9);
+    assert!((qty![*[1.0 long_cwt] in st] - 8.0f64).abs() < 1e-12);
+    assert!((qty![*[1.0 sh_cwt] in lb] - 100.0f64).abs() < 1e-9);
+    assert_eq!(qty![12.0 st].to_string(), "12 st");
+
+    //  Each system should choose its own variants.
+    assert_eq!(qty![1.0 T].into_system::<Imperial>().unit, Mass::LongTon);
+    assert_eq!(qty![1.0 T].into_system::<UsCustomary>().unit, Mass::ShortTon);
+    assert_eq!(qty![2.0 L].into_system::<Imperial>().unit, Volume::ImpPint);
+    assert_eq!(qty![1.0 imp_gal].into_system::<UsCustomary>().unit, Volume::Gallon);
+
+    //  Symbols containing spaces should be parsed whole.
+    assert_eq!("2 imp gal".parse(), Ok(Volume::ImpGallon.quantity(2.0)));
+    assert_eq!("3 sh tn".parse(), Ok(Mass::ShortTon.quantity(3.0)));
+    assert_eq!("1 long tn 4 long cwt".parse(), Ok(Mass::LongHundredweight.quantity(24.0)));
+}
+
+
+#[test]
+fn test_named() {
+    use dana::{symbols::volume_si::*, units::UnitNamed};
+
+    const SHOT: UnitNamed<UnitRescale<Volume>> =
+        UnitRescale::new(Volume::MilliLiter, 44.0).named("shot", "shot");
+
+    let bottle = qty![750.0 mL];
+    let shots = bottle.convert_to(SHOT);
+
+    assert_eq!(format!("{shots:.1}"), "17.0 shot");
+    assert_eq!(SHOT.to_string(), "shot");
+    assert_eq!(qty![*(SHOT.quantity(2.0)) in mL], 88.0);
+
+    //  Named units should compose with other units.
+    let rate = SHOT.quantity(3.0) / qty![1.0 h];
+    assert_eq!(rate.to_string(), "3 shot/h");
+
+    //  Parse with a list of named units.
+    let units = [SHOT, UnitRescale::new(Volume::MilliLiter, 750.0).named("btl", "bottle")];
+    let lookup = |sym: &str| UnitNamed::find(&units, sym);
+
+    assert_eq!(Quantity::<_, f64>::from_str_with("1btl 3shot", lookup), Ok(units[0].quantity(20.0 + 1.0 / 22.0)));
+    assert_eq!(Quantity::<_, f64>::from_str_with("2 bottle", lookup), Ok(units[1].quantity(2.0)));
+    assert!(Quantity::<_, f64>::from_str_with("2 pint", lookup).is_err());
+}
@@ -0,0 +1,9 @@
+//! Tests for compiler errors on operations between incompatible types.
+
+
+#[test]
+fn dim_invalid() {
+    let test = trybuild::TestCases::new();
+
+    test.compile_fail("tests/err_dim/*.rs");
+}
@@ -1,3 +1,5 @@
+//! Tests for dimension types and operations.
+
 use num_traits::Inv;
 use dana::dimension::*;
 
@@ -13,3 +15,128 @@ fn test_dimensions() {
     let _: Length = Velocity::new() * Time::new();
     let _: Torque = Length::new() * Force::new();
 }
+
+
+#[test]
+fn test_dimensions_rational() {
+    type RootLength = <Length as CanDimRoot<2>>::Output;
+    type RootHertz = <Frequency as CanDimRoot<2>>::Output;
+    type NoiseDensity = <Voltage as CanDimDiv<RootHertz>>::Output;
+
+    assert_eq!(format!("{}", RootLength::new()), "L^(1/2)");
+    assert_eq!(format!("{}", RootHertz::new()), "T^(-1/2)");
+    assert_eq!(format!("{}", NoiseDensity::new()), "L^2*M*T^(-5/2)*I^-1");
+
+    let _: Length = RootLength::new() * RootLength::new();
+    let _: Voltage = NoiseDensity::new() * RootHertz::new();
+    let _: RootLength = <Area as CanDimRoot<4>>::Output::new();
+
+    let root = DynDimension::of::<Length>().root(2);
+    assert_eq!(root, DynDimension::of::<RootLength>());
+    assert_eq!(root.pow(2), DynDimension::of::<Length>());
+    assert_eq!(root * root, DynDimension::of::<Length>());
+    assert_eq!(root.to_string(), "L^(1/2)");
+    assert!(!root.is_integer());
+}
+
+
+#[test]
+fn test_dimensions_extension() {
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Parts;
+
+    impl ExtAxis for Parts {
+        type Id = typenum::U1;
+        const SYMBOL: &'static str = "parts";
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Pixels;
+
+    impl ExtAxis for Pixels {
+        type Id = typenum::U2;
+        const SYMBOL: &'static str = "px";
+    }
+
+    type PartRate = DimensionExt<Frequency, Parts>;
+    type PixelDensity = <AxisDim<Pixels> as CanDimDiv<Area>>::Output;
+    type PixelsPerPart = <AxisDim<Pixels> as CanDimDiv<AxisDim<Parts>>>::Output;
+
+    assert_eq!(format!("{}", AxisDim::<Parts>::new()), "parts");
+    assert_eq!(format!("{}", PartRate::new()), "T^-1*parts");
+    assert_eq!(format!("{}", PixelDensity::new()), "L^-2*px");
+    assert_eq!(format!("{}", PixelsPerPart::new()), "parts^-1*px");
+    assert_eq!(format!("{:#}", PixelsPerPart::new()), "parts⁻¹·px");
+    assert_eq!(NamedDim::of::<PartRate>().to_string(), "[T⁻¹·parts]");
+    assert_eq!(NamedDim::of::<AxisDim<Pixels>>().name(), None);
+
+    //  Axes are kept in order, regardless of the order of operations.
+    let _: PartRate = AxisDim::<Parts>::new() / Time::new();
+    let _: PartRate = Frequency::new() * AxisDim::<Parts>::new();
+    let _: PixelsPerPart = AxisDim::<Parts>::new().inv() * AxisDim::<Pixels>::new();
+
+    //  Axes with zero exponents are removed.
+    let _: Frequency = PartRate::new() / AxisDim::<Parts>::new();
+    let _: Area = AxisDim::<Pixels>::new() / PixelDensity::new();
+    let _: One = PixelsPerPart::new() * AxisDim::<Parts>::new() / AxisDim::<Pixels>::new();
+
+    //  Powers and roots apply to every axis.
+    type SquaredRate = <PartRate as CanDimPow<2>>::Output;
+    assert_eq!(format!("{}", SquaredRate::new()), "T^-2*parts^2");
+    let _: PartRate = <SquaredRate as CanDimRoot<2>>::Output::new();
+    assert_eq!(PartRate::EXT.unwrap().exp, 1);
+    assert_eq!(SquaredRate::EXT.unwrap().exp, 2);
+    assert_eq!(Frequency::EXT, None);
+
+    //  Runtime dimensions keep the extension axes.
+    let rate = DynDimension::of::<PartRate>();
+    assert_ne!(rate, DynDimension::of::<Frequency>());
+    assert_eq!(rate / DynDimension::of::<AxisDim<Parts>>(), DynDimension::of::<Frequency>());
+    assert_eq!(rate.pow(2), DynDimension::of::<SquaredRate>());
+    assert_eq!(rate.pow(2).root(2), rate);
+    assert_eq!(rate.inv() * rate, DynDimension::ONE);
+    assert_eq!(rate.name(), None);
+    assert_eq!(rate.to_string(), "T^-1*parts");
+    assert_eq!(DynDimension::of::<PixelsPerPart>().named().to_string(), "[parts⁻¹·px]");
+
+    //  Anonymous units with extension axes are not shown as concrete units.
+    let anon = dana::units::UnitAnon::<PartRate>::new(1.0);
+    assert_eq!(anon.to_string(), "*1");
+}
+
+
+#[test]
+fn test_dimensions_named() {
+    assert_eq!(format!("{:#}", Energy::new()), "L²MT⁻²");
+    assert_eq!(format!("{:#}", Capacitance::new()), "L⁻²M⁻¹T⁴I²");
+    assert_eq!(format!("{:#}", One::new()), "1");
+    assert_eq!(format!("{}", One::new()), "");
+
+    //  Dimensions with the same exponents take the first name listed.
+    assert_eq!(DynDimension::of::<Torque>().name(), Some("Energy"));
+    assert_eq!(DynDimension::of::<Voltage>().named().to_string(), "Voltage [L²MT⁻³I⁻¹]");
+    assert_eq!(NamedDim::of::<Force>().to_string(), "Force [LMT⁻²]");
+
+    //  Dimensions without names only show their exponents.
+    let jerk = DynDimension::of::<Accel>() / DynDimension::of::<Time>();
+    assert_eq!(jerk.name(), None);
+    assert_eq!(jerk.named().to_string(), "[LT⁻³]");
+
+    //  Anonymous units and dimension errors show named dimensions.
+    let anon = dana::units::UnitAnon::<Energy>::new(1e3);
+    assert_eq!(format!("{anon:?}"), "UnitAnon(1000.0, \"Energy [L²MT⁻²]\")");
+
+    let err = dana::error::DimensionError::Mismatch {
+        expected: DynDimension::of::<Power>(),
+        found: jerk,
+    };
+    assert_eq!(err.to_string(), "expected dimension Power [L²MT⁻³], found [LT⁻³]");
+}
+
+
+#[test]
+fn test_dimensions_named_rational() {
+    type RootHertz = <Frequency as CanDimRoot<2>>::Output;
+    assert_eq!(format!("{:#}", RootHertz::new()), "T⁻¹ᐟ²");
+    assert_eq!(NamedDim::of::<RootHertz>().to_string(), "[T⁻¹ᐟ²]");
+}
@@ -0,0 +1,93 @@
+//! Tests for automatic differentiation with dual numbers.
+
+use dana::{
+    assert_qty_approx,
+    prelude::*,
+    quantity::{derivative, value_and_derivative},
+    symbols::{basic::*, physics::*, electrical::*},
+    value::Dual,
+};
+use num_traits::real::Real;
+
+
+#[test]
+fn dual_value() {
+    let x = Dual::variable(2.0);
+
+    assert_eq!(x * x, Dual::new(4.0, 4.0));
+    assert_eq!(x / x, Dual::new(1.0, 0.0));
+    assert_eq!(3.0 * x + 1.0, Dual::new(7.0, 3.0));
+    assert_eq!(x.powi(3), Dual::new(8.0, 12.0));
+    assert_eq!(x.to_string(), "2+1ε");
+    assert!(Dual::variable(1.0) < Dual::constant(2.0));
+
+    //  Ordering should only consider the real part, unlike equality.
+    assert_ne!(Dual::new(1.0, 0.0), Dual::new(1.0, 1.0));
+    assert_eq!(Dual::new(1.0, 0.0).partial_cmp(&Dual::new(1.0, 1.0)), Some(core::cmp::Ordering::Equal));
+    assert!(Dual::new(2.0, -5.0) > Dual::new(1.0, 5.0));
+
+    //  Compare every derivative against a central finite difference.
+    type Func = fn(Dual<f64>) -> Dual<f64>;
+
+    let cases: [(Func, f64); 12] = [
+        (|x| x.sqrt(), 2.0),
+        (|x| x.cbrt(), 2.0),
+        (|x| x.exp(), 0.5),
+        (|x| x.ln(), 0.5),
+        (|x| x.log10(), 0.5),
+        (|x| x.sin() * x.cos(), 0.3),
+        (|x| x.tan(), 0.3),
+        (|x| x.atan2(Dual::constant(2.0)), 0.7),
+        (|x| x.asin() + x.acos() * x, 0.4),
+        (|x| x.tanh() + x.asinh(), 0.4),
+        (|x| x.powf(Dual::constant(2.5)), 1.5),
+        (|x| x.powf(x), 1.5),
+    ];
+
+    for (i, (f, at)) in cases.into_iter().enumerate() {
+        let step = 1e-6;
+        let numeric = (f(Dual::constant(at + step)).re - f(Dual::constant(at - step)).re) / (2.0 * step);
+        let dual = f(Dual::variable(at)).eps;
+
+        assert!((dual - numeric).abs() < 1e-6, "case {i}: {dual} != {numeric}");
+    }
+}
+
+
+#[test]
+fn dual_derivative() {
+    //  Position under constant acceleration.
+    let fall = |t: Quantity<Time, Dual<f64>>| -> Quantity<Length, Dual<f64>> {
+        (qty![4.9 m/s^2] * t * t).convert()
+    };
+
+    let (x, v) = value_and_derivative(fall, qty![2.0 s]);
+    assert_qty_approx!(x, qty![19.6 m]);
+    assert_qty_approx!(v, qty![19.6 m/s]);
+    assert_eq!(v.unit, unit!(m/s));
+
+    //  The derivative should be in terms of the input unit.
+    let v = derivative(fall, qty![2000.0 ms]);
+    assert_eq!(v.unit, UnitDiv::new(Length::Meter, Time::MilliSecond));
+    assert_qty_approx!(v, qty![19.6 m/s]);
+
+    //  Heat loss through a wall, relative to the inside temperature.
+    let ambient = qty![273.15 K];
+    let conductance: Quantity<UnitDiv<Power, Temp>> = qty![12.5 W/K];
+
+    let loss = |inside: Quantity<Temp, Dual<f64>>| -> Quantity<Power, Dual<f64>> {
+        ((inside - ambient) * conductance).convert()
+    };
+
+    let dp_dt = derivative(loss, qty![293.15 K]);
+    assert_qty_approx!(dp_dt, conductance);
+
+    //  Joule heating, relative to current.
+    let heat = |i: Quantity<Current, Dual<f64>>| -> Quantity<Power, Dual<f64>> {
+        (i.squared() * qty![50.0 Ω]).convert()
+    };
+
+    let (p, dp_di) = value_and_derivative(heat, qty![2.0 A]);
+    assert_qty_approx!(p, qty![200.0 W]);
+    assert_qty_approx!(dp_di, qty![200.0 W/A]);
+}
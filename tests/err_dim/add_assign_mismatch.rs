@@ -0,0 +1,6 @@
+use dana::{qty, symbols::basic::*};
+
+fn main() {
+    let mut time = qty![1.0 s];
+    time += qty![1.0 m];
+}
@@ -0,0 +1,5 @@
+use dana::{prelude::*, symbols::basic::*};
+
+fn main() {
+    let _err = qty![1.0 m].pow::<100>();
+}
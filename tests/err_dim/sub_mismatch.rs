@@ -0,0 +1,5 @@
+use dana::{qty, symbols::basic::*};
+
+fn main() {
+    let _err = qty![1.0 kg] - qty![1.0 s];
+}
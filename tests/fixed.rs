@@ -0,0 +1,131 @@
+//! Tests for fixed-point values and float-free unit conversion.
+
+use dana::{
+    prelude::*,
+    symbols::{basic::*, length_us::*},
+    units::ScaleRatio,
+    value::{Fixed32, Fixed64},
+};
+use num_traits::{FromPrimitive, Num};
+
+
+#[test]
+fn fixed_value() {
+    let a = Fixed32::from_int(3);
+    let b = Fixed32::from_str_radix("0.25", 10).unwrap();
+
+    assert_eq!(a.to_bits(), 3 << 16);
+    assert_eq!(b.to_bits(), 1 << 14);
+    assert_eq!(a + b, Fixed32::from_bits(13 << 14));
+    assert_eq!(a * b, Fixed32::from_str_radix("0.75", 10).unwrap());
+    assert_eq!(a / b, Fixed32::from_int(12));
+    assert_eq!(-a % Fixed32::from_int(2), Fixed32::from_int(-1));
+    assert_eq!((-a - b).floor(), Fixed32::from_int(-4));
+    assert_eq!((-a - b).fract(), Fixed32::from_str_radix("0.75", 10).unwrap());
+
+    //  Division should round to the nearest step.
+    let third = Fixed32::ONE / Fixed32::from_int(3);
+    assert_eq!(third.to_bits(), 21845);
+    assert_eq!((Fixed32::from_int(2) / Fixed32::from_int(3)).to_bits(), 43691);
+
+    //  Overflow is reported by the checked operations.
+    assert_eq!(Fixed32::MAX.checked_add(Fixed32::DELTA), None);
+    assert_eq!(Fixed32::from_int(256).checked_mul(Fixed32::from_int(256)), None);
+    assert_eq!(Fixed32::ONE.checked_div(Fixed32::ZERO), None);
+    assert_eq!(Fixed32::from_i64(40_000), None);
+    assert_eq!(Fixed64::from_i64(40_000), Some(Fixed64::from_int(40_000)));
+    assert_eq!(Fixed32::MIN.checked_abs(), None);
+    assert_eq!(Fixed32::MIN.wrapping_abs(), Fixed32::MIN);
+    assert_eq!((-a).checked_abs(), Some(a));
+    assert_eq!((-a).abs(), a);
+
+    //  Display should use the fewest digits that round-trip.
+    assert_eq!(a.to_string(), "3");
+    assert_eq!((-a - b).to_string(), "-3.25");
+    assert_eq!(third.to_string(), "0.33333");
+    assert_eq!(Fixed32::DELTA.to_string(), "0.00002");
+    assert_eq!(format!("{:.3}", third), "0.333");
+    assert_eq!(format!("{:.0}", Fixed32::from_str_radix("2.5", 10).unwrap()), "3");
+    assert_eq!(format!("{:.20}", Fixed32::DELTA), "0.00001525878906250000");
+    assert_eq!(Fixed64::from_str_radix("0.1", 10).unwrap().to_string(), "0.1");
+
+    for bits in [1, 7, 21845, 65535, -1, -98304, i32::MAX, i32::MIN] {
+        let x = Fixed32::from_bits(bits);
+        assert_eq!(Fixed32::from_str_radix(&x.to_string(), 10), Ok(x));
+    }
+
+    assert_eq!(Fixed32::from_str_radix("", 10), Err(dana::error::ParseFixedError::Empty));
+    assert_eq!(Fixed32::from_str_radix("1.2x", 10), Err(dana::error::ParseFixedError::InvalidDigit));
+    assert_eq!(Fixed32::from_str_radix("32768", 10), Err(dana::error::ParseFixedError::Overflow));
+    assert_eq!(Fixed32::from_str_radix("-32768", 10), Ok(Fixed32::MIN));
+}
+
+
+#[test]
+fn fixed_conversion() {
+    assert_eq!(mm.scale_factor_ratio(m), Some(ScaleRatio::new(1, 1000)));
+    assert_eq!(m.scale_factor_ratio(inch), Some(ScaleRatio::new(5000, 127)));
+    assert_eq!((km / h).scale_ratio(), Some(ScaleRatio::new(5, 18)));
+    assert_eq!(m.squared().scale_factor_ratio(mm.squared()), Some(ScaleRatio::int(1_000_000)));
+
+    //  Millimeters to meters, without floating point.
+    let len = mm.quantity(Fixed32::from_int(1250));
+    let len_m = len.convert_to_ratio(m).unwrap();
+    assert_eq!(len_m.value, Fixed32::from_str_radix("1.25", 10).unwrap());
+    assert_eq!(len_m.unit, m);
+
+    //  Integer values are also supported, but truncate.
+    assert_eq!(qty![3 km].value_as_ratio(m), Some(3000));
+    assert_eq!(qty![1999 mm].value_as_ratio(m), Some(1));
+    assert_eq!(qty![72 km/h].value_as_ratio(m/s), Some(20));
+
+    //  A ratio that the value type cannot represent is reported.
+    assert_eq!(mm.quantity(Fixed32::from_int(100)).value_as_ratio(km), None);
+    assert_eq!(mm.quantity(Fixed64::from_int(100)).value_as_ratio(km), Some(Fixed64::from_bits(429_497)));
+    assert_eq!(km.quantity(Fixed64::from_int(100)).value_as_ratio(m), Some(Fixed64::from_int(100_000)));
+    assert_eq!(qty![5_000_000_000_u64 mm].value_as_ratio(m/s*s), Some(5_000_000));
+
+    let speed = (km/h).quantity(Fixed32::from_int(90));
+    assert_eq!(speed.with_unit_ratio(m/s).unwrap().value, Fixed32::from_int(25));
+}
+
+
+#[test]
+#[should_panic = "fixed-point absolute value overflowed"]
+fn fixed_abs_overflow() {
+    let _ = Fixed32::MIN.abs();
+}
+
+
+#[test]
+fn fixed_scale_ratios() {
+    //  Every exact ratio should agree with the float scale of its unit.
+    fn check<U: UnitConcrete + core::fmt::Debug>() {
+        for &unit in U::VARIANTS {
+            let Some(ratio) = unit.scale_ratio() else { continue };
+            let (exact, scale) = (ratio.to_f64(), unit.scale());
+
+            assert!((exact - scale).abs() <= scale * 1e-12, "{unit:?}: {ratio} != {scale}");
+        }
+    }
+
+    check::<One>();
+    check::<Length>();
+    check::<Mass>();
+    check::<Time>();
+    check::<Frequency>();
+    check::<Temp>();
+    check::<Amount>();
+    check::<Intensity>();
+    check::<Force>();
+    check::<Volume>();
+    check::<Pressure>();
+    check::<Energy>();
+    check::<Power>();
+    check::<Charge>();
+    check::<Current>();
+    check::<Voltage>();
+    check::<Resistance>();
+    check::<Capacitance>();
+    check::<Inductance>();
+}
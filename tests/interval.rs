@@ -0,0 +1,91 @@
+//! Tests for quantities with interval values.
+
+use dana::{prelude::*, symbols::{basic::*, length_us::*}, value::Interval};
+use num_traits::{Inv, Num};
+
+
+#[test]
+fn interval_value() {
+    let a = Interval::new(1.0, 2.0);
+    let b = Interval::new(-3.0, 0.5);
+
+    //  Exact operations should not be widened.
+    assert_eq!(a + b, Interval::new(-2.0, 2.5));
+    assert_eq!(a - b, Interval::new(0.5, 5.0));
+    assert_eq!(a * b, Interval::new(-6.0, 1.0));
+    assert_eq!(-b, Interval::new(-0.5, 3.0));
+    assert_eq!(b.powi(2), Interval::new(0.0, 9.0));
+    assert_eq!(b.abs(), Interval::new(0.0, 3.0));
+    assert_eq!(Interval::new(4.0, 9.0).sqrt(), Interval::new(2.0, 3.0));
+    assert_eq!(a / b, Interval::entire());
+
+    //  Inexact operations should be widened outward.
+    let third = Interval::point(1.0) / Interval::point(3.0);
+    assert!(third.lo < third.hi);
+    assert!(third.lo <= 1.0 / 3.0 && 1.0 / 3.0 <= third.hi);
+
+    let tenth = Interval::<f64>::from_str_radix("0.1", 10).unwrap();
+    let sum = tenth + tenth + tenth;
+    assert!(sum.contains(0.3) && sum.contains(0.1 + 0.1 + 0.1));
+
+    let two = Interval::point(2.0).sqrt();
+    assert!(two.contains(core::f64::consts::SQRT_2));
+    assert!((two * two).contains(2.0));
+
+    let cube = Interval::new(8.0, 27.0).powf(Interval::point(3.0).inv());
+    assert!(cube.contains(2.0) && cube.contains(3.0));
+    assert!(cube.width() < 1.0 + 1e-12);
+
+    assert_eq!(Interval::new(0.0, 1.0).partial_cmp(&Interval::new(2.0, 3.0)), Some(core::cmp::Ordering::Less));
+    assert_eq!(Interval::new(0.0, 2.0).partial_cmp(&Interval::new(1.0, 3.0)), None);
+
+    assert_eq!(a.to_string(), "[1, 2]");
+    assert_eq!(Interval::<f64>::from_str_radix("[1, 2]", 10).unwrap().midpoint(), 1.5);
+}
+
+
+#[test]
+fn interval_quantity() {
+    let x = qty![12.0 inch].with_tolerance(qty![1.0 mm]);
+    assert!(x.contains(qty![1.0 ft]));
+    assert!(x.contains(qty![305.7 mm]));
+    assert!(!x.contains(qty![306.0 mm]));
+
+    //  Bounds should survive conversion, even with inexact factors.
+    let y = x.with_unit(Length::Meter).with_unit(Length::Inch);
+    assert!(y.value.contains_interval(&x.value));
+    assert!(y.width() < x.width() + qty![1e-12 inch]);
+
+    let z = Quantity::between(qty![1.0 m], qty![150.0 cm]);
+    assert_eq!(z.lo(), qty![1.0 m]);
+    assert!(z.contains(qty![150.0 cm]));
+    assert!(z.hi() - qty![1.5 m] < qty![1e-12 m]);
+}
+
+
+#[test]
+fn interval_braking() {
+    //  Stopping distance of a vehicle, for all inputs within tolerance:
+    //      d = v*t + v²/(2a)
+    let speed = qty![100.0 km/h].with_tolerance(qty![5.0 km/h]);
+    let reaction = qty![1.5 s].with_tolerance(qty![0.1 s]);
+    let decel = Quantity::between(qty![6.5 m/s^2], qty![7.5 m/s^2]);
+
+    let thinking: Quantity<Length, Interval<f64>> = (speed * reaction).convert();
+    let braking: Quantity<Length, Interval<f64>> = (speed.pow::<2>() / (decel * 2.0)).convert();
+    let total = thinking + braking;
+
+    assert!(total.is_below(qty![120.0 m]));
+    assert!(!total.is_below(qty![100.0 m]));
+    assert!(total.is_above(qty![60.0 m]));
+
+    //  Every sampled point must be within the bounds.
+    for v in [95.0, 100.0, 105.0] {
+        for t in [1.4, 1.5, 1.6] {
+            for a in [6.5, 7.0, 7.5] {
+                let v = v / 3.6;
+                assert!(total.contains(m.quantity(v * t + v * v / (2.0 * a))));
+            }
+        }
+    }
+}
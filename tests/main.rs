@@ -1,9 +1,11 @@
+//! General tests for units and quantities.
+
 use num_traits::Inv;
 use dana::{assert_qty_approx, prelude::*, symbols::basic::*};
 
 
 #[test]
-pub fn test_macros() {
+fn test_macros() {
     //  Start with a basic length unit, and ensure `unit!` correctly
     //      produces one.
     let u: Length = m;
@@ -114,6 +116,127 @@ fn test_quantity_norm() {
 }
 
 
+#[test]
+fn test_quantity_norm_with() {
+    use dana::quantity::NormalizeOptions;
+
+    //  Values just below a threshold should stay in the smaller unit.
+    let q = qty![0.9 km].normalize();
+    assert_eq!(q.unit, Length::Meter);
+    assert_qty_approx!(q, qty![900.0 m]);
+
+    //  Negative values should be normalized by magnitude.
+    let q = qty![-2500.0 m].normalize();
+    assert_eq!(q.unit, Length::KiloMeter);
+
+    //  Custom target range.
+    let q = qty![250.0 m].normalize_with(&NormalizeOptions::new().with_range(0.1, 100.0));
+    assert_eq!(q.unit, Length::KiloMeter);
+    assert_qty_approx!(q, qty![0.25 km]);
+
+    //  Engineering notation should skip centimeters.
+    let q = qty![2.5 cm].normalize_with(&NormalizeOptions::new().engineering());
+    assert_eq!(q.unit, Length::MilliMeter);
+    assert_qty_approx!(q, qty![25.0 mm]);
+
+    //  SI only should move away from imperial units.
+    let q = Length::Foot.quantity(5280.0).normalize_with(&NormalizeOptions::new().si_only());
+    assert_eq!(q.unit, Length::KiloMeter);
+    assert_qty_approx!(<= 1e-9, q, qty![1.609344 km]);
+
+    //  Compound units should be normalized through their components.
+    assert_eq!(qty![36_000.0 m/h].normalize().unit, unit!(km/h));
+    assert_eq!(qty![1.0e6 m^2].normalize().unit, unit!(km^2));
+    assert_eq!(qty![5.0e-4/s].normalize().unit, PerUnit(h));
+
+    //  Numerator only should leave the denominator alone.
+    let q = qty![36_000.0 m/h].normalize_with(&NormalizeOptions::new().numerator_only());
+    assert_eq!(q.unit, unit!(km/h));
+    assert_qty_approx!(q, qty![36.0 km/h]);
+}
+
+
+#[test]
+fn test_quantity_best_of() {
+    use dana::units::traits::UnitAny;
+
+    let metric = [Length::MilliMeter, Length::CentiMeter, Length::Meter, Length::KiloMeter];
+    let imperial = [Length::Inch, Length::Foot, Length::Mile];
+
+    //  The largest unit that keeps the value in range should be chosen.
+    assert_eq!(qty![2.5 cm].best_of(&metric, 1.0..1000.0).unwrap().unit, Length::CentiMeter);
+    assert_eq!(qty![-3.0 km].best_of(&metric, 1.0..1000.0).unwrap(), qty![-3.0 km]);
+    assert_eq!(qty![2.0 m].best_of(&imperial, 1.0..1000.0).unwrap().unit, Length::Foot);
+    assert_eq!(qty![20.0 km].best_of(&imperial, 1.0..1000.0).unwrap().unit, Length::Mile);
+
+    //  Values above the range should be preferred over values below it.
+    assert_eq!(qty![5_000.0 km].best_of(&[Length::Meter], 1.0..1000.0).unwrap().unit, Length::Meter);
+    assert_eq!(qty![0.5 mm].best_of(&metric, 1.0..1000.0).unwrap().unit, Length::MilliMeter);
+    assert_eq!(qty![2.0 km].best_of(&[Length::MilliMeter, Length::Mile], 1.0..1000.0).unwrap().unit, Length::Mile);
+    assert!(qty![2.0 km].best_of::<Length>(&[], 1.0..1000.0).is_none());
+
+    //  Other ranges should be respected.
+    assert_eq!(qty![2.5 cm].best_of(&metric, 10.0..10_000.0).unwrap().unit, Length::MilliMeter);
+    assert_eq!(qty![1.5 km].best_of(&metric, 2.0..2000.0).unwrap().unit, Length::Meter);
+    assert_eq!(qty![2.0 m].best_of(&imperial, 0.1..100.0).unwrap().unit, Length::Foot);
+
+    //  Units of different types should be usable together.
+    let hand = UnitRescale::new(Length::Inch, 4.0);
+    let units: [&dyn UnitAny<_>; 3] = [&Length::MilliMeter, &hand, &Length::Meter];
+
+    let (unit, value) = qty![60.96 cm].best_of_any(&units, 1.0..1000.0).unwrap();
+    assert_eq!(unit.to_string(), hand.to_string());
+    assert!((value - 6.0f64).abs() < 1e-9);
+
+    let (unit, _) = qty![1.5 km].best_of_any(&units, 1.0..1000.0).unwrap();
+    assert_eq!(unit.to_string(), "m");
+}
+
+
+#[test]
+fn test_unit_system() {
+    use dana::{quantity::NormalizeOptions, units::system::*};
+
+    //  Concrete units should map to the nearest unit in each system.
+    let q = qty![2.0 m].into_system::<UsCustomary>();
+    assert_eq!(q.unit, Length::Foot);
+    assert_qty_approx!(<= 1e-12, q, qty![2.0 m]);
+    assert_qty_approx!(<= 1e-12, Length::Foot.quantity(3.0).into_system::<Si>(), qty![0.9144 m]);
+    assert_eq!(qty![2.0 kg].into_system::<Imperial>().unit, Mass::Pound);
+    assert_eq!(qty![2.0 kg].into_system::<Cgs>(), Mass::Gram.quantity(2_000.0));
+
+    //  Units with no system-specific variants should be unchanged.
+    assert_eq!(qty![2.0 h].into_system::<UsCustomary>(), qty![2.0 h]);
+    assert!(InSystem::<Imperial>::is_in_system(&Time::Hour));
+    assert!(!InSystem::<Si>::is_in_system(&Length::Mile));
+
+    //  Compound units should map each of their components.
+    let accel = qty![9.8 m/s^2].into_system::<UsCustomary>();
+    assert_eq!(accel.unit, Length::Foot / Time::Second.squared());
+    let torque = Quantity::new(Force::Newton * Length::Meter, 1.0);
+    assert_eq!(torque.into_system::<Cgs>().unit, Force::Dyne * Length::CentiMeter);
+    let q = Pressure::KiloPascal.quantity(3.0).into_system::<Cgs>();
+    assert_eq!(q.unit, Pressure::Barye);
+    assert_qty_approx!(<= 1e-9, q, Pressure::Barye.quantity(30_000.0));
+    let q = Energy::Joule.quantity(2.0).into_system::<Cgs>();
+    assert_eq!(q.unit, Energy::Erg);
+    assert_qty_approx!(<= 1e-9, q, Energy::Erg.quantity(2e7));
+    assert_eq!(Energy::Erg.quantity(10.0).into_system::<Si>().unit, Energy::MicroJoule);
+
+    //  The system may be chosen at runtime.
+    for system in MeasurementSystem::ALL {
+        let q = qty![5.0 km/h].into_measurement_system(system);
+        assert_qty_approx!(<= 1e-12, q, qty![5.0 km/h]);
+    }
+    assert_eq!(MeasurementSystem::UsCustomary.to_string(), "US customary");
+
+    //  Normalization may be restricted to a system.
+    let options = NormalizeOptions::new().system_only::<UsCustomary>();
+    let q = qty![5_000.0 m].normalize_with(&options);
+    assert_eq!(q.unit, Length::Mile);
+}
+
+
 #[test]
 fn test_unit_step() {
     fn test<U: UnitStep + PartialOrd + core::fmt::Debug>() {
@@ -131,15 +254,130 @@ fn test_unit_step() {
     test::<Amount>();
     test::<Intensity>();
 
+    test::<Capacitance>();
+    test::<Charge>();
+    test::<Energy>();
+    test::<Force>();
+    test::<Frequency>();
+    test::<Inductance>();
+    test::<One>();
+    test::<Power>();
+    test::<Pressure>();
+    test::<Resistance>();
+    test::<Voltage>();
+}
+
+
+#[test]
+fn test_unit_variants() {
+    fn test<U: UnitConcrete + core::fmt::Debug>() {
+        assert!(
+            U::VARIANTS.windows(2).all(|w| w[0].scale() < w[1].scale()),
+            "variants are not sorted",
+        );
+
+        for unit in U::VARIANTS {
+            assert_eq!(U::from_symbol(unit.symbol()), Some(*unit));
+        }
+    }
+
+    test::<Length>();
+    test::<Mass>();
+    test::<Time>();
+    test::<Current>();
+    test::<Temp>();
+    test::<Amount>();
+    test::<Intensity>();
+
+    test::<Capacitance>();
     test::<Charge>();
     test::<Energy>();
     test::<Force>();
     test::<Frequency>();
+    test::<Inductance>();
     test::<One>();
     test::<Power>();
     test::<Pressure>();
     test::<Resistance>();
     test::<Voltage>();
+    test::<Volume>();
+}
+
+
+#[test]
+fn test_unit_step_table() {
+    /// Find the floor unit by stepping one variant at a time.
+    fn walk<U: UnitStep>(mut unit: U, scale: f64) -> U {
+        while unit.scale() > scale {
+            match unit.step_down() {
+                Some(next) => unit = next,
+                None => return unit,
+            }
+        }
+
+        while let Some(next) = unit.step_up() {
+            if next.scale() > scale { break; }
+            unit = next;
+        }
+
+        unit
+    }
+
+    fn test<U: UnitStep + core::fmt::Debug>(units: &[U]) {
+        let targets = (-60..=90).map(|e| 10f64.powf(e as f64 / 3.0));
+
+        for unit in units {
+            for target in targets.clone() {
+                assert_eq!(unit.step_to_floor(target), walk(*unit, target));
+            }
+
+            let Some(table) = unit.step_table() else { continue };
+
+            assert!(
+                table.windows(2).all(|w| w[0].scale() < w[1].scale()),
+                "table for {unit:?} is not sorted",
+            );
+
+            let i = table.iter().position(|u| u == unit)
+                .expect("unit is missing from its own table");
+
+            let mut down = *unit;
+            for expected in table[..i].iter().rev() {
+                down = down.step_down().unwrap();
+                assert_eq!(down, *expected);
+            }
+            assert_eq!(down.step_down(), None);
+
+            let mut up = *unit;
+            for expected in &table[i + 1..] {
+                up = up.step_up().unwrap();
+                assert_eq!(up, *expected);
+            }
+            assert_eq!(up.step_up(), None);
+        }
+    }
+
+    test(&[Length::Meter, Length::CentiMeter, Length::Inch, Length::Foot, Length::Yard]);
+    test(&[Mass::Gram, Mass::KiloGram, Mass::Grain, Mass::Pound, Mass::SolarMass]);
+    test(&[Mass::Stone, Mass::ShortHundredweight, Mass::ShortTon, Mass::LongTon]);
+    test(&[Time::Second, Time::Minute, Time::Day]);
+    test(&[Volume::Liter, Volume::MilliLiter, Volume::Cup, Volume::Gallon]);
+    test(&[Volume::ImpFlOunce, Volume::ImpGallon, Volume::DryPint, Volume::Bushel]);
+    test(&[Force::Newton, Force::Dyne, Force::Ounce, Force::Pound]);
+    test(&[Pressure::Pascal, Pressure::Barye, Pressure::Psi, Pressure::MegaPsi]);
+    test(&[Energy::Joule, Energy::ElectronVolt, Energy::Erg]);
+    test(&[Current::Amp]);
+    test(&[Temp::Kelvin]);
+    test(&[Amount::Mole]);
+    test(&[Voltage::Volt]);
+    test(&[Power::Watt]);
+    test(&[Charge::Coulomb]);
+    test(&[Resistance::Ohm]);
+    test(&[Capacitance::Farad, Capacitance::PicoFarad]);
+    test(&[Inductance::Henry]);
+    test(&[Intensity::Candela]);
+    test(&[Frequency::Hertz]);
+    test(&[One]);
 }
 
 
@@ -176,6 +414,8 @@ fn test_scale() {
     for unit in [
         Volume::Dram, Volume::FlOunce, Volume::Cup,
         Volume::Pint, Volume::Quart, Volume::Gallon,
+        Volume::ImpFlOunce, Volume::ImpPint, Volume::ImpGallon,
+        Volume::DryPint, Volume::DryQuart, Volume::Peck, Volume::Bushel,
     ] {
         assert_qty_approx!(
             qty![1.0 L in unit].cbrt(), qty![0.1 m],
@@ -189,6 +429,139 @@ fn test_scale() {
 }
 
 
+#[test]
+fn test_quantity_split() {
+    const HMS: [Time; 3] = [Time::Hour, Time::Minute, Time::Second];
+
+    let [hours, minutes, seconds] = qty![5_004.5 s].split_into(&HMS);
+    assert_eq!(hours, qty![1.0 h]);
+    assert_eq!(minutes, qty![23.0 min]);
+    assert_eq!(seconds, qty![24.5 s]);
+
+    //  Conversion factors that are not exact in floating point should still
+    //      produce whole components.
+    let [feet, inches] = qty![2.0 m].split_into(&[Length::Foot, Length::Inch]);
+    assert_eq!(feet, Length::Foot.quantity(6.0));
+    assert_qty_approx!(inches, Length::Inch.quantity(6.740_157_480_314_96));
+
+    //  Negative quantities should have every component negative.
+    let [hours, minutes] = qty![-90.0 min].split_into(&[Time::Hour, Time::Minute]);
+    assert_eq!(hours, qty![-1.0 h]);
+    assert_eq!(minutes, qty![-30.0 min]);
+
+    //  Zero components should be skipped when displayed.
+    assert_eq!(qty![5_004.5 s].display_split(&HMS).to_string(), "1 h 23 min 24.5 s");
+    assert_eq!(qty![3_604.0 s].display_split(&HMS).to_string(), "1 h 4 s");
+    assert_eq!(qty![-90.0 s].display_split(&HMS).to_string(), "-1 min 30 s");
+    assert_eq!(qty![0.0 s].display_split(&HMS).to_string(), "0 s");
+    assert_eq!(format!("{:.1}", qty![61.0 s].display_split(&HMS)), "1 min 1.0 s");
+}
+
+
+#[test]
+fn test_quantity_parse() {
+    use dana::error::ParseQuantityError;
+
+    let parse = |text: &str| text.parse::<Quantity<Length>>();
+
+    assert_eq!(parse("5ft 3in"), Ok(Length::Inch.quantity(63.0)));
+    assert_eq!(parse("5 ft 3 in"), Ok(Length::Inch.quantity(63.0)));
+    assert_eq!(parse("1.5 km"), Ok(qty![1.5 km]));
+    assert_eq!(parse("-2m 50cm"), Ok(qty![-250.0 cm]));
+
+    assert_eq!("1h30m".parse(), Ok(qty![90.0 min]));
+    assert_eq!("1h 30min 15s".parse(), Ok(qty![5_415.0 s]));
+
+    assert_eq!(parse(""), Err(ParseQuantityError::Empty));
+    assert_eq!(parse("ft"), Err(ParseQuantityError::InvalidValue));
+    assert_eq!(parse("5"), Err(ParseQuantityError::MissingUnit));
+    assert_eq!(parse("5 furlongs"), Err(ParseQuantityError::UnknownUnit));
+    assert_eq!(parse("1.2.3 m"), Err(ParseQuantityError::InvalidValue));
+
+    //  Integer values should be converted exactly, or not at all.
+    let parse_int = |text: &str| text.parse::<Quantity<Length, u32>>();
+    assert_eq!(parse_int("5 ft 3 in"), Ok(Length::Inch.quantity(63)));
+    assert_eq!(parse_int("2 m 50 cm"), Ok(Length::CentiMeter.quantity(250)));
+    assert_eq!(parse_int("-3 m"), Err(ParseQuantityError::NegativeValue));
+    assert_eq!(parse_int("1 in 1 ft"), Err(ParseQuantityError::InexactValue));
+    assert_eq!(parse_int("24 in 1 ft"), Ok(Length::Foot.quantity(3)));
+    assert_eq!("-3 m".parse::<Quantity<Length, i32>>(), Ok(Length::Meter.quantity(-3)));
+
+    //  Parsing should reverse displaying a split quantity.
+    let q = qty![5_004.5 s];
+    let text = q.display_split(&[Time::Hour, Time::Minute, Time::Second]).to_string();
+    assert_eq!(text.parse(), Ok(q));
+
+    //  Parsing should reverse displaying every unit, including symbols that
+    //      contain spaces.
+    fn round_trip<U: UnitConcrete + core::fmt::Debug>() {
+        for &unit in U::VARIANTS {
+            let q = unit.quantity(2.5);
+            assert_eq!(q.to_string().parse::<Quantity<U>>(), Ok(q), "failed for {unit:?}");
+        }
+    }
+
+    round_trip::<Length>();
+    round_trip::<Mass>();
+    round_trip::<Time>();
+    round_trip::<Current>();
+    round_trip::<Temp>();
+    round_trip::<Amount>();
+    round_trip::<Intensity>();
+    round_trip::<Capacitance>();
+    round_trip::<Charge>();
+    round_trip::<Energy>();
+    round_trip::<Force>();
+    round_trip::<Frequency>();
+    round_trip::<Inductance>();
+    round_trip::<Power>();
+    round_trip::<Pressure>();
+    round_trip::<Resistance>();
+    round_trip::<Voltage>();
+    round_trip::<Volume>();
+
+    assert_eq!("2 fl oz".parse(), Ok(Volume::FlOunce.quantity(2.0)));
+    assert_eq!("1 gal 2 fl oz".parse(), Ok(Volume::FlOunce.quantity(130.0)));
+}
+
+
+#[test]
+fn test_quantity_quantize() {
+    //  Snap to a step in a different unit.
+    assert_qty_approx!(qty![12.34 mm].round_to(qty![0.5 mm]), qty![12.5 mm]);
+    assert_qty_approx!(qty![1.234 cm].round_to(qty![0.5 mm]), qty![1.25 cm]);
+    assert_qty_approx!(qty![1.234 cm].floor_to(qty![0.5 mm]), qty![1.2 cm]);
+    assert_qty_approx!(qty![1.234 cm].ceil_to(qty![0.5 mm]), qty![1.25 cm]);
+    assert_eq!(qty![1.234 cm].round_to(qty![0.5 mm]).unit, Length::CentiMeter);
+
+    //  Negative values should round towards the appropriate infinity.
+    assert_qty_approx!(qty![-1.2 m].floor_to(qty![0.5 m]), qty![-1.5 m]);
+    assert_qty_approx!(qty![-1.2 m].ceil_to(qty![0.5 m]), qty![-1.0 m]);
+
+    //  Remainder between quantities.
+    assert_qty_approx!(qty![100.0 min] % qty![1.0 h], qty![40.0 min]);
+    assert_qty_approx!(qty![-100.0 min] % qty![1.0 h], qty![-40.0 min]);
+
+    let mut q = qty![7.0 m];
+    q %= qty![200.0 cm];
+    assert_qty_approx!(q, qty![1.0 m]);
+
+    //  Euclidean division should count whole increments.
+    let (count, rest) = qty![1.0 h].div_euclid(qty![15.0 min]);
+    assert_eq!(count, 4.0);
+    assert_qty_approx!(rest, qty![0.0 h]);
+
+    let (count, rest) = qty![100.0 min].div_euclid(qty![15.0 min]);
+    assert_eq!(count, 6.0);
+    assert_qty_approx!(rest, qty![10.0 min]);
+
+    let (count, rest) = qty![-100.0 min].div_euclid(qty![1.0 h]);
+    assert_eq!(count, -2.0);
+    assert_qty_approx!(rest, qty![20.0 min]);
+    assert_qty_approx!(qty![-100.0 min].rem_euclid(qty![1.0 h]), qty![20.0 min]);
+}
+
+
 #[test]
 fn test_rescale() {
     //  Simple rescaled operations.
@@ -0,0 +1,123 @@
+//! Tests for dimensioned vectors and matrices.
+
+use dana::{
+    assert_qty_approx,
+    dimension::{self as dim, DynDimension},
+    error::DimensionError,
+    matrix::*,
+    prelude::*,
+    symbols::basic::*,
+};
+
+
+#[test]
+fn test_matrix_dimensions() {
+    let state = dims::<(dim::Length, dim::Velocity)>();
+    assert_eq!(state, [DynDimension::of::<dim::Length>(), DynDimension::of::<dim::Velocity>()]);
+    assert_eq!(state[1].to_string(), "L*T^-1");
+    assert_eq!((state[0] / state[1]), DynDimension::of::<dim::Time>());
+    assert_eq!(DynDimension::from(dim::Velocity::new()).pow(2), state[1] * state[1]);
+
+    let mut x = DimVector::new(state);
+    x.set(0, qty![150.0 cm]).unwrap();
+    x.set(1, qty![2.0 m/s]).unwrap();
+    assert_eq!(x.values, [1.5, 2.0]);
+    assert_eq!(x.get(0, cm).unwrap(), qty![150.0 cm]);
+
+    //  Components should refuse quantities of the wrong dimension.
+    assert_eq!(
+        x.set(1, qty![1.0 s]),
+        Err(DimensionError::Mismatch { expected: state[1], found: DynDimension::of::<dim::Time>() }),
+    );
+    assert!(x.get(0, s).is_err());
+
+    //  Transition matrix for constant velocity. The upper right entry maps a
+    //      velocity onto a length, so it must be a time.
+    let mut f = DimMatrix::identity(state);
+    f.set(0, 1, qty![500.0 ms]).unwrap();
+    assert_eq!(f.entry_dim(0, 1), DynDimension::of::<dim::Time>());
+    assert!(f.set(1, 0, qty![1.0 s]).is_err());
+
+    let x = f.try_mul_vec(&x).unwrap();
+    assert_eq!(x.dims, state);
+    assert_qty_approx!(<= 1e-9, x.get(0, m).unwrap(), qty![2.5 m]);
+
+    //  A vector with the components swapped should not fit the matrix.
+    let swapped = DimVector::<f64, 2>::new([state[1], state[0]]);
+    assert!(f.try_mul_vec(&swapped).is_err());
+    assert!(x.try_add(swapped).is_err());
+}
+
+
+#[test]
+fn test_matrix_kalman() {
+    let state = dims::<(dim::Length, dim::Velocity)>();
+    let meas = dims::<(dim::Length,)>();
+
+    let mut x = DimVector::new(state);
+    x.set(1, qty![1.0 m/s]).unwrap();
+
+    let mut f = DimMatrix::identity(state);
+    f.set(0, 1, qty![1.0 s]).unwrap();
+
+    let mut p = DimMatrix::outer(state);
+    p.set(0, 0, qty![1.0 m^2]).unwrap();
+    p.set(1, 1, qty![1.0 m^2/s^2]).unwrap();
+
+    let mut obs = DimMatrix::new(meas, state);
+    obs.set(0, 0, qty![1.0 m/m]).unwrap();
+
+    let mut r = DimMatrix::outer(meas);
+    r.set(0, 0, qty![1.0 m^2]).unwrap();
+
+    //  Predict.
+    let x = f.try_mul_vec(&x).unwrap();
+    let p = f.try_mul(&p).unwrap().try_mul(&f.transpose()).unwrap();
+    assert_eq!((p.rows, p.cols), (state, state.map(num_traits::Inv::inv)));
+    assert_eq!(p.values, [[2.0, 1.0], [1.0, 1.0]]);
+
+    //  Update with a measurement of 3 meters.
+    let mut z = DimVector::new(meas);
+    z.set(0, qty![3.0 m]).unwrap();
+
+    let innov = obs.try_mul(&p).unwrap().try_mul(&obs.transpose()).unwrap().try_add(&r).unwrap();
+    let k = p.try_mul(&obs.transpose()).unwrap().try_mul(&innov.inverse().unwrap()).unwrap();
+
+    //  The gain maps a length residual onto the state.
+    assert_eq!(k.entry_dim(0, 0), DynDimension::ONE);
+    assert_eq!(k.entry_dim(1, 0), DynDimension::of::<dim::Frequency>());
+
+    let y = z.try_sub(obs.try_mul_vec(&x).unwrap()).unwrap();
+    let x = x.try_add(k.try_mul_vec(&y).unwrap()).unwrap();
+
+    assert_qty_approx!(<= 1e-9, x.get(0, m).unwrap(), m.quantity(1.0 + 2.0 * 2.0/3.0));
+    assert_qty_approx!(<= 1e-9, x.get(1, m/s).unwrap(), (m/s).quantity(1.0 + 2.0/3.0));
+
+    //  Mixing up the gain and the covariance should be caught.
+    assert!(p.try_mul(&k).is_err());
+    assert!(p.try_add(&f).is_err());
+}
+
+
+#[test]
+fn test_matrix_inverse() {
+    let state = dims::<(dim::Length, dim::Time)>();
+    let mut a = DimMatrix::<f64, 2, 2>::new(state, state);
+    a.values = [[4.0, 7.0], [2.0, 6.0]];
+
+    let inv = a.inverse().unwrap();
+    assert_eq!((inv.rows, inv.cols), (a.cols, a.rows));
+
+    let id = a.try_mul(&inv).unwrap();
+    assert_eq!((id.rows, id.cols), (state, state));
+
+    for (i, row) in id.values.iter().enumerate() {
+        for (j, v) in row.iter().enumerate() {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((v - expected).abs() < 1e-12);
+        }
+    }
+
+    a.values = [[1.0, 2.0], [2.0, 4.0]];
+    assert_eq!(a.inverse(), Err(DimensionError::Singular));
+}
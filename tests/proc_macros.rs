@@ -1,4 +1,7 @@
+//! Tests for the procedural macros.
+
 #[test]
+#[allow(clippy::type_complexity)]
 fn dim() {
     use dana::{
         prelude::*,
@@ -1,3 +1,4 @@
+//! Tests for random quantity generation.
 #![cfg(feature = "rand")]
 
 use rand::prelude::*;
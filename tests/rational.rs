@@ -0,0 +1,35 @@
+//! Tests for units and quantities with fractional exponents.
+
+use dana::{assert_qty_approx, prelude::*, symbols::{basic::*, electrical::*, frequency::*}};
+
+
+#[test]
+fn rational_root() {
+    //  Roots no longer need evenly-divisible exponents.
+    let side: Quantity<UnitSquareRoot<Length>> = qty![16.0 m].sqrt();
+    assert_eq!(side.value, 4.0);
+    assert_eq!(side.to_string(), "4 m^(1/2)");
+
+    //  Squaring the root should recover the original unit.
+    assert_eq!(side.squared().cancel_exponent(), qty![16.0 m]);
+    assert_eq!(qty![8.0 m^3].sqrt().unit.to_string(), "m^(3/2)");
+    assert_eq!(qty![1.0 m^3].root::<-2>().unit.to_string(), "m^(-3/2)");
+    assert_eq!(qty![1.0 km].root::<3>().root::<2>().unit.to_string(), "km^(1/6)");
+}
+
+
+#[test]
+fn rational_noise_density() {
+    let noise = qty![10.0 uV] / qty![100.0 Hz].sqrt();
+    assert_eq!(noise.value, 1.0);
+    assert_eq!(noise.unit.to_string(), "μV/Hz^(1/2)");
+
+    //  Integrating the density over a bandwidth gives a voltage.
+    let bandwidth = qty![4.0 kHz];
+    let total = noise * bandwidth.sqrt();
+    assert_qty_approx!(rel <= 1e-12, total.convert_to(uV), qty![63.245_553_203_367_59 uV]);
+
+    //  Conversion between units with fractional exponents.
+    let density = noise.convert_to(V / kHz.sqrt());
+    assert_qty_approx!(rel <= 1e-12, density, qty![31.622_776_601_683_79 uV] / qty![1.0 kHz].sqrt());
+}
@@ -0,0 +1,138 @@
+//! Tests for runtime lookup and parsing of units.
+#![cfg(feature = "alloc")]
+
+use dana::{
+    dimension::{self as dim, DynDimension},
+    error::{DimensionError, ParseUnitError},
+    prelude::*,
+    symbols::basic::*,
+    registry::*,
+    units::UnitNamed,
+};
+
+
+#[test]
+fn registry_lookup() {
+    let units = UnitRegistry::new();
+
+    //  Concrete units are registered by symbol and by name.
+    assert_eq!(units.get("km"), Some(DynUnit::of(Length::KiloMeter)));
+    assert_eq!(units.get("kilometer"), Some(DynUnit::of(Length::KiloMeter)));
+    assert_eq!(units.get("day"), Some(DynUnit::of(Time::Day)));
+
+    //  Aliases from the symbols modules are registered by name.
+    assert_eq!(units.get("um"), Some(DynUnit::of(Length::MicroMeter)));
+    assert_eq!(units.get("kph"), Some(DynUnit::of(Length::KiloMeter / Time::Hour)));
+    assert_eq!(units.get("kWh"), Some(DynUnit::of(Power::KiloWatt * Time::Hour)));
+
+    assert_eq!(units.get("furlong"), None);
+    assert!(UnitRegistry::empty().iter().next().is_none());
+}
+
+
+#[test]
+fn registry_parse_unit() {
+    let units = UnitRegistry::new();
+    let newton = DynUnit::of(Force::Newton);
+
+    let parse = |expr: &str| units.parse_unit(expr).unwrap();
+
+    assert_eq!(parse("kg*m/s^2").dim, newton.dim);
+    assert_eq!(parse("kg m / s / s").dim, newton.dim);
+    assert_eq!(parse("kg·m·s^-2").dim, newton.dim);
+    assert_eq!(parse("(kg m)/(s s)").dim, newton.dim);
+    assert_eq!(parse("1/s").dim, DynDimension::of::<dim::Frequency>());
+    assert_eq!(parse("kWh/day").dim, DynDimension::of::<dim::Power>());
+    assert_eq!(parse("km^3").scale, 1e9);
+
+    assert_eq!(units.parse_unit(""), Err(ParseUnitError::Empty));
+    assert_eq!(units.parse_unit("m/"), Err(ParseUnitError::InvalidSyntax));
+    assert_eq!(units.parse_unit("(m/s"), Err(ParseUnitError::InvalidSyntax));
+    assert_eq!(units.parse_unit("m^x"), Err(ParseUnitError::InvalidExponent));
+    assert_eq!(units.parse_unit("m/furlong"), Err(ParseUnitError::UnknownUnit));
+}
+
+
+#[test]
+fn registry_parse_qty() {
+    let mut units = UnitRegistry::new();
+
+    let usage = units.parse::<f64>("24kWh/day").unwrap();
+    assert_eq!(usage.value, 24.0);
+    assert_eq!(usage.dim(), DynDimension::of::<dim::Power>());
+    assert_eq!(usage.value_base(), 1000.0);
+
+    let speed: Quantity<Speed> = units.parse_qty("-36 km/h", unit!(m/s)).unwrap();
+    assert_eq!(speed, qty![-10.0 m/s]);
+
+    assert_eq!(
+        units.parse_qty::<_, f64>("36 km/h", Time::Second),
+        Err(ParseUnitError::Mismatch(DimensionError::Mismatch {
+            expected: DynDimension::of::<dim::Time>(),
+            found: DynDimension::of::<dim::Velocity>(),
+        })),
+    );
+    assert_eq!(units.parse::<f64>("km"), Err(ParseUnitError::InvalidValue));
+
+    //  Numbers may have a sign and an exponent.
+    let parse_m = |text: &str| units.parse_qty::<_, f64>(text, Length::Meter).unwrap().value;
+    assert_eq!(parse_m("1e3 m"), 1e3);
+    assert_eq!(parse_m("1e3m"), 1e3);
+    assert_eq!(parse_m("+5 m"), 5.0);
+    assert_eq!(parse_m("-2.5E-1 km"), -250.0);
+    assert_eq!(parse_m("2e+1 mm"), 0.02);
+
+    //  An `e` without digits after it begins the unit instead.
+    let energy = units.parse::<f64>("5eV").unwrap();
+    assert_eq!(energy.value, 5.0);
+    assert_eq!(energy.unit, DynUnit::of(Energy::ElectronVolt));
+
+    //  Symbols containing spaces are matched whole, and take precedence over
+    //      implicit products.
+    assert_eq!(units.get("fl oz"), Some(DynUnit::of(Volume::FlOunce)));
+    assert_eq!(units.parse_unit("fl oz"), Ok(DynUnit::of(Volume::FlOunce)));
+    assert_eq!(units.parse_unit("imp gal/s").unwrap().scale, Volume::ImpGallon.scale());
+    assert_eq!(units.parse_unit("(imp gal)^2").unwrap().scale, Volume::ImpGallon.scale().powi(2));
+    let gal = units.parse_qty::<_, f64>("2 imp gal", Volume::ImpGallon).unwrap();
+    assert_eq!(gal.value, 2.0);
+
+    //  Every displayed quantity should parse back to the same unit.
+    fn round_trip<U: UnitConcrete + core::fmt::Debug>(units: &UnitRegistry) {
+        for &unit in U::VARIANTS {
+            let parsed = units.parse::<f64>(&unit.quantity(2.0).to_string()).unwrap();
+            assert_eq!(parsed.value, 2.0, "failed for {unit:?}");
+            assert_eq!(parsed.unit, DynUnit::of(unit), "failed for {unit:?}");
+        }
+    }
+
+    round_trip::<One>(&units);
+    round_trip::<Length>(&units);
+    round_trip::<Mass>(&units);
+    round_trip::<Time>(&units);
+    round_trip::<Frequency>(&units);
+    round_trip::<Temp>(&units);
+    round_trip::<Amount>(&units);
+    round_trip::<Intensity>(&units);
+    round_trip::<Force>(&units);
+    round_trip::<Volume>(&units);
+    round_trip::<Pressure>(&units);
+    round_trip::<Energy>(&units);
+    round_trip::<Power>(&units);
+    round_trip::<Charge>(&units);
+    round_trip::<Current>(&units);
+    round_trip::<Voltage>(&units);
+    round_trip::<Resistance>(&units);
+    round_trip::<Capacitance>(&units);
+    round_trip::<Inductance>(&units);
+
+    //  Named units are registered under both symbol and name.
+    units.register_named(UnitNamed::new(Length::Meter, "ph", "pallet_height"));
+    let a = units.parse_qty::<_, f64>("3 ph", Length::Meter).unwrap();
+    let b = units.parse_qty::<_, f64>("3 pallet_height", Length::Meter).unwrap();
+    assert_eq!(a, b);
+
+    //  Dynamic quantities combine, and convert back to typed quantities.
+    let dist = DynQuantity::from(qty![3.0 km]);
+    let time = units.parse::<f64>("2 min").unwrap();
+    assert_eq!((dist / time).to_qty(unit!(m/s)).unwrap(), qty![25.0 m/s]);
+}
@@ -1,3 +1,4 @@
+//! Tests for serialization and deserialization.
 #![cfg(feature = "serde")]
 
 use serde::{de::DeserializeOwned, Serialize};
@@ -54,6 +55,7 @@ fn serde() {
     test_json(qty![2.0 m].pow::<1>(), r#"{"value":2.0,"unit":{"base":"Meter","exp":1}}"#);
     test_json(qty![2.0 m].pow::<2>(), r#"{"value":4.0,"unit":{"base":"Meter","exp":2}}"#);
     test_json(qty![2.0 m].pow::<3>(), r#"{"value":8.0,"unit":{"base":"Meter","exp":3}}"#);
+    test_json(qty![4.0 m].sqrt(), r#"{"value":2.0,"unit":{"base":"Meter","exp":1,"den":2}}"#);
 
     //  Test anonymous.
     test_json(qty![2.0 m/s as ?], r#"{"value":2.0,"unit":1.0}"#);
@@ -63,6 +65,37 @@ fn serde() {
     //  Ensure that compounds cannot be confused.
     test_fail(qty![2.0 m^2], r#"{"value":2.0,"unit":{"base":"Meter","exp":3}}"#);
     test_fail(qty![2.0 m^3], r#"{"value":2.0,"unit":{"base":"Meter","exp":2}}"#);
+    test_fail(qty![4.0 m].sqrt(), r#"{"value":2.0,"unit":{"base":"Meter","exp":1}}"#);
     test_fail(qty![2.0 m/s], r#"{"value":2.0,"unit":{"mul":["Meter","Second"]}}"#);
     test_fail(qty![2.0 m*s], r#"{"value":2.0,"unit":{"div":["Meter","Second"]}}"#);
 }
+
+
+#[test]
+fn serde_named() {
+    use serde::de::DeserializeSeed;
+    use dana::units::unit_named::NamedSeed;
+
+    let fur = UnitRescale::new(Length::Meter, 201.168).named("fur", "furlong");
+    let chain = UnitRescale::new(Length::Meter, 20.1168).named("ch", "chain");
+    let units = [fur, chain];
+    let json = r#"{"value":8.0,"unit":{"unit":["Meter",201.168],"symbol":"fur","name":"furlong"}}"#;
+
+    assert_eq!(serde_json::to_string(&fur.quantity(8.0)).unwrap(), json);
+
+    //  Deserialize from a string that does not live for `'static`.
+    let owned = String::from(json);
+    let mut de = serde_json::Deserializer::from_str(&owned);
+    assert_eq!(NamedSeed(&units).quantity::<f64>().deserialize(&mut de).unwrap(), fur.quantity(8.0));
+
+    let owned = String::from(r#"{"symbol":"ch"}"#);
+    let mut de = serde_json::Deserializer::from_str(&owned);
+    assert_eq!(NamedSeed(&units).deserialize(&mut de).unwrap(), chain);
+
+    //  Symbols not in the list are rejected.
+    let mut de = serde_json::Deserializer::from_str(r#"{"symbol":"lea"}"#);
+    assert!(NamedSeed(&units).deserialize(&mut de).is_err());
+
+    let mut de = serde_json::Deserializer::from_str(r#"{"name":"furlong"}"#);
+    assert!(NamedSeed(&units).deserialize(&mut de).is_err());
+}
@@ -0,0 +1,76 @@
+//! Tests for total ordering and hashing of quantities.
+
+use std::collections::{BTreeSet, HashMap};
+use core::cmp::Ordering;
+use dana::{prelude::*, symbols::basic::*};
+
+
+#[test]
+fn total_cmp() {
+    assert_eq!(qty![1000.0 m].total_cmp(&qty![1.0 km]), Ordering::Equal);
+    assert_eq!(qty![999.0 m].total_cmp(&qty![1.0 km]), Ordering::Less);
+    assert_eq!(qty![1.0 h].total_cmp(&qty![3599.0 s]), Ordering::Greater);
+    assert_eq!(qty![0.0 m].total_cmp(&qty![-0.0 km]), Ordering::Equal);
+
+    let nan = m.quantity(f64::NAN);
+    assert_eq!(nan.total_cmp(&km.quantity(-f64::NAN)), Ordering::Equal);
+    assert_eq!(nan.total_cmp(&km.quantity(f64::INFINITY)), Ordering::Greater);
+    assert_eq!(qty![1.0 m].total_cmp(&nan), Ordering::Less);
+
+    let mut set = BTreeSet::new();
+    set.insert(qty![1.0 km].total());
+    set.insert(qty![5.0 m].total());
+    set.insert(qty![1000.0 m].total());
+    set.insert(nan.total());
+
+    let sorted: Vec<_> = set.into_iter().map(|q| q.into_inner()).collect();
+    assert_eq!(sorted.len(), 3);
+    assert_eq!(sorted[0], qty![5.0 m]);
+    assert_eq!(sorted[1], qty![1.0 km]);
+    assert!(sorted[2].value.is_nan());
+}
+
+
+#[test]
+fn total_hash() {
+    let mut cache = HashMap::new();
+    cache.insert(qty![1.0 km].total(), "one");
+    cache.insert(qty![250.0 mm].total(), "quarter");
+
+    assert_eq!(cache.get(&qty![1000.0 m].total()), Some(&"one"));
+    assert_eq!(cache.get(&qty![1.0 km].total()), Some(&"one"));
+    assert_eq!(cache.get(&qty![1.001 km].total()), None);
+
+    assert_eq!(cache.insert(qty![1000.0 m].total(), "two"), Some("one"));
+    assert_eq!(cache.len(), 2);
+
+    let mut zeros = HashMap::new();
+    zeros.insert(qty![0.0 s].total(), ());
+    zeros.insert(qty![-0.0 h].total(), ());
+    assert_eq!(zeros.len(), 1);
+}
+
+
+#[test]
+fn total_imperial() {
+    use dana::symbols::{length_us::*, mass_imp::*};
+
+    //  Imperial units should be equal to each other exactly, not only after
+    //      rounding through their scales in metres or kilograms.
+    assert_eq!(qty![12.0 inch].total_cmp(&qty![1.0 ft]), Ordering::Equal);
+    assert_eq!(qty![3.0 ft].total_cmp(&qty![1.0 yd]), Ordering::Equal);
+    assert_eq!(qty![36.0 inch].total_cmp(&qty![1.0 yd]), Ordering::Equal);
+    assert_eq!(qty![1760.0 yd].total_cmp(&qty![1.0 mi]), Ordering::Equal);
+    assert_eq!(qty![16.0 oz].total_cmp(&qty![1.0 lb]), Ordering::Equal);
+    assert_eq!(qty![14.0 lb].total_cmp(&qty![1.0 st]), Ordering::Equal);
+    assert_eq!(qty![20.0 long_cwt].total_cmp(&qty![1.0 long_tn]), Ordering::Equal);
+    assert_eq!(qty![11.0 inch].total_cmp(&qty![1.0 ft]), Ordering::Less);
+
+    let mut cache = HashMap::new();
+    cache.insert(qty![1.0 ft].total(), "foot");
+    cache.insert(qty![1.0 yd].total(), "yard");
+
+    assert_eq!(cache.get(&qty![12.0 inch].total()), Some(&"foot"));
+    assert_eq!(cache.get(&qty![3.0 ft].total()), Some(&"yard"));
+    assert_eq!(cache.get(&qty![36.0 inch].total()), Some(&"yard"));
+}
@@ -0,0 +1,68 @@
+//! Tests for vectors of quantities.
+
+use dana::{assert_qty_approx, prelude::*, symbols::{basic::*, physics::*}, vector::*};
+
+
+#[test]
+fn vector_arithmetic() {
+    let a = QtyVec3::new(m, 1.0, 2.0, 3.0);
+    let b = QtyVec3::new(cm, 100.0, 0.0, -50.0);
+
+    //  Addition and subtraction should convert into the unit on the left.
+    assert_eq!(a + b, QtyVec3::new(m, 2.0, 2.0, 2.5));
+    assert_eq!(a - b, QtyVec3::new(m, 0.0, 2.0, 3.5));
+    assert_eq!(-a, QtyVec3::new(m, -1.0, -2.0, -3.0));
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    assert_eq!(c, b.with_unit(m));
+
+    //  Scaling by scalars should keep the unit.
+    assert_eq!(a * 2.0, QtyVec3::new(m, 2.0, 4.0, 6.0));
+    assert_eq!(a / 2.0, QtyVec3::new(m, 0.5, 1.0, 1.5));
+
+    //  Scaling by quantities should change the unit.
+    let v: QtyVec3<UnitDiv<Length, Time>> = a / qty![2.0 s];
+    assert_eq!(v, QtyVec3::new(m/s, 0.5, 1.0, 1.5));
+
+    let p: QtyVec2<UnitMul<UnitDiv<Length, Time>, Mass>> = QtyVec2::new(m/s, 3.0, 4.0) * qty![2.0 kg];
+    assert_eq!(p.values(), [6.0, 8.0]);
+
+    assert_eq!(a.to_string(), "(1, 2, 3) m");
+    assert_eq!(format!("{:.1}", QtyVec2::new(s, 1.0, 2.0)), "(1.0, 2.0) s");
+}
+
+
+#[test]
+fn vector_products() {
+    let a = QtyVec3::new(m, 3.0, 0.0, 4.0);
+    let f = QtyVec3::new(N, 0.0, 2.0, 0.0);
+
+    assert_eq!(a.norm(), qty![5.0 m]);
+    assert_eq!(QtyVec2::new(km, 3.0, 4.0).norm(), qty![5.0 km]);
+
+    //  Dot product.
+    let work: Quantity<UnitMul<Length, Force>> = a.dot(QtyVec3::new(N, 1.0, 5.0, 2.0));
+    assert_qty_approx!(work, qty![11.0 m*N]);
+    assert_qty_approx!(work.convert_to(J), qty![11.0 J]);
+
+    //  Cross product.
+    let torque: QtyVec3<UnitMul<Length, Force>> = a.cross(f);
+    assert_eq!(torque, QtyVec3::new(m*N, -8.0, 0.0, 6.0));
+    assert_eq!(a.cross(a).norm().value, 0.0);
+    assert_eq!(torque.dot(a.with_unit(m)).value, 0.0);
+
+    let area = QtyVec2::new(m, 2.0, 0.0).cross(QtyVec2::new(cm, 0.0, 300.0));
+    assert_qty_approx!(area, qty![6.0 m*m]);
+
+    //  Direction should be dimensionless with a magnitude of one.
+    let dir: QtyVec3<One> = a.direction().unwrap();
+    assert_eq!(dir, QtyVec3::new(One, 0.6, 0.0, 0.8));
+    assert_eq!(QtyVec3::new(m, 0.0, 0.0, 0.0).direction(), None);
+
+    //  Components should round-trip through quantities.
+    let [x, y] = QtyVec2::new(m, 1.0, 2.0).to_qty();
+    assert_eq!(QtyVec2::from_qty([x, y.with_unit(cm)]), QtyVec2::new(m, 1.0, 2.0));
+    assert_eq!(QtyVec2::new(m, 1.0, 2.0).extend(3.0).truncate(), QtyVec2::new(m, 1.0, 2.0));
+}